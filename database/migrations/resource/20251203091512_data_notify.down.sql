@@ -0,0 +1,3 @@
+DROP TRIGGER "data_buffer_notify_insert" ON "data_buffer";
+DROP TRIGGER "data_notify_insert" ON "data";
+DROP FUNCTION "data_notify";
@@ -0,0 +1,21 @@
+CREATE OR REPLACE FUNCTION "data_notify"() RETURNS trigger AS $$
+BEGIN
+  PERFORM pg_notify('data_insert', concat_ws(',',
+    TG_ARGV[0],
+    CASE WHEN TG_ARGV[0] = 'buffer' THEN (to_jsonb(NEW)->>'id') ELSE '' END,
+    NEW."device_id",
+    NEW."model_id",
+    (extract(epoch FROM NEW."timestamp") * 1000000)::bigint,
+    NEW."tag"
+  ));
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER "data_notify_insert"
+  AFTER INSERT ON "data"
+  FOR EACH ROW EXECUTE FUNCTION "data_notify"('data');
+
+CREATE OR REPLACE TRIGGER "data_buffer_notify_insert"
+  AFTER INSERT ON "data_buffer"
+  FOR EACH ROW EXECUTE FUNCTION "data_notify"('buffer');
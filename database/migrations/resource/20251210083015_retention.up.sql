@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS "data_retention" (
+  "id" serial NOT NULL,
+  "model_id" uuid,
+  "type_id" uuid,
+  "tag" smallint,
+  "retention" bigint NOT NULL,
+  PRIMARY KEY ("id"),
+  UNIQUE NULLS NOT DISTINCT ("model_id","type_id","tag"),
+  FOREIGN KEY ("model_id")
+    REFERENCES "model" ("model_id") ON UPDATE CASCADE ON DELETE CASCADE,
+  FOREIGN KEY ("type_id")
+    REFERENCES "device_type" ("type_id") ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+DROP TRIGGER "data_audit_update" ON "data";
+DROP FUNCTION "data_audit";
+DROP TABLE "data_audit";
@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS "data_audit" (
+  "id" serial NOT NULL,
+  "device_id" uuid NOT NULL,
+  "model_id" uuid NOT NULL,
+  "timestamp" timestamptz NOT NULL,
+  "tag" smallint NOT NULL,
+  "data" bytea NOT NULL,
+  "new_tag" smallint NOT NULL,
+  "new_data" bytea NOT NULL,
+  "changed_at" timestamptz NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY ("id"),
+  FOREIGN KEY ("model_id")
+    REFERENCES "model" ("model_id") ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS "data_audit_row" ON "data_audit" ("device_id","model_id","timestamp");
+
+CREATE OR REPLACE FUNCTION "data_audit"() RETURNS trigger AS $$
+BEGIN
+  INSERT INTO "data_audit" ("device_id","model_id","timestamp","tag","data","new_tag","new_data")
+  VALUES (OLD."device_id", OLD."model_id", OLD."timestamp", OLD."tag", OLD."data", NEW."tag", NEW."data");
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER "data_audit_update"
+  AFTER UPDATE ON "data"
+  FOR EACH ROW EXECUTE FUNCTION "data_audit"();
@@ -0,0 +1 @@
+DROP TABLE "slice_rule";
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS "slice_rule" (
+  "id" serial NOT NULL,
+  "device_id" uuid NOT NULL,
+  "model_id" uuid NOT NULL,
+  "index" smallint NOT NULL,
+  "condition" varchar(8) NOT NULL,
+  "threshold" double precision NOT NULL,
+  "min_duration" bigint NOT NULL DEFAULT 0,
+  "max_gap" bigint NOT NULL DEFAULT 0,
+  "name" varchar(96) NOT NULL,
+  "checkpoint" timestamptz,
+  PRIMARY KEY ("id"),
+  FOREIGN KEY ("device_id")
+    REFERENCES "device" ("device_id") ON UPDATE CASCADE ON DELETE CASCADE,
+  FOREIGN KEY ("model_id")
+    REFERENCES "model" ("model_id") ON UPDATE CASCADE ON DELETE CASCADE
+);
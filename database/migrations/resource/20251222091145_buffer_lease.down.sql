@@ -0,0 +1,3 @@
+ALTER TABLE "data_buffer"
+  DROP COLUMN IF EXISTS "lease_worker",
+  DROP COLUMN IF EXISTS "lease_expire";
@@ -0,0 +1,3 @@
+ALTER TABLE "data_buffer"
+  ADD COLUMN IF NOT EXISTS "lease_worker" varchar(64),
+  ADD COLUMN IF NOT EXISTS "lease_expire" timestamptz;
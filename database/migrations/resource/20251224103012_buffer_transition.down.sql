@@ -0,0 +1,4 @@
+DROP TRIGGER "buffer_history_update" ON "data_buffer";
+DROP FUNCTION "buffer_history";
+DROP TABLE "buffer_history";
+DROP TABLE "buffer_transition";
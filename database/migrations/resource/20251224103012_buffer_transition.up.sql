@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS "buffer_transition" (
+  "tag" smallint NOT NULL,
+  "new_tag" smallint NOT NULL,
+  PRIMARY KEY ("tag","new_tag")
+);
+
+CREATE TABLE IF NOT EXISTS "buffer_history" (
+  "id" serial NOT NULL,
+  "buffer_id" integer NOT NULL,
+  "tag" smallint NOT NULL,
+  "new_tag" smallint NOT NULL,
+  "changed_by" varchar(64) NOT NULL DEFAULT '',
+  "changed_at" timestamptz NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX IF NOT EXISTS "buffer_history_buffer" ON "buffer_history" ("buffer_id");
+
+CREATE OR REPLACE FUNCTION "buffer_history"() RETURNS trigger AS $$
+BEGIN
+  INSERT INTO "buffer_history" ("buffer_id","tag","new_tag","changed_by")
+  VALUES (OLD."id", OLD."tag", NEW."tag", COALESCE(current_setting('bbthings.changed_by', true), ''));
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER "buffer_history_update"
+  AFTER UPDATE OF "tag" ON "data_buffer"
+  FOR EACH ROW WHEN (OLD."tag" IS DISTINCT FROM NEW."tag") EXECUTE FUNCTION "buffer_history"();
@@ -0,0 +1,9 @@
+CREATE OR REPLACE FUNCTION "data_audit"() RETURNS trigger AS $$
+BEGIN
+  INSERT INTO "data_audit" ("device_id","model_id","timestamp","tag","data","new_tag","new_data")
+  VALUES (OLD."device_id", OLD."model_id", OLD."timestamp", OLD."tag", OLD."data", NEW."tag", NEW."data");
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE "data_audit" DROP COLUMN "changed_by";
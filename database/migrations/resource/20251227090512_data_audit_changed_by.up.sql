@@ -0,0 +1,9 @@
+ALTER TABLE "data_audit" ADD COLUMN IF NOT EXISTS "changed_by" varchar(64) NOT NULL DEFAULT '';
+
+CREATE OR REPLACE FUNCTION "data_audit"() RETURNS trigger AS $$
+BEGIN
+  INSERT INTO "data_audit" ("device_id","model_id","timestamp","tag","data","new_tag","new_data","changed_by")
+  VALUES (OLD."device_id", OLD."model_id", OLD."timestamp", OLD."tag", OLD."data", NEW."tag", NEW."data", COALESCE(current_setting('bbthings.changed_by', true), ''));
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
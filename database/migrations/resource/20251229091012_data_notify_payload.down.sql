@@ -0,0 +1,13 @@
+CREATE OR REPLACE FUNCTION "data_notify"() RETURNS trigger AS $$
+BEGIN
+  PERFORM pg_notify('data_insert', concat_ws(',',
+    TG_ARGV[0],
+    CASE WHEN TG_ARGV[0] = 'buffer' THEN (to_jsonb(NEW)->>'id') ELSE '' END,
+    NEW."device_id",
+    NEW."model_id",
+    (extract(epoch FROM NEW."timestamp") * 1000000)::bigint,
+    NEW."tag"
+  ));
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
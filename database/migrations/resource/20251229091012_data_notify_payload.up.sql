@@ -0,0 +1,22 @@
+CREATE OR REPLACE FUNCTION "data_notify"() RETURNS trigger AS $$
+DECLARE
+  "payload" text;
+  "types" bytea;
+BEGIN
+  "payload" := concat_ws(',',
+    TG_ARGV[0],
+    CASE WHEN TG_ARGV[0] = 'buffer' THEN (to_jsonb(NEW)->>'id') ELSE '' END,
+    NEW."device_id",
+    NEW."model_id",
+    (extract(epoch FROM NEW."timestamp") * 1000000)::bigint,
+    NEW."tag"
+  );
+  SELECT "data_type" INTO "types" FROM "model" WHERE "model_id" = NEW."model_id";
+  -- append model types and row data as hex when the payload stays below the notification size limit
+  IF octet_length("payload") + 2 * (coalesce(octet_length("types"), 0) + coalesce(octet_length(NEW."data"), 0)) < 7900 THEN
+    "payload" := concat_ws(',', "payload", encode(coalesce("types", ''::bytea), 'hex'), encode(coalesce(NEW."data", ''::bytea), 'hex'));
+  END IF;
+  PERFORM pg_notify('data_insert', "payload");
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
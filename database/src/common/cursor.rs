@@ -0,0 +1,97 @@
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+const TIMESTAMP_FLAG: u8 = 0x01;
+const ID_FLAG: u8 = 0x02;
+const DEVICE_ID_FLAG: u8 = 0x04;
+const MODEL_ID_FLAG: u8 = 0x08;
+const TAG_FLAG: u8 = 0x10;
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Cursor {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub id: Option<i32>,
+    pub device_id: Option<Uuid>,
+    pub model_id: Option<Uuid>,
+    pub tag: Option<i16>
+}
+
+impl Cursor {
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // first byte is a flag of which keys are present followed by the present keys
+        let mut flag = 0;
+        let mut bytes = vec![0];
+        if let Some(timestamp) = self.timestamp {
+            flag |= TIMESTAMP_FLAG;
+            bytes.extend(timestamp.timestamp_micros().to_be_bytes());
+        }
+        if let Some(id) = self.id {
+            flag |= ID_FLAG;
+            bytes.extend(id.to_be_bytes());
+        }
+        if let Some(device_id) = self.device_id {
+            flag |= DEVICE_ID_FLAG;
+            bytes.extend(device_id.as_bytes());
+        }
+        if let Some(model_id) = self.model_id {
+            flag |= MODEL_ID_FLAG;
+            bytes.extend(model_id.as_bytes());
+        }
+        if let Some(tag) = self.tag {
+            flag |= TAG_FLAG;
+            bytes.extend(tag.to_be_bytes());
+        }
+        bytes[0] = flag;
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&flag, mut bytes) = bytes.split_first()?;
+        let mut take = |n: usize| -> Option<&[u8]> {
+            if bytes.len() < n {
+                return None;
+            }
+            let (head, tail) = bytes.split_at(n);
+            bytes = tail;
+            Some(head)
+        };
+        let mut cursor = Self::default();
+        if flag & TIMESTAMP_FLAG > 0 {
+            let micros = i64::from_be_bytes(take(8)?.try_into().ok()?);
+            cursor.timestamp = Some(DateTime::from_timestamp_micros(micros)?);
+        }
+        if flag & ID_FLAG > 0 {
+            cursor.id = Some(i32::from_be_bytes(take(4)?.try_into().ok()?));
+        }
+        if flag & DEVICE_ID_FLAG > 0 {
+            cursor.device_id = Some(Uuid::from_slice(take(16)?).ok()?);
+        }
+        if flag & MODEL_ID_FLAG > 0 {
+            cursor.model_id = Some(Uuid::from_slice(take(16)?).ok()?);
+        }
+        if flag & TAG_FLAG > 0 {
+            cursor.tag = Some(i16::from_be_bytes(take(2)?.try_into().ok()?));
+        }
+        // trailing bytes means the cursor is not valid
+        if !bytes.is_empty() {
+            return None;
+        }
+        Some(cursor)
+    }
+
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Page {
+    pub cursor: Option<Cursor>,
+    pub limit: usize
+}
+
+impl Page {
+
+    pub fn new(cursor: Option<Cursor>, limit: usize) -> Self {
+        Self { cursor, limit }
+    }
+
+}
@@ -1,4 +1,7 @@
-use sqlx::{Pool, Row, Error};
+use sqlx::{Pool, Row, Error, Transaction};
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
 use sqlx::postgres::{Postgres, PgRow};
 use sqlx::types::chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -14,14 +17,17 @@ use crate::auth::_row::{
 use crate::resource::_schema::{
     ModelSchema, TagSchema, ModelConfigSchema, DeviceSchema, TypeSchema, DeviceConfigSchema, TypeConfigSchema,
     GroupSchema, SetSchema, SetMember, SetMemberSort, SetTemplateSchema, SetTemplateMember, SetTemplateMemberSort,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema
+    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, DataAuditSchema, BufferTransitionSchema, BufferHistorySchema, IntegrityViolationSchema
 };
 use crate::resource::_row::{
     ModelRow, TagRow, DeviceRow, TypeRow, GroupRow, SetRow, SetTemplateRow, DataSetRow, BufferSetRow,
     map_to_model_schema, map_to_tag_schema, map_to_device_schema, map_to_type_schema, map_to_group_schema,
-    map_to_set_schema, map_to_set_template_schema, map_to_dataset_schema, map_to_bufferset_schema
+    map_to_set_schema, map_to_set_template_schema, map_to_dataset_schema, merge_dataset_row, map_to_bufferset_schema
 };
 use crate::common::type_value::DataType;
+use crate::resource::data::DataKey;
+
+const STREAM_CHANNEL_SIZE: usize = 256;
 
 #[derive(Debug, Clone)]
 pub enum QueryStatement {
@@ -60,6 +66,33 @@ impl QueryStatement {
         Ok(())
     }
 
+    pub(crate) async fn execute_count(&self, pool: &Pool<Postgres>) -> Result<usize, Error>
+    {
+        let (sql, arguments) = self.build();
+        let result = sqlx::query_with(&sql, arguments)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() as usize)
+    }
+
+    pub(crate) async fn execute_transaction(&self, tx: &mut Transaction<'_, Postgres>) -> Result<(), Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_with(&sql, arguments)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn execute_count_transaction(&self, tx: &mut Transaction<'_, Postgres>) -> Result<usize, Error>
+    {
+        let (sql, arguments) = self.build();
+        let result = sqlx::query_with(&sql, arguments)
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected() as usize)
+    }
+
     pub(crate) async fn fetch_id(&self, pool: &Pool<Postgres>) -> Result<i32, Error>
     {
         let (sql, arguments) = self.build();
@@ -70,6 +103,59 @@ impl QueryStatement {
         Ok(id)
     }
 
+    pub(crate) async fn fetch_ids_transaction(&self, tx: &mut Transaction<'_, Postgres>) -> Result<Vec<i32>, Error>
+    {
+        let (sql, arguments) = self.build();
+        let ids = sqlx::query_with(&sql, arguments)
+            .try_map(|row: PgRow| row.try_get(0))
+            .fetch_all(&mut **tx)
+            .await?;
+        Ok(ids)
+    }
+
+    pub(crate) async fn fetch_id_tags_transaction(&self, tx: &mut Transaction<'_, Postgres>) -> Result<Vec<(i32, i16)>, Error>
+    {
+        let (sql, arguments) = self.build();
+        let id_tags = sqlx::query_with(&sql, arguments)
+            .try_map(|row: PgRow| Ok((row.try_get(0)?, row.try_get(1)?)))
+            .fetch_all(&mut **tx)
+            .await?;
+        Ok(id_tags)
+    }
+
+    pub(crate) async fn fetch_inserted(&self, pool: &Pool<Postgres>) -> Result<Vec<bool>, Error>
+    {
+        let (sql, arguments) = self.build();
+        let results = sqlx::query_with(&sql, arguments)
+            .map(|row: PgRow| row.try_get::<bool,_>(0))
+            .fetch_all(pool)
+            .await?;
+        results.into_iter().collect()
+    }
+
+    pub(crate) async fn fetch_inserted_key(&self, pool: &Pool<Postgres>) -> Result<Vec<DataKey>, Error>
+    {
+        let (sql, arguments) = self.build();
+        let results = sqlx::query_with(&sql, arguments)
+            .map(|row: PgRow| -> Result<DataKey, Error> {
+                let timestamp: DateTime<Utc> = row.try_get(2)?;
+                Ok((row.try_get(0)?, row.try_get(1)?, timestamp.timestamp_micros(), row.try_get(3)?))
+            })
+            .fetch_all(pool)
+            .await?;
+        results.into_iter().collect()
+    }
+
+    pub(crate) async fn fetch_id_inserted(&self, pool: &Pool<Postgres>) -> Result<Vec<(i32, bool)>, Error>
+    {
+        let (sql, arguments) = self.build();
+        let results = sqlx::query_with(&sql, arguments)
+            .map(|row: PgRow| Ok((row.try_get(0)?, row.try_get(1)?)))
+            .fetch_all(pool)
+            .await?;
+        results.into_iter().collect()
+    }
+
     pub(crate) async fn fetch_count(&self, pool: &Pool<Postgres>) -> Result<usize, Error>
     {
         let (sql, arguments) = self.build();
@@ -270,6 +356,32 @@ impl QueryStatement {
             .await
     }
 
+    pub(crate) async fn fetch_data_schema_transaction(&self, tx: &mut Transaction<'_, Postgres>) -> Result<Vec<DataSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(&mut **tx)
+            .await
+    }
+
+    pub(crate) fn fetch_data_schema_stream(&self, pool: &Pool<Postgres>) -> ReceiverStream<Result<DataSchema, Error>>
+    {
+        let (sql, arguments) = self.build();
+        let pool = pool.clone();
+        let (tx, rx) = mpsc::channel(STREAM_CHANNEL_SIZE);
+        tokio::spawn(async move {
+            let mut rows = sqlx::query_as_with(&sql, arguments).fetch(&pool);
+            while let Some(row) = rows.next().await {
+                // stop fetching when the receiver is dropped or an error occurred
+                let error = row.is_err();
+                if tx.send(row).await.is_err() || error {
+                    break;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+
     pub(crate) async fn fetch_data_types(&self, pool: &Pool<Postgres>) -> Result<Vec<Vec<DataType>>, Error>
     {
         let (sql, arguments) = self.build();
@@ -294,6 +406,36 @@ impl QueryStatement {
         Ok(map_to_dataset_schema(rows, set_id))
     }
 
+    pub(crate) fn fetch_data_set_schema_stream(&self, pool: &Pool<Postgres>, set_id: Uuid) -> ReceiverStream<Result<DataSetSchema, Error>>
+    {
+        let (sql, arguments) = self.build();
+        let pool = pool.clone();
+        let (tx, rx) = mpsc::channel(STREAM_CHANNEL_SIZE);
+        tokio::spawn(async move {
+            let mut rows = sqlx::query_as_with(&sql, arguments).fetch(&pool);
+            let mut current = None;
+            while let Some(row) = rows.next().await {
+                // member rows are merged and a data set row is sent when all of its member rows are read
+                let result = match row {
+                    Ok(row) => match merge_dataset_row(&mut current, row, set_id) {
+                        Some(dataset) => Ok(dataset),
+                        None => continue
+                    },
+                    Err(e) => Err(e)
+                };
+                // stop fetching when the receiver is dropped or an error occurred
+                let error = result.is_err();
+                if tx.send(result).await.is_err() || error {
+                    return;
+                }
+            }
+            if let Some(dataset) = current {
+                let _ = tx.send(Ok(dataset)).await;
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+
     pub(crate) async fn fetch_buffer_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<BufferSchema>, Error>
     {
         let (sql, arguments) = self.build();
@@ -302,6 +444,24 @@ impl QueryStatement {
             .await
     }
 
+    pub(crate) fn fetch_buffer_schema_stream(&self, pool: &Pool<Postgres>) -> ReceiverStream<Result<BufferSchema, Error>>
+    {
+        let (sql, arguments) = self.build();
+        let pool = pool.clone();
+        let (tx, rx) = mpsc::channel(STREAM_CHANNEL_SIZE);
+        tokio::spawn(async move {
+            let mut rows = sqlx::query_as_with(&sql, arguments).fetch(&pool);
+            while let Some(row) = rows.next().await {
+                // stop fetching when the receiver is dropped or an error occurred
+                let error = row.is_err();
+                if tx.send(row).await.is_err() || error {
+                    break;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+
     pub(crate) async fn fetch_buffer_types(&self, pool: &Pool<Postgres>) -> Result<Vec<DataType>, Error>
     {
         let (sql, arguments) = self.build();
@@ -337,4 +497,61 @@ impl QueryStatement {
             .await
     }
 
+    pub(crate) async fn fetch_slice_rule_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<SliceRuleSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(crate) async fn fetch_data_audit_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<DataAuditSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(crate) async fn fetch_buffer_transition_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<BufferTransitionSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(crate) async fn fetch_buffer_history_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<BufferHistorySchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(crate) async fn fetch_retention_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<RetentionSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(crate) async fn fetch_compression_schema(&self, pool: &Pool<Postgres>) -> Result<CompressionSchema, Error>
+    {
+        let (sql, arguments) = self.build();
+        let result = sqlx::query_as_with(&sql, arguments)
+            .fetch_optional(pool)
+            .await?;
+        Ok(result.unwrap_or_default())
+    }
+
+    pub(crate) async fn fetch_integrity_violation_schema(&self, pool: &Pool<Postgres>) -> Result<Vec<IntegrityViolationSchema>, Error>
+    {
+        let (sql, arguments) = self.build();
+        sqlx::query_as_with(&sql, arguments)
+            .fetch_all(pool)
+            .await
+    }
+
 }
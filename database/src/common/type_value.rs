@@ -195,18 +195,19 @@ impl DataValue {
             Self::Null => DataType::NullT
         }
     }
-    fn to_int(&self) -> Option<u64> {
+    fn to_int(&self) -> Option<i128> {
+        // every integer fits into i128 except an unsigned 128 bit value above i128 maximum
         match *self {
-            I8(value) => Some(value as u64),
-            I16(value) => Some(value as u64),
-            I32(value) => Some(value as u64),
-            I64(value) => Some(value as u64),
-            I128(value) => Some(value as u64),
-            U8(value) => Some(value as u64),
-            U16(value) => Some(value as u64),
-            U32(value) => Some(value as u64),
-            U64(value) => Some(value as u64),
-            U128(value) => Some(value as u64),
+            I8(value) => Some(value.into()),
+            I16(value) => Some(value.into()),
+            I32(value) => Some(value.into()),
+            I64(value) => Some(value.into()),
+            I128(value) => Some(value),
+            U8(value) => Some(value.into()),
+            U16(value) => Some(value.into()),
+            U32(value) => Some(value.into()),
+            U64(value) => Some(value.into()),
+            U128(value) => i128::try_from(value).ok(),
             _ => None
         }
     }
@@ -217,6 +218,82 @@ impl DataValue {
             _ => None
         }
     }
+    pub fn to_f64(&self) -> Option<f64> {
+        match *self {
+            I8(value) => Some(value as f64),
+            I16(value) => Some(value as f64),
+            I32(value) => Some(value as f64),
+            I64(value) => Some(value as f64),
+            I128(value) => Some(value as f64),
+            U8(value) => Some(value as f64),
+            U16(value) => Some(value as f64),
+            U32(value) => Some(value as f64),
+            U64(value) => Some(value as f64),
+            U128(value) => Some(value as f64),
+            F32(value) => Some(value as f64),
+            F64(value) => Some(value),
+            _ => None
+        }
+    }
+    pub fn from_f64(value: f64, type_: DataType) -> Self {
+        // integer types are rounded to the nearest value, non numeric types return null
+        match type_ {
+            I8T => I8(value.round() as i8),
+            I16T => I16(value.round() as i16),
+            I32T => I32(value.round() as i32),
+            I64T => I64(value.round() as i64),
+            I128T => I128(value.round() as i128),
+            U8T => U8(value.round() as u8),
+            U16T => U16(value.round() as u16),
+            U32T => U32(value.round() as u32),
+            U64T => U64(value.round() as u64),
+            U128T => U128(value.round() as u128),
+            F32T => F32(value as f32),
+            F64T => F64(value),
+            _ => Self::Null
+        }
+    }
+    pub fn parse(text: &str, type_: DataType) -> Option<Self> {
+        // numbers and boolean are parsed from trimmed text, bytes are parsed from hex text
+        let trimmed = text.trim();
+        match type_ {
+            I8T => trimmed.parse().ok().map(I8),
+            I16T => trimmed.parse().ok().map(I16),
+            I32T => trimmed.parse().ok().map(I32),
+            I64T => trimmed.parse().ok().map(I64),
+            I128T => trimmed.parse().ok().map(I128),
+            U8T => trimmed.parse().ok().map(U8),
+            U16T => trimmed.parse().ok().map(U16),
+            U32T => trimmed.parse().ok().map(U32),
+            U64T => trimmed.parse().ok().map(U64),
+            U128T => trimmed.parse().ok().map(U128),
+            F32T => trimmed.parse().ok().map(F32),
+            F64T => trimmed.parse().ok().map(F64),
+            BoolT => match trimmed.to_lowercase().as_str() {
+                "true" | "1" => Some(Bool(true)),
+                "false" | "0" => Some(Bool(false)),
+                _ => None
+            },
+            CharT => {
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(Char(c)),
+                    _ => None
+                }
+            },
+            StringT => Some(Self::String(text.to_owned())),
+            BytesT => {
+                if !trimmed.len().is_multiple_of(2) {
+                    return None;
+                }
+                (0..trimmed.len()).step_by(2)
+                    .map(|i| trimmed.get(i..i + 2).and_then(|h| u8::from_str_radix(h, 16).ok()))
+                    .collect::<Option<Vec<u8>>>()
+                    .map(Self::Bytes)
+            },
+            DataType::NullT => Some(Self::Null)
+        }
+    }
     pub fn convert(self, type_: DataType) -> Option<Self> {
         let type_group = | t: DataType | -> u8 {
             match t {
@@ -230,17 +307,21 @@ impl DataValue {
         if type_group(self.get_type()) != type_group(type_.clone()) {
             return None
         }
+        if self.get_type() == type_ {
+            return Some(self)
+        }
+        // integer value outside of the target type range is not converted
         match type_ {
-            I8T => Some(I8(self.to_int().unwrap() as i8)),
-            I16T => Some(I16(self.to_int().unwrap() as i16)),
-            I32T => Some(I32(self.to_int().unwrap() as i32)),
-            I64T => Some(I64(self.to_int().unwrap() as i64)),
-            I128T => Some(I128(self.to_int().unwrap() as i128)),
-            U8T => Some(U8(self.to_int().unwrap() as u8)),
-            U16T => Some(U16(self.to_int().unwrap() as u16)),
-            U32T => Some(U32(self.to_int().unwrap() as u32)),
-            U64T => Some(U64(self.to_int().unwrap())),
-            U128T => Some(U128(self.to_int().unwrap() as u128)),
+            I8T => self.to_int().and_then(|v| i8::try_from(v).ok()).map(I8),
+            I16T => self.to_int().and_then(|v| i16::try_from(v).ok()).map(I16),
+            I32T => self.to_int().and_then(|v| i32::try_from(v).ok()).map(I32),
+            I64T => self.to_int().and_then(|v| i64::try_from(v).ok()).map(I64),
+            I128T => self.to_int().map(I128),
+            U8T => self.to_int().and_then(|v| u8::try_from(v).ok()).map(U8),
+            U16T => self.to_int().and_then(|v| u16::try_from(v).ok()).map(U16),
+            U32T => self.to_int().and_then(|v| u32::try_from(v).ok()).map(U32),
+            U64T => self.to_int().and_then(|v| u64::try_from(v).ok()).map(U64),
+            U128T => self.to_int().and_then(|v| u128::try_from(v).ok()).map(U128),
             F32T => Some(F32(self.to_float().unwrap() as f32)),
             F64T => Some(F64(self.to_float().unwrap())),
             _ => Some(self)
@@ -320,6 +401,20 @@ impl ArrayDataValue {
         }
         return Some(Self(data_array));
     }
+    pub fn validate(self, types: &[DataType]) -> Result<Self, usize> {
+        // coerce every value to its field type and return the index of the first offending field
+        let mut data_array = Vec::new();
+        for (index, ty) in types.iter().enumerate() {
+            match self.0.get(index).cloned().and_then(|value| value.convert(ty.clone())) {
+                Some(value) if value.get_type() == *ty => data_array.push(value),
+                _ => return Err(index)
+            }
+        }
+        if self.0.len() > types.len() {
+            return Err(types.len());
+        }
+        Ok(Self(data_array))
+    }
 }
 
 macro_rules! value_impl_from {
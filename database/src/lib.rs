@@ -5,6 +5,7 @@ pub mod common {
     pub mod type_value;
     pub mod utility;
     pub mod tag;
+    pub mod cursor;
 }
 
 pub use auth::Auth;
@@ -14,3 +15,4 @@ pub use resource::_schema::*;
 pub use common::type_value::{DataType, DataValue, ArrayDataValue};
 pub use common::utility;
 pub use common::tag;
+pub use common::cursor::{Cursor, Page};
@@ -7,29 +7,53 @@ pub mod set;
 pub mod data;
 pub mod buffer;
 pub mod slice;
-
-use sqlx::{Pool, Error};
-use sqlx::postgres::{Postgres, PgPoolOptions};
+pub mod aggregate;
+pub mod retention;
+pub mod compression;
+pub mod audit;
+pub mod transition;
+pub mod integrity;
+pub mod export;
+
+use sqlx::{Pool, Error, Transaction};
+use sqlx::postgres::{Postgres, PgPoolOptions, PgListener};
 use sqlx::types::chrono::{DateTime, Utc};
+use chrono::Duration;
+use tokio::sync::{mpsc, broadcast};
+use tokio_stream::{Stream, StreamExt};
+use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 use std::slice::from_ref;
+use std::collections::HashSet;
+use std::sync::Arc;
 use crate::common::type_value::{DataType, DataValue, ArrayDataValue};
+use crate::common::cursor::{Cursor, Page};
+use crate::common::query_statement::QueryStatement;
+use crate::common::tag as Tag;
 use _schema::{
     ModelSchema, ModelConfigSchema, TagSchema, TypeConfigSchema,
     DeviceSchema, DeviceConfigSchema, GatewaySchema, GatewayConfigSchema, TypeSchema,
     GroupModelSchema, GroupDeviceSchema, GroupGatewaySchema,
     SetSchema, SetMember, SetTemplateSchema, SetTemplateMember,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema
+    DataSchema, DataSetSchema, DataSetAlignedSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, DataAuditSchema, BufferTransitionSchema, BufferHistorySchema, DataStatsSchema, IntegrityViolationSchema
 };
 use device::DeviceKind;
 use group::GroupKind;
-use data::DataSelector;
+use data::{DataSelector, DataNotify, DataNotifier, DataRangeUpdate, DataNotifyItem, DataConflict, DataConflictCount, DataKey};
 use buffer::BufferSelector;
-use slice::SliceSelector;
+use slice::{SliceSelector, SliceCondition, SliceRuleCreate, SliceRun, SliceDetector};
+use aggregate::{DataAggregate, DataAlign, DataStats, DataBucket, DataWindow};
+use compression::CompressionTable;
+use integrity::{IntegrityMode, IntegrityTable};
+use transition::TransitionMode;
+use export::{ExportFormat, Exporter};
 
 #[derive(Debug, Clone)]
 pub struct Resource {
-    pub pool: Pool<Postgres>
+    pub pool: Pool<Postgres>,
+    pub integrity: IntegrityMode,
+    pub transition: TransitionMode,
+    pub notifier: DataNotifier
 }
 
 impl Resource {
@@ -45,11 +69,11 @@ impl Resource {
             .connect(url)
             .await
             .expect(&format!("Error connecting to {}", url));
-        Resource { pool }
+        Resource { pool, integrity: IntegrityMode::default(), transition: TransitionMode::default(), notifier: DataNotifier::default() }
     }
 
     pub fn new_with_pool(pool: &Pool<Postgres>) -> Self {
-        Resource { pool: pool.to_owned() }
+        Resource { pool: pool.to_owned(), integrity: IntegrityMode::default(), transition: TransitionMode::default(), notifier: DataNotifier::default() }
     }
 
     pub async fn read_model(&self, id: Uuid)
@@ -246,6 +270,25 @@ impl Resource {
         qs.fetch_device_schema(&self.pool).await
     }
 
+    pub async fn list_device_option_page(&self, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>, page: &Page)
+        -> Result<(Vec<DeviceSchema>, Option<Cursor>), Error>
+    {
+        if page.limit == 0 {
+            return Err(Error::InvalidArgument(String::from(PAGE_LIMIT_INVALID)));
+        }
+        let after = match &page.cursor {
+            Some(c) => Some(c.device_id.ok_or(Error::InvalidArgument(String::from(CURSOR_INVALID)))?),
+            None => None
+        };
+        let qs = device::select_device_page(DeviceKind::Device, gateway_id, type_id, name, after, page.limit);
+        let results = qs.fetch_device_schema(&self.pool).await?;
+        let next = match results.last() {
+            Some(d) if results.len() == page.limit => Some(Cursor { device_id: Some(d.id), ..Default::default() }),
+            _ => None
+        };
+        Ok((results, next))
+    }
+
     pub async fn create_device(&self, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: &str)
         -> Result<Uuid, Error>
     {
@@ -774,15 +817,17 @@ impl Resource {
     }
 
     pub async fn add_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
-        -> Result<(), Error>
+        -> Result<usize, Error>
     {
+        let violated = self.check_integrity(&[device_id], &[model_id]).await?;
         // delete all set members and get the deleted set members
         let qs = set::delete_set_members(id);
         let mut members = qs.fetch_set_members(&self.pool).await?;
         // push new set member and insert back all members
         members.push(SetMember { device_id, model_id, data_index: data_index.to_vec() });
         let qs = set::insert_set_members(id, &members);
-        qs.execute(&self.pool).await
+        qs.execute(&self.pool).await?;
+        Ok(violated)
     }
 
     pub async fn remove_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid)
@@ -946,6 +991,14 @@ impl Resource {
         qs.fetch_data_schema(&self.pool).await
     }
 
+    pub fn stream_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Stream<Item = Result<DataSchema, Error>> + Send + use<>
+    {
+        let selector = DataSelector::Range(begin, end);
+        let qs = data::select_data(selector, &[device_id], &[model_id], tag);
+        qs.fetch_data_schema_stream(&self.pool)
+    }
+
     pub async fn list_data_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Error>
     {
@@ -994,6 +1047,48 @@ impl Resource {
         qs.fetch_data_schema(&self.pool).await
     }
 
+    pub async fn list_data_by_range_page(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<DataSchema>, Option<Cursor>), Error>
+    {
+        self.list_data_group_by_range_page(&[device_id], &[model_id], begin, end, tag, page).await
+    }
+
+    pub async fn list_data_group_by_range_page(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<DataSchema>, Option<Cursor>), Error>
+    {
+        if page.limit == 0 {
+            return Err(Error::InvalidArgument(String::from(PAGE_LIMIT_INVALID)));
+        }
+        let after = match &page.cursor {
+            Some(c) => match (c.timestamp, c.device_id, c.model_id, c.tag) {
+                (Some(timestamp), Some(device_id), Some(model_id), Some(tag)) => Some((timestamp, device_id, model_id, tag)),
+                _ => return Err(Error::InvalidArgument(String::from(CURSOR_INVALID)))
+            },
+            None => None
+        };
+        let qs = data::select_data_page(device_ids, model_ids, begin, end, tag, after, page.limit);
+        let results = qs.fetch_data_schema(&self.pool).await?;
+        let next = match results.last() {
+            Some(d) if results.len() == page.limit => Some(Cursor {
+                timestamp: Some(d.timestamp),
+                device_id: Some(d.device_id),
+                model_id: Some(d.model_id),
+                tag: Some(d.tag),
+                ..Default::default()
+            }),
+            _ => None
+        };
+        Ok((results, next))
+    }
+
+    pub fn stream_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Stream<Item = Result<DataSchema, Error>> + Send + use<>
+    {
+        let selector = DataSelector::Range(begin, end);
+        let qs = data::select_data(selector, device_ids, model_ids, tag);
+        qs.fetch_data_schema_stream(&self.pool)
+    }
+
     pub async fn list_data_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Error>
     {
@@ -1010,6 +1105,69 @@ impl Resource {
         qs.fetch_data_schema(&self.pool).await
     }
 
+    pub async fn list_data_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Error>
+    {
+        let qs = data::select_data_latest(device_ids, model_ids, tag);
+        qs.fetch_data_schema(&self.pool).await
+    }
+
+    pub async fn list_data_bucketed(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, aggregate: DataAggregate, tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Error>
+    {
+        let interval = interval.num_microseconds().filter(|&i| i > 0)
+            .ok_or(Error::InvalidArgument(String::from(BUCKET_INTERVAL_INVALID)))?;
+        // stream the data so only the running aggregate of the current bucket is held in memory
+        let qs = data::select_data_bucketed(interval, &[device_id], &[model_id], begin, end, tag);
+        let mut stream = qs.fetch_data_schema_stream(&self.pool);
+        let mut bucket = DataBucket::new(aggregate);
+        while let Some(data) = stream.next().await {
+            bucket.push(&data?);
+        }
+        Ok(bucket.finish())
+    }
+
+    pub async fn stats_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Error>
+    {
+        self.stats_data_group_by_range(&[device_id], &[model_id], begin, end, tag).await
+    }
+
+    pub async fn stats_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Error>
+    {
+        // stream the data so the statistics are computed without holding all rows in memory
+        let selector = DataSelector::Range(begin, end);
+        let qs = data::select_data(selector, device_ids, model_ids, tag);
+        let mut stream = qs.fetch_data_schema_stream(&self.pool);
+        let mut stats = DataStats::default();
+        while let Some(data) = stream.next().await {
+            stats.push(&data?);
+        }
+        Ok(stats.finish())
+    }
+
+    pub async fn list_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Error>
+    {
+        let slice = self.read_slice(slice_id).await?;
+        self.list_data_by_range(slice.device_id, slice.model_id, slice.timestamp_begin, slice.timestamp_end, tag).await
+    }
+
+    pub async fn stream_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Error>> + Send + use<>, Error>
+    {
+        let slice = self.read_slice(slice_id).await?;
+        Ok(self.stream_data_by_range(slice.device_id, slice.model_id, slice.timestamp_begin, slice.timestamp_end, tag))
+    }
+
+    pub async fn stats_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Error>
+    {
+        let slice = self.read_slice(slice_id).await?;
+        self.stats_data_by_range(slice.device_id, slice.model_id, slice.timestamp_begin, slice.timestamp_end, tag).await
+    }
+
     pub async fn read_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DataSetSchema, Error>
     {
@@ -1050,20 +1208,90 @@ impl Resource {
         qs.fetch_data_set_schema(&self.pool, set_id).await
     }
 
+    pub fn stream_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Stream<Item = Result<DataSetSchema, Error>> + Send + use<>
+    {
+        let selector = DataSelector::Range(begin, end);
+        let qs = data::select_data_set(selector, set_id, tag);
+        qs.fetch_data_set_schema_stream(&self.pool, set_id)
+    }
+
+    pub async fn list_data_by_slice_set(&self, slice_set_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Error>
+    {
+        let slice = self.read_slice_set(slice_set_id).await?;
+        self.list_data_set_by_range(slice.set_id, slice.timestamp_begin, slice.timestamp_end, tag).await
+    }
+
+    pub async fn stats_data_by_slice_set(&self, slice_set_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Error>
+    {
+        let slice = self.read_slice_set(slice_set_id).await?;
+        let set = self.read_set(slice.set_id).await?;
+        let device_ids: Vec<Uuid> = set.members.iter().map(|m| m.device_id).collect();
+        let model_ids: Vec<Uuid> = set.members.iter().map(|m| m.model_id).collect();
+        let stats = self.stats_data_group_by_range(&device_ids, &model_ids, slice.timestamp_begin, slice.timestamp_end, tag).await?;
+        // group query selects every device and model combination so only the set member pairs are kept
+        Ok(stats.into_iter()
+            .filter(|s| set.members.iter().any(|m| m.device_id == s.device_id && m.model_id == s.model_id))
+            .collect())
+    }
+
+    pub async fn list_data_set_aligned(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, align: DataAlign, tag: Option<i16>)
+        -> Result<Vec<DataSetAlignedSchema>, Error>
+    {
+        let interval = interval.num_microseconds().filter(|&i| i > 0)
+            .ok_or(Error::InvalidArgument(String::from(BUCKET_INTERVAL_INVALID)))?;
+        // every aligned bucket is held in memory so the number of buckets is limited
+        let range = (end - begin).num_microseconds().unwrap_or(i64::MAX);
+        if range / interval >= ALIGN_BUCKET_MAX as i64 {
+            return Err(Error::InvalidArgument(format!("{} of {}", ALIGN_BUCKET_EXCEED, ALIGN_BUCKET_MAX)));
+        }
+        let selector = DataSelector::Range(begin, end);
+        let qs = data::select_data_set(selector, set_id, tag);
+        let data = qs.fetch_data_set_schema(&self.pool, set_id).await?;
+        Ok(aggregate::align_data_set(&data, begin, end, interval, align))
+    }
+
+    pub async fn list_data_set_by_number_before(&self, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Error>
+    {
+        let selector = DataSelector::NumberBefore(before, number);
+        let qs = data::select_data_set(selector, set_id, tag);
+        qs.fetch_data_set_schema(&self.pool, set_id).await
+    }
+
+    pub async fn list_data_set_by_number_after(&self, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Error>
+    {
+        let selector = DataSelector::NumberAfter(after, number);
+        let qs = data::select_data_set(selector, set_id, tag);
+        qs.fetch_data_set_schema(&self.pool, set_id).await
+    }
+
     pub async fn create_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-        -> Result<(), Error>
+        -> Result<usize, Error>
     {
+        let violated = self.check_integrity(&[device_id], &[model_id]).await?;
         // get data types then try to convert the data
         let qs = data::select_data_types(&[model_id]);
         let types = qs.fetch_data_types(&self.pool).await?.into_iter().next().unwrap_or_default();
-        let data = ArrayDataValue::from_vec(data).convert(&types)
-            .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
-        let qs = data::insert_data(device_id, model_id, timestamp, &data.to_vec(), tag);
-        qs.execute(&self.pool).await
+        let data = ArrayDataValue::from_vec(data).validate(&types)
+            .map_err(data_type_unmatch)?;
+        let qs = data::insert_data(device_id, model_id, timestamp, &data.to_vec(), tag, DataConflict::Error);
+        qs.execute(&self.pool).await?;
+        Ok(violated)
     }
 
     pub async fn create_data_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
-        -> Result<(), Error>
+        -> Result<usize, Error>
+    {
+        self.create_data_multiple_conflict(device_ids, model_ids, timestamps, data, tags, DataConflict::Error).await
+            .map(|count| count.violated)
+    }
+
+    pub async fn create_data_multiple_conflict(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: DataConflict)
+        -> Result<DataConflictCount, Error>
     {
         // get the number of data and check if all the arrays has the same length
         let number = data.len();
@@ -1071,6 +1299,7 @@ impl Resource {
         if number == 0 || numbers.into_iter().any(|n| n != number) {
             return Err(Error::InvalidArgument(EMPTY_LENGTH_UNMATCH.to_string()))
         }
+        let violated = self.check_integrity(device_ids, model_ids).await?;
         // get data types array from unique model id then try to convert the data array
         let mut model_ids_unique = model_ids.to_vec();
         model_ids_unique.sort();
@@ -1081,13 +1310,325 @@ impl Resource {
         for i in 0..number {
             let index = model_ids_unique.iter().position(|&id_unique| id_unique == model_ids[i]).unwrap_or_default();
             let types = types_vec.get(index).unwrap_or(&Vec::new()).to_vec();
-            let adv = ArrayDataValue::from_vec(data[i]).convert(&types)
-                .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
+            let adv = ArrayDataValue::from_vec(data[i]).validate(&types)
+                .map_err(|index| Error::InvalidArgument(format!("{} at field index {} of data {}", DATA_TYPE_UNMATCH, index, i)))?;
             data_vec.push(adv.to_vec());
         }
         let data_slice: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
-        let qs = data::insert_data_multiple(device_ids, model_ids, timestamps, &data_slice, tags);
-        qs.execute(&self.pool).await
+        let qs = data::insert_data_multiple(device_ids, model_ids, timestamps, &data_slice, tags, conflict);
+        let inserted = qs.fetch_inserted(&self.pool).await?;
+        Ok(DataConflictCount { violated, ..DataConflictCount::from_inserted(number, &inserted) })
+    }
+
+    pub async fn create_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> Result<usize, Error>
+    {
+        let (device_ids, model_ids, data_vec) = self.split_set_data(set_id, data).await?;
+        let violated = self.check_integrity(&device_ids, &model_ids).await?;
+        let number = data_vec.len();
+        let timestamps = vec![timestamp; number];
+        let tags = tag.map(|t| vec![t; number]);
+        let data_slice: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
+        // all member rows are inserted in one transaction so a set row is never partially written
+        let mut tx = self.pool.begin().await?;
+        let qs = data::insert_data_multiple(&device_ids, &model_ids, &timestamps, &data_slice, tags.as_deref(), DataConflict::Error);
+        qs.execute_transaction(&mut tx).await?;
+        tx.commit().await?;
+        Ok(violated)
+    }
+
+    async fn split_set_data(&self, set_id: Uuid, data: &[DataValue])
+        -> Result<(Vec<Uuid>, Vec<Uuid>, Vec<Vec<DataValue>>), Error>
+    {
+        // set members are sorted by set position so member data is laid out in order on merged data
+        let set = self.read_set(set_id).await?;
+        let number = set.members.iter().fold(0, |acc, m| acc + m.data_index.len());
+        if set.members.is_empty() || data.len() != number {
+            return Err(Error::InvalidArgument(String::from(SET_LENGTH_UNMATCH)));
+        }
+        let mut model_ids_unique: Vec<Uuid> = set.members.iter().map(|m| m.model_id).collect();
+        model_ids_unique.sort();
+        model_ids_unique.dedup();
+        let qs = data::select_data_types(&model_ids_unique);
+        let types_vec = qs.fetch_data_types(&self.pool).await?;
+        let mut device_ids = Vec::new();
+        let mut model_ids = Vec::new();
+        let mut data_vec = Vec::new();
+        let mut position = 0;
+        for member in set.members {
+            let index = model_ids_unique.iter().position(|&id_unique| id_unique == member.model_id).unwrap_or_default();
+            let types = types_vec.get(index).unwrap_or(&Vec::new()).to_vec();
+            // place each merged value on the member model field pointed by data index
+            let mut values = vec![DataValue::Null; types.len()];
+            for (offset, data_index) in member.data_index.iter().enumerate() {
+                let type_ = types.get(*data_index as usize)
+                    .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
+                values[*data_index as usize] = data[position + offset].clone().convert(type_.clone())
+                    .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
+            }
+            // a model field which is not a part of the set can not be filled
+            if values.contains(&DataValue::Null) {
+                return Err(Error::InvalidArgument(String::from(SET_MEMBER_INCOMPLETE)));
+            }
+            position += member.data_index.len();
+            device_ids.push(member.device_id);
+            model_ids.push(member.model_id);
+            data_vec.push(values);
+        }
+        Ok((device_ids, model_ids, data_vec))
+    }
+
+    pub async fn subscribe_data(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Error>> + Send + use<>, Error>
+    {
+        // subscribe to the shared insert listener before returning the stream
+        let mut receiver = self.data_notifier().await?;
+        let device_ids = device_ids.to_vec();
+        let model_ids = model_ids.to_vec();
+        let (tx, rx) = mpsc::channel(SUBSCRIBE_CHANNEL_SIZE);
+        tokio::spawn(async move {
+            loop {
+                // stop receiving when the receiver is dropped
+                let item = tokio::select! {
+                    result = receiver.recv() => result,
+                    _ = tx.closed() => break
+                };
+                let result = match item {
+                    Ok(Ok(data)) => {
+                        if (!device_ids.is_empty() && !device_ids.contains(&data.device_id))
+                            || (!model_ids.is_empty() && !model_ids.contains(&data.model_id))
+                            || tag.is_some_and(|t| t != data.tag)
+                        {
+                            continue;
+                        }
+                        Ok(data.as_ref().clone())
+                    },
+                    Ok(Err(message)) => Err(Error::Protocol(message)),
+                    Err(broadcast::error::RecvError::Lagged(number)) => Err(Error::Protocol(format!("{} {}", SUBSCRIBE_LAGGED, number))),
+                    Err(broadcast::error::RecvError::Closed) => break
+                };
+                let error = result.is_err();
+                if tx.send(result).await.is_err() || error {
+                    break;
+                }
+            }
+        });
+        Ok(ReceiverStream::new(rx))
+    }
+
+    async fn data_notifier(&self) -> Result<broadcast::Receiver<DataNotifyItem>, Error>
+    {
+        let mut slot = self.notifier.sender.lock().await;
+        if let Some(sender) = slot.as_ref() {
+            return Ok(sender.subscribe());
+        }
+        // start the shared listener on the first subscription
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(data::DATA_NOTIFY_CHANNEL).await?;
+        let (sender, receiver) = broadcast::channel(SUBSCRIBE_CHANNEL_SIZE);
+        *slot = Some(sender.clone());
+        drop(slot);
+        let resource = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let (item, failed) = match listener.recv().await {
+                    Ok(notification) => {
+                        let notify = match DataNotify::from_payload(notification.payload()) {
+                            Some(value) => value,
+                            None => continue
+                        };
+                        match resource.notify_data(notify).await {
+                            Ok(data) => (Ok(Arc::new(data)), false),
+                            Err(Error::RowNotFound) => continue,
+                            Err(e) => (Err(e.to_string()), false)
+                        }
+                    },
+                    Err(e) => (Err(e.to_string()), true)
+                };
+                // stop listening when all subscribers are gone or the listener failed,
+                // the next subscription starts a new listener
+                let mut slot = resource.notifier.sender.lock().await;
+                if sender.send(item).is_err() || failed {
+                    *slot = None;
+                    break;
+                }
+            }
+        });
+        Ok(receiver)
+    }
+
+    async fn notify_data(&self, notify: DataNotify)
+        -> Result<DataSchema, Error>
+    {
+        if let Some(data) = notify.data {
+            return Ok(DataSchema {
+                device_id: notify.device_id,
+                model_id: notify.model_id,
+                timestamp: notify.timestamp,
+                data,
+                tag: notify.tag
+            });
+        }
+        // read back the inserted row when the payload doesn't carry row data, skip the row if already deleted
+        match notify.buffer_id {
+            Some(id) => self.read_buffer(id).await.map(|b| DataSchema {
+                device_id: b.device_id,
+                model_id: b.model_id,
+                timestamp: b.timestamp,
+                data: b.data,
+                tag: b.tag
+            }),
+            None => self.list_data_by_time(notify.device_id, notify.model_id, notify.timestamp, None).await
+                .and_then(|v| v.into_iter().find(|d| d.tag == notify.tag).ok_or(Error::RowNotFound))
+        }
+    }
+
+    pub async fn export_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Error>> + Send + use<>, Error>
+    {
+        let model = self.read_model(model_id).await?;
+        let exporter = Exporter::new::<DataSchema>(format, &export::model_columns(&model))?;
+        let stream = self.stream_data_by_range(device_id, model_id, begin, end, tag);
+        Ok(export::export_stream(stream, exporter))
+    }
+
+    pub async fn export_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Error>> + Send + use<>, Error>
+    {
+        let model = self.read_model(model_id).await?;
+        let exporter = Exporter::new::<BufferSchema>(format, &export::model_columns(&model))?;
+        let stream = self.stream_buffer_by_range(device_id, model_id, begin, end, tag);
+        Ok(export::export_stream(stream, exporter))
+    }
+
+    pub async fn export_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Error>> + Send + use<>, Error>
+    {
+        let set = self.read_set(set_id).await?;
+        let mut model_ids: Vec<Uuid> = set.members.iter().map(|m| m.model_id).collect();
+        model_ids.sort();
+        model_ids.dedup();
+        let models = self.list_model_by_ids(&model_ids).await?;
+        let exporter = Exporter::new::<DataSetSchema>(format, &export::set_columns(&set, &models))?;
+        let stream = self.stream_data_set_by_range(set_id, begin, end, tag);
+        Ok(export::export_stream(stream, exporter))
+    }
+
+    pub async fn ingest_data(&self, data: &[DataSchema])
+        -> Result<(usize, Vec<(usize, String)>), Error>
+    {
+        let rows: Vec<(Uuid, Uuid, &[DataValue])> = data.iter().map(|d| (d.device_id, d.model_id, d.data.as_slice())).collect();
+        let (converted, mut rejected, violations) = self.ingest_convert(&rows).await?;
+        if converted.is_empty() {
+            return Ok((0, rejected));
+        }
+        let device_ids: Vec<Uuid> = converted.iter().map(|(i, _)| data[*i].device_id).collect();
+        let model_ids: Vec<Uuid> = converted.iter().map(|(i, _)| data[*i].model_id).collect();
+        let timestamps: Vec<DateTime<Utc>> = converted.iter().map(|(i, _)| data[*i].timestamp).collect();
+        let tags: Vec<i16> = converted.iter().map(|(i, _)| data[*i].tag).collect();
+        let data_slice: Vec<&[DataValue]> = converted.iter().map(|(_, d)| d.as_slice()).collect();
+        let qs = data::insert_data_ingest(&device_ids, &model_ids, &timestamps, &data_slice, &tags);
+        let mut inserted: HashSet<DataKey> = qs.fetch_inserted_key(&self.pool).await?.into_iter().collect();
+        // rows without returned key conflict with an existing row or an earlier row of the same batch
+        let mut violated = 0;
+        for (i, _) in converted {
+            let key = (data[i].device_id, data[i].model_id, data[i].timestamp.timestamp_micros(), data[i].tag);
+            if !inserted.remove(&key) {
+                rejected.push((i, String::from(DATA_CONFLICT)));
+            }
+            else if violations.contains(&(data[i].device_id, data[i].model_id)) {
+                violated += 1;
+            }
+        }
+        rejected.sort_by_key(|(i, _)| *i);
+        Ok((violated, rejected))
+    }
+
+    pub async fn import_data(&self, data: &[DataSchema], conflict: DataConflict)
+        -> Result<(DataConflictCount, Vec<(usize, String)>), Error>
+    {
+        let rows: Vec<(Uuid, Uuid, &[DataValue])> = data.iter().map(|d| (d.device_id, d.model_id, d.data.as_slice())).collect();
+        let (converted, mut rejected, violations) = self.ingest_convert(&rows).await?;
+        if converted.is_empty() {
+            return Ok((DataConflictCount::default(), rejected));
+        }
+        let violated = converted.iter()
+            .filter(|(i, _)| violations.contains(&(data[*i].device_id, data[*i].model_id)))
+            .count();
+        let device_ids: Vec<Uuid> = converted.iter().map(|(i, _)| data[*i].device_id).collect();
+        let model_ids: Vec<Uuid> = converted.iter().map(|(i, _)| data[*i].model_id).collect();
+        let timestamps: Vec<DateTime<Utc>> = converted.iter().map(|(i, _)| data[*i].timestamp).collect();
+        let tags: Vec<i16> = converted.iter().map(|(i, _)| data[*i].tag).collect();
+        let data_slice: Vec<&[DataValue]> = converted.iter().map(|(_, d)| d.as_slice()).collect();
+        let qs = data::insert_data_multiple(&device_ids, &model_ids, &timestamps, &data_slice, Some(&tags), conflict);
+        let count = match qs.fetch_inserted(&self.pool).await {
+            Ok(inserted) => DataConflictCount { violated, ..DataConflictCount::from_inserted(converted.len(), &inserted) },
+            Err(Error::Database(_)) => {
+                // multiple rows insertion failed, insert the rows one by one to find the rejected rows
+                let mut count = DataConflictCount::default();
+                let violations = &violations;
+                for (i, values) in converted {
+                    let qs = data::insert_data(data[i].device_id, data[i].model_id, data[i].timestamp, &values, Some(data[i].tag), conflict);
+                    match qs.fetch_inserted(&self.pool).await {
+                        Ok(inserted) => {
+                            let row_count = DataConflictCount::from_inserted(1, &inserted);
+                            count.inserted += row_count.inserted;
+                            count.skipped += row_count.skipped;
+                            count.overwritten += row_count.overwritten;
+                            if violations.contains(&(data[i].device_id, data[i].model_id)) {
+                                count.violated += 1;
+                            }
+                        },
+                        Err(Error::Database(e)) => rejected.push((i, e.message().to_string())),
+                        Err(e) => return Err(e)
+                    }
+                }
+                rejected.sort_by_key(|(i, _)| *i);
+                count
+            },
+            Err(e) => return Err(e)
+        };
+        Ok((count, rejected))
+    }
+
+    async fn ingest_convert(&self, rows: &[(Uuid, Uuid, &[DataValue])])
+        -> Result<(Vec<(usize, Vec<DataValue>)>, Vec<(usize, String)>, HashSet<(Uuid, Uuid)>), Error>
+    {
+        let device_ids: Vec<Uuid> = rows.iter().map(|(device_id, _, _)| *device_id).collect();
+        let model_ids: Vec<Uuid> = rows.iter().map(|(_, model_id, _)| *model_id).collect();
+        let violations = self.integrity_violations(&device_ids, &model_ids).await?;
+        // get data types of every unique model id
+        let mut model_ids_unique: Vec<Uuid> = model_ids.clone();
+        model_ids_unique.sort();
+        model_ids_unique.dedup();
+        let mut types_vec = Vec::new();
+        for model_id in &model_ids_unique {
+            let qs = data::select_data_types(from_ref(model_id));
+            types_vec.push(qs.fetch_data_types(&self.pool).await?.into_iter().next());
+        }
+        // try to convert the data array, rows with unknown model, unmatched type, or strict mode integrity violation are rejected
+        let mut converted = Vec::new();
+        let mut rejected = Vec::new();
+        for (i, (device_id, model_id, values)) in rows.iter().enumerate() {
+            if self.integrity == IntegrityMode::Strict && violations.contains(&(*device_id, *model_id)) {
+                rejected.push((i, model_not_in_device(*device_id, *model_id)));
+                continue;
+            }
+            let index = model_ids_unique.iter().position(|id| id == model_id).unwrap_or_default();
+            let types = match &types_vec[index] {
+                Some(types) => types,
+                None => {
+                    rejected.push((i, String::from(MODEL_NOT_FOUND)));
+                    continue;
+                }
+            };
+            match ArrayDataValue::from_vec(values).validate(types) {
+                Ok(adv) => converted.push((i, adv.to_vec())),
+                Err(index) => rejected.push((i, format!("{} at field index {}", DATA_TYPE_UNMATCH, index)))
+            }
+        }
+        // violations of warn mode rows are returned so the written rows are counted by the caller
+        Ok((converted, rejected, violations))
     }
 
     pub async fn delete_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
@@ -1097,6 +1638,114 @@ impl Resource {
         qs.execute(&self.pool).await
     }
 
+    pub async fn update_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>, data: Option<&[DataValue]>, new_tag: Option<i16>)
+        -> Result<(), Error>
+    {
+        self.update_data_changed_by(device_id, model_id, timestamp, tag, data, new_tag, "").await
+    }
+
+    pub async fn update_data_changed_by(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>, data: Option<&[DataValue]>, new_tag: Option<i16>, changed_by: &str)
+        -> Result<(), Error>
+    {
+        if data.is_none() && new_tag.is_none() {
+            return Ok(());
+        }
+        // get data types then try to convert the data
+        let qs = data::select_data_types(&[model_id]);
+        let types = qs.fetch_data_types(&self.pool).await?.into_iter().next().unwrap_or_default();
+        let data = match data {
+            Some(d) => Some(ArrayDataValue::from_vec(d).validate(&types)
+                .map_err(data_type_unmatch)?.to_vec()),
+            None => None
+        };
+        let mut tx = self.pool.begin().await?;
+        let qs = transition::select_changed_by(changed_by);
+        qs.execute_transaction(&mut tx).await?;
+        let qs = data::update_data(device_id, model_id, timestamp, tag, data.as_deref(), new_tag);
+        qs.execute_transaction(&mut tx).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn update_data_by_range(&self, update: DataRangeUpdate)
+        -> Result<usize, Error>
+    {
+        self.update_data_by_range_changed_by(update, "").await
+    }
+
+    pub async fn update_data_by_range_changed_by(&self, update: DataRangeUpdate, changed_by: &str)
+        -> Result<usize, Error>
+    {
+        let DataRangeUpdate { device_id, model_id, begin, end, tag, index, value } = update;
+        // convert the value to the type of model field pointed by index
+        let qs = data::select_data_types(&[model_id]);
+        let types = qs.fetch_data_types(&self.pool).await?.into_iter().next().unwrap_or_default();
+        let type_ = types.get(index)
+            .ok_or(Error::InvalidArgument(String::from(DATA_INDEX_INVALID)))?;
+        let value = value.convert(type_.clone())
+            .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
+        // rows inside the range are read and locked page by page in the same transaction as the rewrite
+        // so concurrent changes between read and write are not overwritten and only one page is held in memory
+        let mut tx = self.pool.begin().await?;
+        let qs = transition::select_changed_by(changed_by);
+        qs.execute_transaction(&mut tx).await?;
+        let mut after = None;
+        let mut count = 0;
+        loop {
+            let qs = data::select_data_range_lock(device_id, model_id, begin, end, tag, after, DATA_UPDATE_CHUNK);
+            let mut rows = qs.fetch_data_schema_transaction(&mut tx).await?;
+            let last = match rows.last() {
+                Some(row) => (row.timestamp, row.tag),
+                None => break
+            };
+            for row in rows.iter_mut() {
+                if let Some(field) = row.data.get_mut(index) {
+                    *field = value.clone();
+                }
+            }
+            let values: Vec<(DateTime<Utc>, i16, &[DataValue])> = rows.iter()
+                .map(|row| (row.timestamp, row.tag, row.data.as_slice()))
+                .collect();
+            let qs = data::update_data_multiple(device_id, model_id, &values);
+            qs.execute_transaction(&mut tx).await?;
+            count += rows.len();
+            if rows.len() < DATA_UPDATE_CHUNK {
+                break;
+            }
+            after = Some(last);
+        }
+        tx.commit().await?;
+        Ok(count)
+    }
+
+    pub async fn list_data_audit_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> Result<Vec<DataAuditSchema>, Error>
+    {
+        let qs = audit::select_data_audit(device_id, model_id, begin, end);
+        qs.fetch_data_audit_schema(&self.pool).await
+    }
+
+    pub async fn delete_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Error>
+    {
+        self.delete_data_group_by_range(&[device_id], &[model_id], begin, end, tag, dry_run).await
+    }
+
+    pub async fn delete_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Error>
+    {
+        // empty id lists are rejected on both dry run and delete so the reported number is the deleted number
+        if device_ids.is_empty() || model_ids.is_empty() {
+            return Err(Error::InvalidArgument(String::from(DELETE_RANGE_EMPTY)));
+        }
+        if dry_run {
+            let qs = data::count_data(DataSelector::Range(begin, end), device_ids, model_ids, tag);
+            return qs.fetch_count(&self.pool).await;
+        }
+        let qs = data::delete_data_by_range(device_ids, model_ids, begin, end, tag);
+        qs.execute_count(&self.pool).await
+    }
+
     pub async fn read_data_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Error>
     {
@@ -1217,6 +1866,62 @@ impl Resource {
         qs.fetch_count(&self.pool).await
     }
 
+    pub async fn aggregate_data(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+        -> Result<usize, Error>
+    {
+        self.aggregate_data_group(&[device_id], &[model_id], begin, end, tag).await
+    }
+
+    pub async fn aggregate_data_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+        -> Result<usize, Error>
+    {
+        let (tag_avg, tag_min, tag_max) = aggregate::aggregate_tags(tag)
+            .ok_or(Error::InvalidArgument(String::from(AGGREGATE_TAG_INVALID)))?;
+        let mut window = DataWindow::new(tag)
+            .ok_or(Error::InvalidArgument(String::from(AGGREGATE_TAG_INVALID)))?;
+        // expand input range so all aggregation windows are complete
+        let begin = aggregate::aggregate_floor(begin, tag);
+        let end = aggregate::aggregate_ceil(end, tag);
+        // replace previous aggregation results inside the range with the new results
+        let mut tx = self.pool.begin().await?;
+        let qs = aggregate::delete_aggregate(device_ids, model_ids, begin, end, &[tag_avg, tag_min, tag_max]);
+        qs.execute_transaction(&mut tx).await?;
+        // stream the source data so only the running aggregates and a chunk of results are held in memory
+        let qs = aggregate::select_aggregate_source(device_ids, model_ids, begin, end);
+        let mut stream = qs.fetch_data_schema_stream(&self.pool);
+        let mut results = Vec::new();
+        let mut count = 0;
+        while let Some(data) = stream.next().await {
+            window.push(&data?);
+            results.extend(window.take());
+            if results.len() >= AGGREGATE_INSERT_CHUNK {
+                count += results.len();
+                Self::insert_aggregate(&mut tx, &results).await?;
+                results.clear();
+            }
+        }
+        results.extend(window.finish());
+        count += results.len();
+        Self::insert_aggregate(&mut tx, &results).await?;
+        tx.commit().await?;
+        Ok(count)
+    }
+
+    async fn insert_aggregate(tx: &mut Transaction<'_, Postgres>, results: &[DataSchema])
+        -> Result<(), Error>
+    {
+        if results.is_empty() {
+            return Ok(());
+        }
+        let device_ids: Vec<Uuid> = results.iter().map(|d| d.device_id).collect();
+        let model_ids: Vec<Uuid> = results.iter().map(|d| d.model_id).collect();
+        let timestamps: Vec<DateTime<Utc>> = results.iter().map(|d| d.timestamp).collect();
+        let data: Vec<&[DataValue]> = results.iter().map(|d| d.data.as_slice()).collect();
+        let tags: Vec<i16> = results.iter().map(|d| d.tag).collect();
+        let qs = data::insert_data_multiple(&device_ids, &model_ids, &timestamps, &data, Some(&tags), DataConflict::Error);
+        qs.execute_transaction(tx).await
+    }
+
     pub async fn read_buffer(&self, id: i32)
         -> Result<BufferSchema, Error>
     {
@@ -1271,6 +1976,14 @@ impl Resource {
         qs.fetch_buffer_schema(&self.pool).await
     }
 
+    pub fn stream_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Stream<Item = Result<BufferSchema, Error>> + Send + use<>
+    {
+        let selector = BufferSelector::Range(begin, end);
+        let qs = buffer::select_buffer(selector, None, Some(&[device_id]), Some(&[model_id]), tag);
+        qs.fetch_buffer_schema_stream(&self.pool)
+    }
+
     pub async fn list_buffer_by_number_before(&self, device_id: Uuid, model_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Error>
     {
@@ -1367,6 +2080,46 @@ impl Resource {
         qs.fetch_buffer_schema(&self.pool).await
     }
 
+    pub async fn list_buffer_by_range_page(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<BufferSchema>, Option<Cursor>), Error>
+    {
+        self.list_buffer_group_by_range_page(&[device_id], &[model_id], begin, end, tag, page).await
+    }
+
+    pub async fn list_buffer_group_by_range_page(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<BufferSchema>, Option<Cursor>), Error>
+    {
+        if page.limit == 0 {
+            return Err(Error::InvalidArgument(String::from(PAGE_LIMIT_INVALID)));
+        }
+        let after = match &page.cursor {
+            Some(c) => match (c.timestamp, c.id) {
+                (Some(timestamp), Some(id)) => Some((timestamp, id)),
+                _ => return Err(Error::InvalidArgument(String::from(CURSOR_INVALID)))
+            },
+            None => None
+        };
+        let qs = buffer::select_buffer_page(device_ids, model_ids, begin, end, tag, after, page.limit);
+        let results = qs.fetch_buffer_schema(&self.pool).await?;
+        let next = match results.last() {
+            Some(b) if results.len() == page.limit => Some(Cursor {
+                timestamp: Some(b.timestamp),
+                id: Some(b.id),
+                ..Default::default()
+            }),
+            _ => None
+        };
+        Ok((results, next))
+    }
+
+    pub fn stream_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> impl Stream<Item = Result<BufferSchema, Error>> + Send + use<>
+    {
+        let selector = BufferSelector::Range(begin, end);
+        let qs = buffer::select_buffer(selector, None, Some(device_ids), Some(model_ids), tag);
+        qs.fetch_buffer_schema_stream(&self.pool)
+    }
+
     pub async fn list_buffer_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Error>
     {
@@ -1471,20 +2224,45 @@ impl Resource {
         qs.fetch_buffer_set_schema(&self.pool, set_id).await
     }
 
+    pub async fn list_buffer_set_by_number_before(&self, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<BufferSetSchema>, Error>
+    {
+        let selector = BufferSelector::NumberBefore(before, number);
+        let qs = buffer::select_buffer_set(selector, set_id, tag);
+        qs.fetch_buffer_set_schema(&self.pool, set_id).await
+    }
+
+    pub async fn list_buffer_set_by_number_after(&self, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<BufferSetSchema>, Error>
+    {
+        let selector = BufferSelector::NumberAfter(after, number);
+        let qs = buffer::select_buffer_set(selector, set_id, tag);
+        qs.fetch_buffer_set_schema(&self.pool, set_id).await
+    }
+
     pub async fn create_buffer(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-        -> Result<i32, Error>
+        -> Result<(i32, usize), Error>
     {
+        let violated = self.check_integrity(&[device_id], &[model_id]).await?;
         // get data types then try to convert the data
         let qs = data::select_data_types(&[model_id]);
         let types = qs.fetch_data_types(&self.pool).await?.into_iter().next().unwrap_or_default();
-        let data = ArrayDataValue::from_vec(data).convert(&types)
-            .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?.to_vec();
-        let qs = buffer::insert_buffer(device_id, model_id, timestamp, &data, tag);
-        qs.fetch_id(&self.pool).await
+        let data = ArrayDataValue::from_vec(data).validate(&types)
+            .map_err(data_type_unmatch)?.to_vec();
+        let qs = buffer::insert_buffer(device_id, model_id, timestamp, &data, tag, DataConflict::Error);
+        let id = qs.fetch_id(&self.pool).await?;
+        Ok((id, violated))
     }
 
     pub async fn create_buffer_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
-        -> Result<Vec<i32>, Error>
+        -> Result<(Vec<i32>, usize), Error>
+    {
+        self.create_buffer_multiple_conflict(device_ids, model_ids, timestamps, data, tags, DataConflict::Error).await
+            .map(|(ids, count)| (ids, count.violated))
+    }
+
+    pub async fn create_buffer_multiple_conflict(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: DataConflict)
+        -> Result<(Vec<i32>, DataConflictCount), Error>
     {
         // get the number of data and check if all the arrays has the same length
         let number = data.len();
@@ -1492,6 +2270,7 @@ impl Resource {
         if number == 0 || numbers.into_iter().any(|n| n != number) {
             return Err(Error::InvalidArgument(EMPTY_LENGTH_UNMATCH.to_string()))
         }
+        let violated = self.check_integrity(device_ids, model_ids).await?;
         // get data types array from unique model id then try to convert the data array
         let mut model_ids_unique = model_ids.to_vec();
         model_ids_unique.sort();
@@ -1502,46 +2281,129 @@ impl Resource {
         for i in 0..number {
             let index = model_ids_unique.iter().position(|&id_unique| id_unique == model_ids[i]).unwrap_or_default();
             let types = types_vec.get(index).unwrap_or(&Vec::new()).to_vec();
-            let adv = ArrayDataValue::from_vec(data[i]).convert(&types)
-                .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?;
+            let adv = ArrayDataValue::from_vec(data[i]).validate(&types)
+                .map_err(|index| Error::InvalidArgument(format!("{} at field index {} of data {}", DATA_TYPE_UNMATCH, index, i)))?;
             data_vec.push(adv.to_vec());
         }
         let data_slice: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
-        let qs = buffer::insert_buffer_multiple(device_ids, model_ids, timestamps, &data_slice, tags);
-        let id = qs.fetch_id(&self.pool).await?;
-        Ok((id..id+number as i32).collect())
+        let qs = buffer::insert_buffer_multiple(device_ids, model_ids, timestamps, &data_slice, tags, conflict);
+        let (ids, inserted): (Vec<i32>, Vec<bool>) = qs.fetch_id_inserted(&self.pool).await?.into_iter().unzip();
+        Ok((ids, DataConflictCount { violated, ..DataConflictCount::from_inserted(number, &inserted) }))
+    }
+
+    pub async fn create_buffer_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> Result<(Vec<i32>, usize), Error>
+    {
+        let (device_ids, model_ids, data_vec) = self.split_set_data(set_id, data).await?;
+        let violated = self.check_integrity(&device_ids, &model_ids).await?;
+        let number = data_vec.len();
+        let timestamps = vec![timestamp; number];
+        let tags = tag.map(|t| vec![t; number]);
+        let data_slice: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
+        // all member rows are inserted in one transaction so a set row is never partially written
+        let mut tx = self.pool.begin().await?;
+        let qs = buffer::insert_buffer_multiple(&device_ids, &model_ids, &timestamps, &data_slice, tags.as_deref(), DataConflict::Error);
+        let ids = qs.fetch_ids_transaction(&mut tx).await?;
+        tx.commit().await?;
+        Ok((ids, violated))
+    }
+
+    pub async fn ingest_buffer(&self, buffers: &[BufferSchema])
+        -> Result<(usize, Vec<(usize, String)>), Error>
+    {
+        let rows: Vec<(Uuid, Uuid, &[DataValue])> = buffers.iter().map(|b| (b.device_id, b.model_id, b.data.as_slice())).collect();
+        let (converted, mut rejected, violations) = self.ingest_convert(&rows).await?;
+        if converted.is_empty() {
+            return Ok((0, rejected));
+        }
+        let device_ids: Vec<Uuid> = converted.iter().map(|(i, _)| buffers[*i].device_id).collect();
+        let model_ids: Vec<Uuid> = converted.iter().map(|(i, _)| buffers[*i].model_id).collect();
+        let timestamps: Vec<DateTime<Utc>> = converted.iter().map(|(i, _)| buffers[*i].timestamp).collect();
+        let tags: Vec<i16> = converted.iter().map(|(i, _)| buffers[*i].tag).collect();
+        let data_slice: Vec<&[DataValue]> = converted.iter().map(|(_, d)| d.as_slice()).collect();
+        let qs = buffer::insert_buffer_ingest(&device_ids, &model_ids, &timestamps, &data_slice, &tags);
+        let mut inserted: HashSet<DataKey> = qs.fetch_inserted_key(&self.pool).await?.into_iter().collect();
+        // rows without returned key conflict with an existing row or an earlier row of the same batch
+        let mut violated = 0;
+        for (i, _) in converted {
+            let key = (buffers[i].device_id, buffers[i].model_id, buffers[i].timestamp.timestamp_micros(), buffers[i].tag);
+            if !inserted.remove(&key) {
+                rejected.push((i, String::from(DATA_CONFLICT)));
+            }
+            else if violations.contains(&(buffers[i].device_id, buffers[i].model_id)) {
+                violated += 1;
+            }
+        }
+        rejected.sort_by_key(|(i, _)| *i);
+        Ok((violated, rejected))
     }
 
     pub async fn update_buffer(&self, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
         -> Result<(), Error>
+    {
+        self.update_buffer_changed_by(id, data, tag, "").await
+    }
+
+    pub async fn update_buffer_changed_by(&self, id: i32, data: Option<&[DataValue]>, tag: Option<i16>, changed_by: &str)
+        -> Result<(), Error>
     {
         // get data types then try to convert the data
         let qs = buffer::select_buffer_types(id);
         let types = qs.fetch_buffer_types(&self.pool).await?;
         let data = match data {
-            Some(d) => Some(ArrayDataValue::from_vec(d).convert(&types)
-                .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?.to_vec()),
+            Some(d) => Some(ArrayDataValue::from_vec(d).validate(&types)
+                .map_err(data_type_unmatch)?.to_vec()),
             None => None
         };
         let data = data.as_ref().map(|d| d.as_slice());
-        let qs = buffer::update_buffer(Some(id), None, None, None, data, tag);
-        qs.execute(&self.pool).await
+        let qs = buffer::select_buffer_id_lock(BufferSelector::None, Some(&[id]), None, None, None);
+        self.update_buffer_locked(qs, data, tag, changed_by).await
     }
 
     pub async fn update_buffer_by_time(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>)
         -> Result<(), Error>
+    {
+        self.update_buffer_by_time_changed_by(device_id, model_id, timestamp, data, tag, "").await
+    }
+
+    pub async fn update_buffer_by_time_changed_by(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: Option<&[DataValue]>, tag: Option<i16>, changed_by: &str)
+        -> Result<(), Error>
     {
         // get data types then try to convert the data
         let qs = data::select_data_types(&[model_id]);
         let types = qs.fetch_buffer_types(&self.pool).await?;
         let data = match data {
-            Some(d) => Some(ArrayDataValue::from_vec(d).convert(&types)
-                .ok_or(Error::InvalidArgument(String::from(DATA_TYPE_UNMATCH)))?.to_vec()),
+            Some(d) => Some(ArrayDataValue::from_vec(d).validate(&types)
+                .map_err(data_type_unmatch)?.to_vec()),
             None => None
         };
         let data = data.as_ref().map(|d| d.as_slice());
-        let qs = buffer::update_buffer(None, Some(device_id), Some(model_id), Some(timestamp), data, tag);
-        qs.execute(&self.pool).await
+        // the tag only selects buffers so the tag of selected buffers is kept
+        let qs = buffer::select_buffer_id_lock(BufferSelector::Time(timestamp), None, Some(&[device_id]), Some(&[model_id]), tag);
+        self.update_buffer_locked(qs, data, None, changed_by).await
+    }
+
+    async fn update_buffer_locked(&self, lock_qs: QueryStatement, data: Option<&[DataValue]>, tag: Option<i16>, changed_by: &str)
+        -> Result<(), Error>
+    {
+        // selected buffers are locked so their current tags can't change before the new tag is validated and written
+        let mut tx = self.pool.begin().await?;
+        let id_tags = lock_qs.fetch_id_tags_transaction(&mut tx).await?;
+        if id_tags.is_empty() {
+            return Ok(());
+        }
+        let (ids, mut tags): (Vec<i32>, Vec<i16>) = id_tags.into_iter().unzip();
+        if let Some(tag) = tag {
+            tags.sort();
+            tags.dedup();
+            self.validate_buffer_transition(&tags, tag).await?;
+        }
+        // tag change is recorded to buffer history by database trigger with the changer from the transaction setting
+        let qs = transition::select_changed_by(changed_by);
+        qs.execute_transaction(&mut tx).await?;
+        let qs = buffer::update_buffer_by_ids(&ids, data, tag);
+        qs.execute_transaction(&mut tx).await?;
+        tx.commit().await
     }
 
     pub async fn delete_buffer(&self, id: i32)
@@ -1558,6 +2420,168 @@ impl Resource {
         qs.execute(&self.pool).await
     }
 
+    pub async fn delete_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Error>
+    {
+        self.delete_buffer_group_by_range(&[device_id], &[model_id], begin, end, tag, dry_run).await
+    }
+
+    pub async fn delete_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Error>
+    {
+        // empty id lists are rejected on both dry run and delete so the reported number is the deleted number
+        if device_ids.is_empty() || model_ids.is_empty() {
+            return Err(Error::InvalidArgument(String::from(DELETE_RANGE_EMPTY)));
+        }
+        if dry_run {
+            let qs = buffer::count_buffer(BufferSelector::Range(begin, end), device_ids, model_ids, tag);
+            return qs.fetch_count(&self.pool).await;
+        }
+        let qs = buffer::delete_buffer_by_range(device_ids, model_ids, begin, end, tag);
+        qs.execute_count(&self.pool).await
+    }
+
+    pub async fn transfer_buffer_to_data(&self, selector: BufferSelector, ids: Option<&[i32]>, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>, target_tag: Option<i16>)
+        -> Result<usize, Error>
+    {
+        // selected buffers are locked, copied to data, and deleted in one transaction
+        // so a conflict with existing data rolls back the whole transfer
+        let mut tx = self.pool.begin().await?;
+        let qs = buffer::select_buffer_id_lock(selector, ids, device_ids, model_ids, tag);
+        let buffer_ids = qs.fetch_ids_transaction(&mut tx).await?;
+        for chunk in buffer_ids.chunks(BUFFER_TRANSFER_CHUNK) {
+            let qs = buffer::insert_data_from_buffer(chunk, target_tag);
+            qs.execute_transaction(&mut tx).await?;
+            let qs = buffer::delete_buffer_by_ids(chunk);
+            qs.execute_transaction(&mut tx).await?;
+        }
+        tx.commit().await?;
+        Ok(buffer_ids.len())
+    }
+
+    pub async fn transfer_buffer_by_ids(&self, ids: &[i32], target_tag: Option<i16>)
+        -> Result<usize, Error>
+    {
+        self.transfer_buffer_to_data(BufferSelector::None, Some(ids), None, None, None, target_tag).await
+    }
+
+    pub async fn transfer_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, target_tag: Option<i16>)
+        -> Result<usize, Error>
+    {
+        self.transfer_buffer_group_by_range(&[device_id], &[model_id], begin, end, tag, target_tag).await
+    }
+
+    pub async fn transfer_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, target_tag: Option<i16>)
+        -> Result<usize, Error>
+    {
+        self.transfer_buffer_to_data(BufferSelector::Range(begin, end), None, Some(device_ids), Some(model_ids), tag, target_tag).await
+    }
+
+    pub async fn claim_buffer(&self, number: usize, tag: i16, lease_duration: Duration, worker_id: &str)
+        -> Result<Vec<BufferSchema>, Error>
+    {
+        let lease_duration = lease_duration.num_microseconds().filter(|&d| d > 0)
+            .ok_or(Error::InvalidArgument(String::from(LEASE_DURATION_INVALID)))?;
+        // claimed buffers are leased to the worker and return to the queue when the lease expires
+        let mut tx = self.pool.begin().await?;
+        let qs = buffer::select_buffer_claim(number, tag);
+        let ids = qs.fetch_ids_transaction(&mut tx).await?;
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let qs = buffer::update_buffer_lease(&ids, worker_id, lease_duration);
+        qs.execute_transaction(&mut tx).await?;
+        tx.commit().await?;
+        self.list_buffer_by_ids(&ids).await
+    }
+
+    pub async fn ack_buffer(&self, ids: &[i32], worker_id: &str, tag: i16)
+        -> Result<(usize, Vec<i32>), Error>
+    {
+        self.release_buffer(ids, worker_id, tag).await
+    }
+
+    pub async fn nack_buffer(&self, ids: &[i32], worker_id: &str)
+        -> Result<(usize, Vec<i32>), Error>
+    {
+        self.release_buffer(ids, worker_id, Tag::ERROR).await
+    }
+
+    async fn release_buffer(&self, ids: &[i32], worker_id: &str, tag: i16)
+        -> Result<(usize, Vec<i32>), Error>
+    {
+        // only buffers with an unexpired lease of the worker are locked, validated, and released,
+        // the other ids are returned as not released
+        let mut tx = self.pool.begin().await?;
+        let qs = buffer::select_buffer_lease_lock(ids, worker_id);
+        let (leased_ids, mut tags): (Vec<i32>, Vec<i16>) = qs.fetch_id_tags_transaction(&mut tx).await?
+            .into_iter()
+            .unzip();
+        let unreleased: Vec<i32> = ids.iter().copied().filter(|id| !leased_ids.contains(id)).collect();
+        if leased_ids.is_empty() {
+            return Ok((0, unreleased));
+        }
+        tags.sort();
+        tags.dedup();
+        self.validate_buffer_transition(&tags, tag).await?;
+        let qs = transition::select_changed_by(worker_id);
+        qs.execute_transaction(&mut tx).await?;
+        let qs = buffer::update_buffer_release(&leased_ids, worker_id, tag);
+        let count = qs.execute_count_transaction(&mut tx).await?;
+        tx.commit().await?;
+        Ok((count, unreleased))
+    }
+
+    pub async fn list_buffer_transition(&self, tag: Option<i16>)
+        -> Result<Vec<BufferTransitionSchema>, Error>
+    {
+        let tags = tag.map(|t| vec![t]);
+        let qs = transition::select_buffer_transition(tags.as_deref());
+        qs.fetch_buffer_transition_schema(&self.pool).await
+    }
+
+    pub async fn create_buffer_transition(&self, tag: i16, new_tag: i16)
+        -> Result<(), Error>
+    {
+        let qs = transition::insert_buffer_transition(tag, new_tag);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn delete_buffer_transition(&self, tag: i16, new_tag: i16)
+        -> Result<(), Error>
+    {
+        let qs = transition::delete_buffer_transition(tag, new_tag);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn list_buffer_history(&self, buffer_id: i32)
+        -> Result<Vec<BufferHistorySchema>, Error>
+    {
+        let qs = transition::select_buffer_history(buffer_id);
+        qs.fetch_buffer_history_schema(&self.pool).await
+    }
+
+    async fn validate_buffer_transition(&self, tags: &[i16], new_tag: i16)
+        -> Result<(), Error>
+    {
+        // a tag without any configured transition can only be changed to any tag in open transition mode
+        let qs = transition::select_buffer_transition(Some(tags));
+        let transitions = qs.fetch_buffer_transition_schema(&self.pool).await?;
+        for &tag in tags {
+            let allowed: Vec<i16> = transitions.iter()
+                .filter(|t| t.tag == tag)
+                .map(|t| t.new_tag)
+                .collect();
+            let open = allowed.is_empty() && self.transition == TransitionMode::Open;
+            if tag != new_tag && !open && !allowed.contains(&new_tag) {
+                return Err(Error::InvalidArgument(
+                    format!("{} from tag {} to tag {}, allowed tags are {:?}", TRANSITION_INVALID, tag, new_tag, allowed)
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub async fn read_buffer_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Error>
     {
@@ -1768,6 +2792,30 @@ impl Resource {
         qs.fetch_slice_schema(&self.pool).await
     }
 
+    pub async fn list_slice_option_page(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>, page: &Page)
+        -> Result<(Vec<SliceSchema>, Option<Cursor>), Error>
+    {
+        if page.limit == 0 {
+            return Err(Error::InvalidArgument(String::from(PAGE_LIMIT_INVALID)));
+        }
+        let after = match &page.cursor {
+            Some(c) => Some(c.id.ok_or(Error::InvalidArgument(String::from(CURSOR_INVALID)))?),
+            None => None
+        };
+        let selector = match (begin_or_timestamp, end) {
+            (Some(begin), Some(end)) => SliceSelector::Range(begin, end),
+            (Some(timestamp), None) => SliceSelector::Time(timestamp),
+            _ => SliceSelector::None
+        };
+        let qs = slice::select_slice_page(selector, device_id.as_ref().map(from_ref), model_id.as_ref().map(from_ref), name, after, page.limit);
+        let results = qs.fetch_slice_schema(&self.pool).await?;
+        let next = match results.last() {
+            Some(s) if results.len() == page.limit => Some(Cursor { id: Some(s.id), ..Default::default() }),
+            _ => None
+        };
+        Ok((results, next))
+    }
+
     pub async fn list_slice_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Error>
     {
@@ -1896,7 +2944,330 @@ impl Resource {
         qs.execute(&self.pool).await
     }
 
+    pub async fn read_slice_rule(&self, id: i32)
+        -> Result<SliceRuleSchema, Error>
+    {
+        let qs = slice::select_slice_rule(Some(&[id]), None, None);
+        qs.fetch_slice_rule_schema(&self.pool).await?.into_iter().next().ok_or(Error::RowNotFound)
+    }
+
+    pub async fn list_slice_rule_option(&self, device_id: Option<Uuid>, model_id: Option<Uuid>)
+        -> Result<Vec<SliceRuleSchema>, Error>
+    {
+        let qs = slice::select_slice_rule(None, device_id, model_id);
+        qs.fetch_slice_rule_schema(&self.pool).await
+    }
+
+    pub async fn create_slice_rule(&self, rule: &SliceRuleCreate)
+        -> Result<i32, Error>
+    {
+        if rule.min_duration < Duration::zero() || rule.max_gap < Duration::zero() {
+            return Err(Error::InvalidArgument(String::from(SLICE_RULE_DURATION_INVALID)));
+        }
+        let model = self.read_model(rule.model_id).await?;
+        if rule.index >= model.data_type.len() {
+            return Err(Error::InvalidArgument(String::from(DATA_INDEX_INVALID)));
+        }
+        let qs = slice::insert_slice_rule(rule);
+        qs.fetch_id(&self.pool).await
+    }
+
+    pub async fn update_slice_rule(&self, id: i32, condition: Option<SliceCondition>, threshold: Option<f64>, min_duration: Option<Duration>, max_gap: Option<Duration>, name: Option<&str>)
+        -> Result<(), Error>
+    {
+        if min_duration.is_some_and(|d| d < Duration::zero()) || max_gap.is_some_and(|d| d < Duration::zero()) {
+            return Err(Error::InvalidArgument(String::from(SLICE_RULE_DURATION_INVALID)));
+        }
+        let qs = slice::update_slice_rule(id, condition, threshold, min_duration, max_gap, name, None);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn delete_slice_rule(&self, id: i32)
+        -> Result<(), Error>
+    {
+        let qs = slice::delete_slice_rule(id);
+        qs.execute(&self.pool).await
+    }
+
+    async fn create_slice_by_rule(&self, rule: &SliceRuleSchema, runs: &[SliceRun], dry_run: bool)
+        -> Result<Vec<SliceSchema>, Error>
+    {
+        let (begin, end) = match (runs.first(), runs.last()) {
+            (Some(first), Some(last)) => (first.0, last.1),
+            _ => return Ok(Vec::new())
+        };
+        // slices generated by an earlier run of the rule are not created again
+        let existing = self.list_slice_by_range(rule.device_id, rule.model_id, begin, end).await?;
+        let mut slices = Vec::new();
+        for &(timestamp_begin, timestamp_end) in runs {
+            let mut slice = SliceSchema {
+                id: 0,
+                device_id: rule.device_id,
+                model_id: rule.model_id,
+                timestamp_begin,
+                timestamp_end,
+                name: format!("{} {}", rule.name, timestamp_begin.format("%Y-%m-%d %H:%M:%S")),
+                description: format!("Generated by slice rule {} where field {} {} {}", rule.id, rule.index, rule.condition.symbol(), rule.threshold)
+            };
+            let found = existing.iter().any(|s| {
+                s.timestamp_begin == slice.timestamp_begin && s.timestamp_end == slice.timestamp_end && s.name == slice.name
+            });
+            if found {
+                continue;
+            }
+            if !dry_run {
+                slice.id = self.create_slice(slice.device_id, slice.model_id, timestamp_begin, timestamp_end, &slice.name, &slice.description).await?;
+            }
+            slices.push(slice);
+        }
+        Ok(slices)
+    }
+
+    async fn scan_slice_by_rule(&self, rule: &SliceRuleSchema, begin: Option<DateTime<Utc>>, end: DateTime<Utc>, dry_run: bool, checkpoint: bool)
+        -> Result<(Vec<SliceSchema>, Option<SliceRun>), Error>
+    {
+        // only raw data is scanned page by page, data with aggregate or other tags is skipped
+        let mut detector = SliceDetector::new(rule);
+        let mut slices = Vec::new();
+        let mut begin = begin;
+        loop {
+            let qs = data::select_data_tag_page(rule.device_id, rule.model_id, begin, end, Tag::DEFAULT, SLICE_SCAN_PAGE);
+            let data = qs.fetch_data_schema(&self.pool).await?;
+            let last = match data.last() {
+                Some(d) => d.timestamp + Duration::microseconds(1),
+                None => break
+            };
+            let runs: Vec<SliceRun> = data.iter().filter_map(|d| detector.push(d)).collect();
+            slices.extend(self.create_slice_by_rule(rule, &runs, dry_run).await?);
+            // next scan starts from the open run so it can grow, or after the last scanned data
+            if checkpoint && !dry_run {
+                let checkpoint = detector.open().map(|(begin, _)| begin).unwrap_or(last);
+                let qs = slice::update_slice_rule(rule.id, None, None, None, None, None, Some(checkpoint));
+                qs.execute(&self.pool).await?;
+            }
+            if data.len() < SLICE_SCAN_PAGE {
+                break;
+            }
+            begin = Some(last);
+        }
+        Ok((slices, detector.open()))
+    }
+
+    pub async fn generate_slice_by_range(&self, rule_id: i32, begin: DateTime<Utc>, end: DateTime<Utc>, dry_run: bool)
+        -> Result<Vec<SliceSchema>, Error>
+    {
+        let rule = self.read_slice_rule(rule_id).await?;
+        let (mut slices, open) = self.scan_slice_by_rule(&rule, Some(begin), end, dry_run, false).await?;
+        // a run which is still open at the end of the range is cut at the last matched data
+        if let Some(run) = open.filter(|(begin, end)| *end - *begin >= rule.min_duration) {
+            slices.extend(self.create_slice_by_rule(&rule, &[run], dry_run).await?);
+        }
+        Ok(slices)
+    }
+
+    pub async fn apply_slice_rule(&self, dry_run: bool)
+        -> Result<Vec<(i32, usize)>, Error>
+    {
+        let qs = slice::select_slice_rule(None, None, None);
+        let rules = qs.fetch_slice_rule_schema(&self.pool).await?;
+        let now = Utc::now();
+        let mut results = Vec::new();
+        for rule in rules.iter() {
+            // a rule without checkpoint begins from the earliest raw data of its device and model
+            let (slices, _) = self.scan_slice_by_rule(rule, rule.checkpoint, now, dry_run, true).await?;
+            results.push((rule.id, slices.len()));
+        }
+        Ok(results)
+    }
+
+    pub async fn read_retention(&self, id: i32)
+        -> Result<RetentionSchema, Error>
+    {
+        let qs = retention::select_retention(Some(&[id]), None, None, None);
+        qs.fetch_retention_schema(&self.pool).await?.into_iter().next().ok_or(Error::RowNotFound)
+    }
+
+    pub async fn list_retention_option(&self, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>)
+        -> Result<Vec<RetentionSchema>, Error>
+    {
+        let qs = retention::select_retention(None, model_id, type_id, tag);
+        qs.fetch_retention_schema(&self.pool).await
+    }
+
+    pub async fn create_retention(&self, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>, retention: Duration)
+        -> Result<i32, Error>
+    {
+        if retention <= Duration::zero() {
+            return Err(Error::InvalidArgument(String::from(RETENTION_INVALID)));
+        }
+        let qs = retention::insert_retention(model_id, type_id, tag, retention);
+        qs.fetch_id(&self.pool).await
+    }
+
+    pub async fn update_retention(&self, id: i32, retention: Duration)
+        -> Result<(), Error>
+    {
+        if retention <= Duration::zero() {
+            return Err(Error::InvalidArgument(String::from(RETENTION_INVALID)));
+        }
+        let qs = retention::update_retention(id, retention);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn delete_retention(&self, id: i32)
+        -> Result<(), Error>
+    {
+        let qs = retention::delete_retention(id);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn apply_retention(&self, dry_run: bool)
+        -> Result<Vec<(i32, usize)>, Error>
+    {
+        let qs = retention::select_retention(None, None, None, None);
+        let rules = qs.fetch_retention_schema(&self.pool).await?;
+        let now = Utc::now();
+        // rules are enforced with range deletes since a chunk may hold data of other models, devices, and tags
+        let mut results = Vec::new();
+        for rule in rules.iter() {
+            let count = if dry_run {
+                let qs = retention::count_retention_data(rule, &rules, now);
+                qs.fetch_count(&self.pool).await?
+            } else {
+                let qs = retention::delete_retention_data(rule, &rules, now);
+                qs.execute_count(&self.pool).await?
+            };
+            results.push((rule.id, count));
+        }
+        Ok(results)
+    }
+
+    pub async fn enable_compression(&self, table: CompressionTable)
+        -> Result<(), Error>
+    {
+        let sql = compression::alter_compression(table);
+        sqlx::query(&sql).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn set_compression_policy(&self, table: CompressionTable, compress_after: Duration)
+        -> Result<(), Error>
+    {
+        if compress_after <= Duration::zero() {
+            return Err(Error::InvalidArgument(String::from(COMPRESS_AFTER_INVALID)));
+        }
+        // existing policy is replaced since adding a policy won't change the interval of existing one
+        let mut tx = self.pool.begin().await?;
+        let qs = compression::select_remove_compression_policy(table);
+        qs.execute_transaction(&mut tx).await?;
+        let qs = compression::select_add_compression_policy(table, compress_after);
+        qs.execute_transaction(&mut tx).await?;
+        tx.commit().await
+    }
+
+    pub async fn remove_compression_policy(&self, table: CompressionTable)
+        -> Result<(), Error>
+    {
+        let qs = compression::select_remove_compression_policy(table);
+        qs.execute(&self.pool).await
+    }
+
+    pub async fn read_compression_stats(&self, table: CompressionTable)
+        -> Result<CompressionSchema, Error>
+    {
+        let qs = compression::select_compression_stats(table);
+        let stats = qs.fetch_compression_schema(&self.pool).await?;
+        Ok(CompressionSchema { table: table.into(), ..stats })
+    }
+
+    pub async fn list_integrity_violation(&self, table: Option<IntegrityTable>)
+        -> Result<Vec<IntegrityViolationSchema>, Error>
+    {
+        let tables = match table {
+            Some(table) => vec![table],
+            None => vec![IntegrityTable::Data, IntegrityTable::Buffer, IntegrityTable::Set]
+        };
+        let mut violations = Vec::new();
+        for table in tables {
+            let qs = integrity::select_integrity_violation(table);
+            let results = qs.fetch_integrity_violation_schema(&self.pool).await?;
+            violations.extend(results.into_iter().map(|v| IntegrityViolationSchema { table: table.into(), ..v }));
+        }
+        Ok(violations)
+    }
+
+    async fn check_integrity(&self, device_ids: &[Uuid], model_ids: &[Uuid])
+        -> Result<usize, Error>
+    {
+        // strict mode rejects the write, warn mode accepts it and returns the number of violating rows
+        let violations = self.integrity_violations(device_ids, model_ids).await?;
+        let violated: Vec<(Uuid, Uuid)> = device_ids.iter().copied().zip(model_ids.iter().copied())
+            .filter(|pair| violations.contains(pair))
+            .collect();
+        match violated.first() {
+            Some(&(device_id, model_id)) if self.integrity == IntegrityMode::Strict =>
+                Err(Error::InvalidArgument(model_not_in_device(device_id, model_id))),
+            _ => Ok(violated.len())
+        }
+    }
+
+    async fn integrity_violations(&self, device_ids: &[Uuid], model_ids: &[Uuid])
+        -> Result<HashSet<(Uuid, Uuid)>, Error>
+    {
+        // every distinct device and model pair is checked once against the devices read in one query
+        let pairs: HashSet<(Uuid, Uuid)> = device_ids.iter().copied().zip(model_ids.iter().copied()).collect();
+        let mut device_ids_unique: Vec<Uuid> = pairs.iter().map(|(device_id, _)| *device_id).collect();
+        device_ids_unique.sort();
+        device_ids_unique.dedup();
+        let devices = self.list_device_by_ids(&device_ids_unique).await?;
+        let mut violations = HashSet::new();
+        for (device_id, model_id) in pairs {
+            // unknown device is left to the foreign key constraint of the written table
+            let linked = devices.iter()
+                .find(|d| d.id == device_id)
+                .is_none_or(|d| d.model_ids.contains(&model_id));
+            if !linked {
+                violations.insert((device_id, model_id));
+            }
+        }
+        Ok(violations)
+    }
+
 }
 
+pub(crate) const MODEL_NOT_FOUND: &str = "The model of input data argument is not found";
+pub(crate) const SUBSCRIBE_LAGGED: &str = "The subscriber lagged behind the data notifications, number of skipped notifications:";
+pub(crate) const DATA_CONFLICT: &str = "The data with the same device, model, timestamp, and tag already exists";
 pub(crate) const DATA_TYPE_UNMATCH: &str = "The type of input data argument doesn't match with the model";
 pub(crate) const EMPTY_LENGTH_UNMATCH: &str = "One or more input array arguments are empty or doesn't have the same length";
+pub(crate) const AGGREGATE_TAG_INVALID: &str = "The aggregation tag must be one of MINUTELY, HOURLY, DAILY, WEEKLY, MONTHLY, or ANNUAL";
+pub(crate) const AGGREGATE_INSERT_CHUNK: usize = 1000;
+pub(crate) const BUFFER_TRANSFER_CHUNK: usize = 1000;
+pub(crate) const DATA_UPDATE_CHUNK: usize = 1000;
+pub(crate) const SLICE_SCAN_PAGE: usize = 10_000;
+pub(crate) const PAGE_LIMIT_INVALID: &str = "The page limit must be greater than zero";
+pub(crate) const CURSOR_INVALID: &str = "The page cursor is not valid for the requested list";
+pub(crate) const SUBSCRIBE_CHANNEL_SIZE: usize = 256;
+pub(crate) const SET_LENGTH_UNMATCH: &str = "The length of input data argument doesn't match with the set members";
+pub(crate) const SET_MEMBER_INCOMPLETE: &str = "One or more set members doesn't cover all data fields of the model";
+pub(crate) const RETENTION_INVALID: &str = "The retention duration must be a positive duration";
+pub(crate) const COMPRESS_AFTER_INVALID: &str = "The compression policy interval must be a positive duration";
+pub(crate) const DATA_INDEX_INVALID: &str = "The data index is out of the model data types range";
+pub(crate) const MODEL_NOT_IN_DEVICE: &str = "The model is not linked to the type of the device";
+pub(crate) const TRANSITION_INVALID: &str = "The buffer tag transition is not allowed";
+pub(crate) const LEASE_DURATION_INVALID: &str = "The buffer lease duration must be a positive duration";
+pub(crate) const SLICE_RULE_DURATION_INVALID: &str = "The slice rule minimum duration and maximum gap must not be negative";
+pub(crate) const DELETE_RANGE_EMPTY: &str = "Deleting by range requires at least one device id and one model id";
+pub(crate) const BUCKET_INTERVAL_INVALID: &str = "The bucket interval must be a positive duration";
+pub(crate) const ALIGN_BUCKET_EXCEED: &str = "The number of aligned buckets exceeds the maximum";
+pub(crate) const ALIGN_BUCKET_MAX: usize = 100_000;
+
+fn model_not_in_device(device_id: Uuid, model_id: Uuid) -> String
+{
+    format!("{} (device {}, model {})", MODEL_NOT_IN_DEVICE, device_id, model_id)
+}
+
+fn data_type_unmatch(index: usize) -> Error
+{
+    Error::InvalidArgument(format!("{} at field index {}", DATA_TYPE_UNMATCH, index))
+}
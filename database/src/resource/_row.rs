@@ -1,12 +1,14 @@
 use sqlx::{Row, FromRow, Error, postgres::PgRow};
 use sqlx::types::chrono::{DateTime, Utc};
+use chrono::Duration;
 use uuid::Uuid;
 use crate::resource::_schema::{
     ModelSchema, TagSchema, ModelConfigSchema, DeviceSchema, TypeSchema, DeviceConfigSchema, TypeConfigSchema,
     GroupSchema, SetSchema, SetMember, SetMemberSort, SetTemplateSchema, SetTemplateMember, SetTemplateMemberSort,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema
+    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, DataAuditSchema, BufferTransitionSchema, BufferHistorySchema, IntegrityViolationSchema
 };
 use crate::common::type_value::{DataType, DataValue, ArrayDataValue};
+use crate::resource::slice::SliceCondition;
 
 pub(crate) struct ModelRow {
     model_id: Uuid,
@@ -645,6 +647,27 @@ impl<'r> FromRow<'r, PgRow> for DataSchema {
     }
 }
 
+impl<'r> FromRow<'r, PgRow> for DataAuditSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        let bytes: Vec<u8> = row.try_get(5)?;
+        let new_bytes: Vec<u8> = row.try_get(7)?;
+        let type_number_vec: Vec<u8> = row.try_get(10)?;
+        let types: Vec<DataType> = type_number_vec.into_iter().map(|ty| ty.into()).collect();
+        Ok(Self {
+            id: row.try_get(0)?,
+            device_id: row.try_get(1)?,
+            model_id: row.try_get(2)?,
+            timestamp: row.try_get(3)?,
+            tag: row.try_get(4)?,
+            data: ArrayDataValue::from_bytes(&bytes, &types).to_vec(),
+            new_tag: row.try_get(6)?,
+            new_data: ArrayDataValue::from_bytes(&new_bytes, &types).to_vec(),
+            changed_at: row.try_get(8)?,
+            changed_by: row.try_get(9)?
+        })
+    }
+}
+
 pub(crate) struct DataSetRow {
     _device_id: Uuid,
     _model_id: Uuid,
@@ -675,45 +698,38 @@ impl<'r> FromRow<'r, PgRow> for DataSetRow {
 }
 
 pub(crate) fn map_to_dataset_schema(rows: Vec<DataSetRow>, set_id: Uuid) -> Vec<DataSetSchema> {
-    if rows.is_empty() {
-        return Vec::new();
-    }
     // DataSetRow is sorted by (timestamp, tag, set_position) from query result
-
     let mut result = Vec::new();
-    let mut last_timestamp: Option<DateTime<Utc>> = None;
-    let mut last_tag: Option<i16> = None;
-    let mut last_dataset: DataSetSchema = DataSetSchema::default();
-
+    let mut current: Option<DataSetSchema> = None;
     for row in rows {
-        // 1) Detect new timestamp or tag
-        if Some(row.timestamp) != last_timestamp || Some(row.tag) != last_tag {
-            // push old dataset schema
-            if last_timestamp != None && last_tag != None {
-                result.push(last_dataset);
-            }
-            // reset current dataset schema and initialize data vector with vector of Null with set_number length
-            last_dataset = DataSetSchema {
-                set_id,
-                timestamp: row.timestamp,
-                data: (0..row.set_number).map(|_| DataValue::Null).collect(),
-                tag: row.tag
-            };
-            // set current timestamp and tag value
-            last_timestamp = Some(row.timestamp);
-            last_tag= Some(row.tag);
-        }
-        // 2) replace current dataset schema data vector at (set_position + offset) index with dataset row data at data_index
+        result.extend(merge_dataset_row(&mut current, row, set_id));
+    }
+    // Push last dataset schema
+    result.extend(current);
+    result
+}
+
+pub(crate) fn merge_dataset_row(current: &mut Option<DataSetSchema>, row: DataSetRow, set_id: Uuid) -> Option<DataSetSchema> {
+    // 1) Detect new timestamp or tag and return the completed dataset schema
+    let mut completed = None;
+    if current.as_ref().is_none_or(|c| c.timestamp != row.timestamp || c.tag != row.tag) {
+        // reset current dataset schema and initialize data vector with vector of Null with set_number length
+        completed = current.replace(DataSetSchema {
+            set_id,
+            timestamp: row.timestamp,
+            data: (0..row.set_number).map(|_| DataValue::Null).collect(),
+            tag: row.tag
+        });
+    }
+    // 2) replace current dataset schema data vector at (set_position + offset) index with dataset row data at data_index
+    if let Some(dataset) = current.as_mut() {
         for (position_offset, index) in row.data_index.into_iter().enumerate() {
-            if let Some(data) = last_dataset.data.get_mut(row.set_position as usize + position_offset) {
+            if let Some(data) = dataset.data.get_mut(row.set_position as usize + position_offset) {
                 *data = row.data.get(index as usize).map(|value| value.to_owned()).unwrap_or_default()
             }
         }
     }
-
-    // Push last dataset schema
-    result.push(last_dataset);
-    result
+    completed
 }
 
 impl<'r> FromRow<'r, PgRow> for BufferSchema {
@@ -834,3 +850,79 @@ impl<'r> FromRow<'r, PgRow> for SliceSetSchema {
         })
     }
 }
+
+impl<'r> FromRow<'r, PgRow> for SliceRuleSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        let condition: String = row.try_get(4)?;
+        Ok(Self {
+            id: row.try_get(0)?,
+            device_id: row.try_get(1)?,
+            model_id: row.try_get(2)?,
+            index: row.try_get(3)?,
+            condition: SliceCondition::try_from(condition.as_str()).unwrap_or_default(),
+            threshold: row.try_get(5)?,
+            min_duration: Duration::microseconds(row.try_get(6)?),
+            max_gap: Duration::microseconds(row.try_get(7)?),
+            name: row.try_get(8)?,
+            checkpoint: row.try_get(9)?
+        })
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for BufferTransitionSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(Self {
+            tag: row.try_get(0)?,
+            new_tag: row.try_get(1)?
+        })
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for BufferHistorySchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            buffer_id: row.try_get(1)?,
+            tag: row.try_get(2)?,
+            new_tag: row.try_get(3)?,
+            changed_by: row.try_get(4)?,
+            changed_at: row.try_get(5)?
+        })
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for RetentionSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            model_id: row.try_get(1)?,
+            type_id: row.try_get(2)?,
+            tag: row.try_get(3)?,
+            retention: Duration::seconds(row.try_get(4)?)
+        })
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for CompressionSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        // stats of a hypertable without compressed chunk are null
+        Ok(Self {
+            table: String::new(),
+            total_chunks: row.try_get::<Option<i64>,_>(0)?.unwrap_or_default(),
+            compressed_chunks: row.try_get::<Option<i64>,_>(1)?.unwrap_or_default(),
+            before_bytes: row.try_get::<Option<i64>,_>(2)?.unwrap_or_default(),
+            after_bytes: row.try_get::<Option<i64>,_>(3)?.unwrap_or_default()
+        })
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for IntegrityViolationSchema {
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(Self {
+            table: String::new(),
+            device_id: row.try_get(0)?,
+            model_id: row.try_get(1)?,
+            count: row.try_get(2)?
+        })
+    }
+}
@@ -1,6 +1,8 @@
 use sqlx::types::chrono::{DateTime, Utc};
+use chrono::Duration;
 use uuid::Uuid;
 use crate::common::type_value::{DataType, DataValue};
+use crate::resource::slice::SliceCondition;
 
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct ModelSchema {
@@ -265,6 +267,50 @@ pub struct DataSchema {
     pub tag: i16
 }
 
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DataAuditSchema {
+    pub id: i32,
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub tag: i16,
+    pub data: Vec<DataValue>,
+    pub new_tag: i16,
+    pub new_data: Vec<DataValue>,
+    pub changed_at: DateTime<Utc>,
+    pub changed_by: String
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BufferTransitionSchema {
+    pub tag: i16,
+    pub new_tag: i16
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BufferHistorySchema {
+    pub id: i32,
+    pub buffer_id: i32,
+    pub tag: i16,
+    pub new_tag: i16,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DataStatsSchema {
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub index: usize,
+    pub count: usize,
+    pub min: DataValue,
+    pub max: DataValue,
+    pub mean: f64,
+    pub stddev: f64,
+    pub first: DataValue,
+    pub last: DataValue
+}
+
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct DataSetSchema {
     pub set_id: Uuid,
@@ -273,6 +319,14 @@ pub struct DataSetSchema {
     pub tag: i16
 }
 
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DataSetAlignedSchema {
+    pub set_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub data: Vec<DataValue>,
+    pub present: Vec<bool>
+}
+
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct BufferSchema {
     pub id: i32,
@@ -312,3 +366,43 @@ pub struct SliceSetSchema {
     pub name: String,
     pub description: String
 }
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SliceRuleSchema {
+    pub id: i32,
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub index: i16,
+    pub condition: SliceCondition,
+    pub threshold: f64,
+    pub min_duration: Duration,
+    pub max_gap: Duration,
+    pub name: String,
+    pub checkpoint: Option<DateTime<Utc>>
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RetentionSchema {
+    pub id: i32,
+    pub model_id: Option<Uuid>,
+    pub type_id: Option<Uuid>,
+    pub tag: Option<i16>,
+    pub retention: Duration
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CompressionSchema {
+    pub table: String,
+    pub total_chunks: i64,
+    pub compressed_chunks: i64,
+    pub before_bytes: i64,
+    pub after_bytes: i64
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IntegrityViolationSchema {
+    pub table: String,
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub count: i64
+}
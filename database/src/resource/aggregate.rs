@@ -0,0 +1,547 @@
+use sea_query::{Query, Expr, Order};
+use chrono::{DateTime, Utc, TimeZone, Datelike, Timelike, Duration, Months};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+use crate::common::query_statement::QueryStatement;
+use crate::common::tag as Tag;
+use crate::common::type_value::{DataType, DataValue};
+use crate::resource::_schema::{DataSchema, DataStatsSchema, DataSetSchema, DataSetAlignedSchema};
+use crate::resource::data::Data;
+use crate::resource::model::Model;
+
+pub fn aggregate_tags(tag: i16) -> Option<(i16, i16, i16)>
+{
+    match tag {
+        Tag::MINUTELY => Some((Tag::MINUTELY_AVG, Tag::MINUTELY_MIN, Tag::MINUTELY_MAX)),
+        Tag::HOURLY => Some((Tag::HOURLY_AVG, Tag::HOURLY_MIN, Tag::HOURLY_MAX)),
+        Tag::DAILY => Some((Tag::DAILY_AVG, Tag::DAILY_MIN, Tag::DAILY_MAX)),
+        Tag::WEEKLY => Some((Tag::WEEKLY_AVG, Tag::WEEKLY_MIN, Tag::WEEKLY_MAX)),
+        Tag::MONTHLY => Some((Tag::MONTHLY_AVG, Tag::MONTHLY_MIN, Tag::MONTHLY_MAX)),
+        Tag::ANNUAL => Some((Tag::ANNUAL_AVG, Tag::ANNUAL_MIN, Tag::ANNUAL_MAX)),
+        _ => None
+    }
+}
+
+pub fn aggregate_floor(timestamp: DateTime<Utc>, tag: i16) -> DateTime<Utc>
+{
+    let date = timestamp.date_naive();
+    let floor = match tag {
+        Tag::MINUTELY => date.and_hms_opt(timestamp.hour(), timestamp.minute(), 0),
+        Tag::HOURLY => date.and_hms_opt(timestamp.hour(), 0, 0),
+        Tag::DAILY => date.and_hms_opt(0, 0, 0),
+        Tag::WEEKLY => (date - Duration::days(date.weekday().num_days_from_monday() as i64)).and_hms_opt(0, 0, 0),
+        Tag::MONTHLY => date.with_day(1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        Tag::ANNUAL => date.with_ordinal(1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        _ => None
+    };
+    match floor {
+        Some(value) => Utc.from_utc_datetime(&value),
+        None => timestamp
+    }
+}
+
+pub fn aggregate_next(timestamp: DateTime<Utc>, tag: i16) -> DateTime<Utc>
+{
+    let floor = aggregate_floor(timestamp, tag);
+    let next = match tag {
+        Tag::MINUTELY => Some(floor + Duration::minutes(1)),
+        Tag::HOURLY => Some(floor + Duration::hours(1)),
+        Tag::DAILY => Some(floor + Duration::days(1)),
+        Tag::WEEKLY => Some(floor + Duration::weeks(1)),
+        Tag::MONTHLY => floor.checked_add_months(Months::new(1)),
+        Tag::ANNUAL => floor.checked_add_months(Months::new(12)),
+        _ => None
+    };
+    next.unwrap_or(timestamp)
+}
+
+pub fn aggregate_ceil(timestamp: DateTime<Utc>, tag: i16) -> DateTime<Utc>
+{
+    let floor = aggregate_floor(timestamp, tag);
+    if floor == timestamp {
+        floor
+    } else {
+        aggregate_next(timestamp, tag)
+    }
+}
+
+pub fn select_aggregate_source(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>
+) -> QueryStatement
+{
+    let mut stmt = Query::select()
+        .columns([
+            (Data::Table, Data::DeviceId),
+            (Data::Table, Data::ModelId),
+            (Data::Table, Data::Timestamp),
+            (Data::Table, Data::Tag),
+            (Data::Table, Data::Data)
+        ])
+        .column((Model::Table, Model::DataType))
+        .from(Data::Table)
+        .inner_join(Model::Table,
+            Expr::col((Data::Table, Data::ModelId))
+            .equals((Model::Table, Model::ModelId)))
+        .and_where(Expr::col((Data::Table, Data::Tag)).eq(Tag::DEFAULT))
+        .and_where(Expr::col((Data::Table, Data::Timestamp)).gte(begin))
+        .and_where(Expr::col((Data::Table, Data::Timestamp)).lt(end))
+        .to_owned();
+
+    if device_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).eq(device_ids[0])).to_owned();
+    }
+    else if device_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).is_in(device_ids.to_vec())).to_owned();
+    }
+    if model_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).eq(model_ids[0])).to_owned();
+    }
+    else if model_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).is_in(model_ids.to_vec())).to_owned();
+    }
+
+    stmt = stmt
+        .order_by((Data::Table, Data::DeviceId), Order::Asc)
+        .order_by((Data::Table, Data::ModelId), Order::Asc)
+        .order_by((Data::Table, Data::Timestamp), Order::Asc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn delete_aggregate(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tags: &[i16]
+) -> QueryStatement
+{
+    let mut stmt = Query::delete()
+        .from_table(Data::Table)
+        .and_where(Expr::col(Data::Tag).is_in(tags.to_vec()))
+        .and_where(Expr::col(Data::Timestamp).gte(begin))
+        .and_where(Expr::col(Data::Timestamp).lt(end))
+        .to_owned();
+
+    if device_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col(Data::DeviceId).eq(device_ids[0])).to_owned();
+    }
+    else if device_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col(Data::DeviceId).is_in(device_ids.to_vec())).to_owned();
+    }
+    if model_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col(Data::ModelId).eq(model_ids[0])).to_owned();
+    }
+    else if model_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col(Data::ModelId).is_in(model_ids.to_vec())).to_owned();
+    }
+
+    QueryStatement::Delete(stmt)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataAggregate {
+    Avg,
+    Min,
+    Max,
+    First,
+    Last,
+    Count
+}
+
+impl TryFrom<&str> for DataAggregate {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "avg" => Ok(Self::Avg),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "count" => Ok(Self::Count),
+            _ => Err(format!("Unknown aggregate function '{}'", value))
+        }
+    }
+}
+
+impl From<DataAggregate> for String {
+    fn from(value: DataAggregate) -> Self {
+        match value {
+            DataAggregate::Avg => String::from("avg"),
+            DataAggregate::Min => String::from("min"),
+            DataAggregate::Max => String::from("max"),
+            DataAggregate::First => String::from("first"),
+            DataAggregate::Last => String::from("last"),
+            DataAggregate::Count => String::from("count")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataAlign {
+    Locf,
+    Linear
+}
+
+impl TryFrom<&str> for DataAlign {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "locf" => Ok(Self::Locf),
+            "linear" => Ok(Self::Linear),
+            _ => Err(format!("Unknown align mode '{}'", value))
+        }
+    }
+}
+
+impl From<DataAlign> for String {
+    fn from(value: DataAlign) -> Self {
+        match value {
+            DataAlign::Locf => String::from("locf"),
+            DataAlign::Linear => String::from("linear")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BucketField {
+    type_: DataType,
+    count: usize,
+    sum: f64,
+    numbers: usize,
+    min: Option<(f64, DataValue)>,
+    max: Option<(f64, DataValue)>,
+    first: Option<DataValue>,
+    last: Option<DataValue>
+}
+
+impl BucketField {
+    fn new(type_: DataType) -> Self {
+        Self { type_, count: 0, sum: 0.0, numbers: 0, min: None, max: None, first: None, last: None }
+    }
+}
+
+#[derive(Debug)]
+pub struct DataBucket {
+    aggregate: DataAggregate,
+    empty: bool,
+    current: Option<DataSchema>,
+    fields: Vec<BucketField>,
+    results: Vec<DataSchema>
+}
+
+impl DataBucket {
+
+    pub fn new(aggregate: DataAggregate) -> Self
+    {
+        Self { aggregate, empty: false, current: None, fields: Vec::new(), results: Vec::new() }
+    }
+
+    pub fn with_empty_value(mut self) -> Self
+    {
+        // null result of a field is replaced with empty value of the field type
+        self.empty = true;
+        self
+    }
+
+    pub fn push(&mut self, data: &DataSchema)
+    {
+        // input data timestamp is the bucket timestamp and sorted so rows of a bucket and tag are adjacent
+        let same = self.current.as_ref().is_some_and(|c| {
+            c.device_id == data.device_id && c.model_id == data.model_id && c.timestamp == data.timestamp && c.tag == data.tag
+        });
+        if !same {
+            self.flush();
+            // field types taken from the first row of the bucket
+            self.fields = data.data.iter().map(|v| BucketField::new(v.get_type())).collect();
+            self.current = Some(DataSchema { data: Vec::new(), ..data.clone() });
+        }
+        for (field, value) in self.fields.iter_mut().zip(data.data.iter()) {
+            if value.get_type() != field.type_ {
+                continue;
+            }
+            field.count += 1;
+            if field.first.is_none() {
+                field.first = Some(value.clone());
+            }
+            field.last = Some(value.clone());
+            if let Some(number) = value.to_f64() {
+                field.sum += number;
+                field.numbers += 1;
+                if field.min.as_ref().is_none_or(|(min, _)| number < *min) {
+                    field.min = Some((number, value.clone()));
+                }
+                if field.max.as_ref().is_none_or(|(max, _)| number > *max) {
+                    field.max = Some((number, value.clone()));
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self)
+    {
+        let current = match self.current.take() {
+            Some(value) => value,
+            None => return
+        };
+        // average, minimum, and maximum of non numeric field are null
+        let data = self.fields.drain(..).map(|field| {
+            let value = match self.aggregate {
+                DataAggregate::First => field.first,
+                DataAggregate::Last => field.last,
+                DataAggregate::Count => Some(DataValue::U64(field.count as u64)),
+                DataAggregate::Avg => match field.numbers {
+                    0 => None,
+                    n => Some(DataValue::from_f64(field.sum / n as f64, field.type_.clone()))
+                },
+                DataAggregate::Min => field.min.map(|(_, v)| v),
+                DataAggregate::Max => field.max.map(|(_, v)| v)
+            };
+            match value {
+                Some(value) => value,
+                None if self.empty => empty_value(field.type_),
+                None => DataValue::default()
+            }
+        }).collect();
+        self.results.push(DataSchema { data, ..current });
+    }
+
+    pub fn take(&mut self) -> Vec<DataSchema>
+    {
+        // results of completed buckets, the current bucket may still receive data
+        std::mem::take(&mut self.results)
+    }
+
+    pub fn finish(mut self) -> Vec<DataSchema>
+    {
+        self.flush();
+        self.results
+    }
+
+}
+
+#[derive(Debug)]
+pub struct DataWindow {
+    tag: i16,
+    buckets: Vec<(DataBucket, i16)>
+}
+
+impl DataWindow {
+
+    pub fn new(tag: i16) -> Option<Self>
+    {
+        let (tag_avg, tag_min, tag_max) = aggregate_tags(tag)?;
+        // non numeric field is skipped and filled with empty value of the same type
+        let buckets = [(DataAggregate::Avg, tag_avg), (DataAggregate::Min, tag_min), (DataAggregate::Max, tag_max)]
+            .into_iter()
+            .map(|(aggregate, tag)| (DataBucket::new(aggregate).with_empty_value(), tag))
+            .collect();
+        Some(Self { tag, buckets })
+    }
+
+    pub fn push(&mut self, data: &DataSchema)
+    {
+        // input data must be sorted by device, model, and timestamp so rows of a window are adjacent
+        let data = DataSchema { timestamp: aggregate_floor(data.timestamp, self.tag), ..data.clone() };
+        for (bucket, _) in self.buckets.iter_mut() {
+            bucket.push(&data);
+        }
+    }
+
+    pub fn take(&mut self) -> Vec<DataSchema>
+    {
+        let mut results = Vec::new();
+        for (bucket, tag) in self.buckets.iter_mut() {
+            results.extend(bucket.take().into_iter().map(|d| DataSchema { tag: *tag, ..d }));
+        }
+        results
+    }
+
+    pub fn finish(self) -> Vec<DataSchema>
+    {
+        let mut results = Vec::new();
+        for (bucket, tag) in self.buckets {
+            results.extend(bucket.finish().into_iter().map(|d| DataSchema { tag, ..d }));
+        }
+        results
+    }
+
+}
+
+// observed value of a field inside a bucket with its observation timestamp
+type Observation = Option<(DateTime<Utc>, DataValue)>;
+
+pub fn align_data_set(data: &[DataSetSchema], begin: DateTime<Utc>, end: DateTime<Utc>, interval: i64, align: DataAlign) -> Vec<DataSetAlignedSchema>
+{
+    let first = match data.first() {
+        Some(value) => value,
+        None => return Vec::new()
+    };
+    let range = (end - begin).num_microseconds().unwrap_or(i64::MAX);
+    if range < 0 || interval <= 0 {
+        return Vec::new();
+    }
+    let number = (range / interval + 1) as usize;
+    let fields = first.data.len();
+    // last observed value of every field inside every bucket with the observation timestamp,
+    // input data must be sorted by timestamp so a later value inside a bucket replaces the earlier one
+    let mut observed: Vec<Vec<Observation>> = vec![vec![None; number]; fields];
+    for d in data {
+        let offset = (d.timestamp - begin).num_microseconds().unwrap_or(-1);
+        if offset < 0 || offset / interval >= number as i64 {
+            continue;
+        }
+        let bucket = (offset / interval) as usize;
+        for (field, value) in observed.iter_mut().zip(d.data.iter()) {
+            if *value != DataValue::Null {
+                field[bucket] = Some((d.timestamp, value.clone()));
+            }
+        }
+    }
+    let timestamps: Vec<DateTime<Utc>> = (0..number)
+        .map(|i| begin + Duration::microseconds(interval * i as i64))
+        .collect();
+    // fill the missing value of every field then transpose the fields into set rows
+    let filled: Vec<Vec<DataValue>> = observed.iter()
+        .map(|field| fill_field(field, &timestamps, align))
+        .collect();
+    timestamps.iter().enumerate().map(|(i, &timestamp)| DataSetAlignedSchema {
+        set_id: first.set_id,
+        timestamp,
+        data: filled.iter().map(|field| field[i].clone()).collect(),
+        present: observed.iter().map(|field| field[i].is_some()).collect()
+    }).collect()
+}
+
+fn fill_field(field: &[Observation], timestamps: &[DateTime<Utc>], align: DataAlign) -> Vec<DataValue>
+{
+    // next observation of every bucket for linear interpolation
+    let mut next = vec![None; field.len()];
+    let mut following: Option<&(DateTime<Utc>, DataValue)> = None;
+    for i in (0..field.len()).rev() {
+        next[i] = following;
+        if let Some(observation) = &field[i] {
+            following = Some(observation);
+        }
+    }
+    let mut values = Vec::with_capacity(field.len());
+    let mut previous: Option<&(DateTime<Utc>, DataValue)> = None;
+    for (i, observation) in field.iter().enumerate() {
+        if let Some(observation) = observation {
+            values.push(observation.1.clone());
+            previous = Some(observation);
+            continue;
+        }
+        // value before the first observation is null, value after the last observation or of non numeric field is carried forward
+        let value = match (previous, next[i], align) {
+            (None, _, _) => DataValue::Null,
+            (Some((t0, v0)), Some((t1, v1)), DataAlign::Linear) => match (v0.to_f64(), v1.to_f64()) {
+                (Some(y0), Some(y1)) => {
+                    let span = (*t1 - *t0).num_microseconds().unwrap_or(i64::MAX) as f64;
+                    let ratio = (timestamps[i] - *t0).num_microseconds().unwrap_or(0) as f64 / span;
+                    DataValue::from_f64(y0 + (y1 - y0) * ratio, v0.get_type())
+                },
+                _ => v0.clone()
+            },
+            (Some((_, v0)), _, _) => v0.clone()
+        };
+        values.push(value);
+    }
+    values
+}
+
+#[derive(Debug, Clone, Default)]
+struct FieldStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    min: Option<(f64, DataValue)>,
+    max: Option<(f64, DataValue)>,
+    first: DataValue,
+    last: DataValue
+}
+
+#[derive(Debug, Default)]
+pub struct DataStats {
+    stats: BTreeMap<(Uuid, Uuid), Vec<FieldStats>>
+}
+
+impl DataStats {
+
+    pub fn push(&mut self, data: &DataSchema)
+    {
+        // input data must be sorted by timestamp so the first and last value are correct
+        let fields = self.stats.entry((data.device_id, data.model_id)).or_default();
+        if fields.len() < data.data.len() {
+            fields.resize(data.data.len(), FieldStats::default());
+        }
+        for (value, field) in data.data.iter().zip(fields.iter_mut()) {
+            // non numeric field is skipped
+            let number = match value.to_f64() {
+                Some(number) => number,
+                None => continue
+            };
+            if field.count == 0 {
+                field.first = value.clone();
+            }
+            field.last = value.clone();
+            // running mean and sum of squared differences using Welford's algorithm
+            field.count += 1;
+            let delta = number - field.mean;
+            field.mean += delta / field.count as f64;
+            field.m2 += delta * (number - field.mean);
+            if field.min.as_ref().is_none_or(|(min, _)| number < *min) {
+                field.min = Some((number, value.clone()));
+            }
+            if field.max.as_ref().is_none_or(|(max, _)| number > *max) {
+                field.max = Some((number, value.clone()));
+            }
+        }
+    }
+
+    pub fn finish(self) -> Vec<DataStatsSchema>
+    {
+        let mut results = Vec::new();
+        for ((device_id, model_id), fields) in self.stats {
+            for (index, field) in fields.into_iter().enumerate() {
+                if field.count == 0 {
+                    continue;
+                }
+                // sample standard deviation, zero for a single value
+                let stddev = match field.count {
+                    1 => 0.0,
+                    n => (field.m2 / (n - 1) as f64).sqrt()
+                };
+                results.push(DataStatsSchema {
+                    device_id,
+                    model_id,
+                    index,
+                    count: field.count,
+                    min: field.min.map(|(_, v)| v).unwrap_or_default(),
+                    max: field.max.map(|(_, v)| v).unwrap_or_default(),
+                    mean: field.mean,
+                    stddev,
+                    first: field.first,
+                    last: field.last
+                });
+            }
+        }
+        results
+    }
+
+}
+
+fn empty_value(type_: DataType) -> DataValue
+{
+    match type_ {
+        DataType::BoolT => DataValue::Bool(false),
+        DataType::CharT => DataValue::Char('\0'),
+        DataType::StringT => DataValue::String(String::new()),
+        DataType::BytesT => DataValue::Bytes(Vec::new()),
+        _ => DataValue::Null
+    }
+}
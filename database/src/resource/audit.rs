@@ -0,0 +1,55 @@
+use sea_query::{Iden, Query, Expr, Order};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::common::query_statement::QueryStatement;
+use crate::resource::model::Model;
+
+#[derive(Iden)]
+pub(crate) enum DataAudit {
+    Table,
+    Id,
+    DeviceId,
+    ModelId,
+    Timestamp,
+    Tag,
+    Data,
+    NewTag,
+    NewData,
+    ChangedAt,
+    ChangedBy
+}
+
+pub fn select_data_audit(
+    device_id: Uuid,
+    model_id: Uuid,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>
+) -> QueryStatement
+{
+    let stmt = Query::select()
+        .columns([
+            (DataAudit::Table, DataAudit::Id),
+            (DataAudit::Table, DataAudit::DeviceId),
+            (DataAudit::Table, DataAudit::ModelId),
+            (DataAudit::Table, DataAudit::Timestamp),
+            (DataAudit::Table, DataAudit::Tag),
+            (DataAudit::Table, DataAudit::Data),
+            (DataAudit::Table, DataAudit::NewTag),
+            (DataAudit::Table, DataAudit::NewData),
+            (DataAudit::Table, DataAudit::ChangedAt),
+            (DataAudit::Table, DataAudit::ChangedBy)
+        ])
+        .column((Model::Table, Model::DataType))
+        .from(DataAudit::Table)
+        .inner_join(Model::Table, 
+            Expr::col((DataAudit::Table, DataAudit::ModelId))
+            .equals((Model::Table, Model::ModelId)))
+        .and_where(Expr::col((DataAudit::Table, DataAudit::DeviceId)).eq(device_id))
+        .and_where(Expr::col((DataAudit::Table, DataAudit::ModelId)).eq(model_id))
+        .and_where(Expr::col((DataAudit::Table, DataAudit::Timestamp)).gte(begin))
+        .and_where(Expr::col((DataAudit::Table, DataAudit::Timestamp)).lte(end))
+        .order_by((DataAudit::Table, DataAudit::Id), Order::Asc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
@@ -1,4 +1,4 @@
-use sea_query::{Iden, Query, Expr, Order, Condition};
+use sea_query::{Iden, Query, Expr, Order, Condition, SelectStatement, InsertStatement, OnConflict, SimpleExpr, LockType, LockBehavior};
 use sqlx::types::chrono::{DateTime, Utc};
 use uuid::Uuid;
 use crate::common::query_statement::QueryStatement;
@@ -6,6 +6,7 @@ use crate::common::tag as Tag;
 use crate::common::type_value::{DataValue, ArrayDataValue};
 use crate::resource::model::{self, Model};
 use crate::resource::set::SetMember;
+use crate::resource::data::{Data, DataConflict, RETURNING_INSERTED, conflict_clause};
 
 #[derive(Iden)]
 pub(crate) enum DataBuffer {
@@ -15,7 +16,9 @@ pub(crate) enum DataBuffer {
     ModelId,
     Timestamp,
     Tag,
-    Data
+    Data,
+    LeaseWorker,
+    LeaseExpire
 }
 
 pub enum BufferSelector {
@@ -143,6 +146,65 @@ pub fn select_buffer(
     QueryStatement::Select(stmt)
 }
 
+pub fn select_buffer_id_lock(
+    selector: BufferSelector,
+    ids: Option<&[i32]>,
+    device_ids: Option<&[Uuid]>,
+    model_ids: Option<&[Uuid]>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = match select_buffer(selector, ids, device_ids, model_ids, tag) {
+        QueryStatement::Select(stmt) => stmt,
+        qs => return qs
+    };
+    // selected buffer rows are locked until the end of transaction
+    stmt.clear_selects();
+    let stmt = stmt
+        .column((DataBuffer::Table, DataBuffer::Id))
+        .column((DataBuffer::Table, DataBuffer::Tag))
+        .lock_with_tables(LockType::Update, [DataBuffer::Table])
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_buffer_page(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>,
+    after: Option<(DateTime<Utc>, i32)>,
+    limit: usize
+) -> QueryStatement
+{
+    let mut stmt = match select_buffer(BufferSelector::Range(begin, end), None, Some(device_ids), Some(model_ids), tag) {
+        QueryStatement::Select(stmt) => stmt,
+        qs => return qs
+    };
+
+    // keyset pagination using the last timestamp and id of previous page
+    if let Some((timestamp, id)) = after {
+        stmt = stmt.and_where(
+            Expr::tuple([
+                Expr::col((DataBuffer::Table, DataBuffer::Timestamp)).into(),
+                Expr::col((DataBuffer::Table, DataBuffer::Id)).into()
+            ])
+            .gt(Expr::tuple([
+                Expr::value(timestamp),
+                Expr::value(id)
+            ]))
+        ).to_owned();
+    }
+    stmt = stmt
+        .order_by((DataBuffer::Table, DataBuffer::Id), Order::Asc)
+        .limit(limit as u64)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
 pub fn select_buffer_timestamp(
     selector: BufferSelector,
     device_ids: Option<&[Uuid]>,
@@ -246,12 +308,13 @@ pub fn insert_buffer(
     model_id: Uuid,
     timestamp: DateTime<Utc>,
     data: &[DataValue],
-    tag: Option<i16>
+    tag: Option<i16>,
+    conflict: DataConflict
 ) -> QueryStatement
 {
     let bytes = ArrayDataValue::from_vec(data).to_bytes();
     let tag = tag.unwrap_or(Tag::DEFAULT);
-    let stmt = Query::insert()
+    let mut stmt = Query::insert()
         .into_table(DataBuffer::Table)
         .columns([
             DataBuffer::DeviceId,
@@ -268,8 +331,11 @@ pub fn insert_buffer(
             bytes.into()
         ])
         .unwrap_or(&mut sea_query::InsertStatement::default())
-        .returning(Query::returning().column(DataBuffer::Id))
+        .returning(buffer_returning())
         .to_owned();
+    if let Some(on_conflict) = buffer_conflict_clause(conflict) {
+        stmt.on_conflict(on_conflict);
+    }
 
     QueryStatement::Insert(stmt)
 }
@@ -279,7 +345,8 @@ pub fn insert_buffer_multiple(
     model_ids: &[Uuid],
     timestamps: &[DateTime<Utc>],
     data: &[&[DataValue]],
-    tags: Option<&[i16]>
+    tags: Option<&[i16]>,
+    conflict: DataConflict
 ) -> QueryStatement
 {
     let numbers = [device_ids.len(), model_ids.len(), timestamps.len(), data.len()];
@@ -309,13 +376,45 @@ pub fn insert_buffer_multiple(
             bytes.into()
         ])
         .unwrap_or(&mut sea_query::InsertStatement::default())
-        .returning(Query::returning().column(DataBuffer::Id))
         .to_owned();
     }
+    stmt.returning(buffer_returning());
+    if let Some(on_conflict) = buffer_conflict_clause(conflict) {
+        stmt.on_conflict(on_conflict);
+    }
 
     QueryStatement::Insert(stmt)
 }
 
+pub fn insert_buffer_ingest(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    timestamps: &[DateTime<Utc>],
+    data: &[&[DataValue]],
+    tags: &[i16]
+) -> QueryStatement
+{
+    // conflicting rows are skipped and only the keys of inserted rows are returned
+    let mut stmt = match insert_buffer_multiple(device_ids, model_ids, timestamps, data, Some(tags), DataConflict::Ignore) {
+        QueryStatement::Insert(stmt) => stmt,
+        qs => return qs
+    };
+    stmt.returning(Query::returning().columns([DataBuffer::DeviceId, DataBuffer::ModelId, DataBuffer::Timestamp, DataBuffer::Tag]));
+
+    QueryStatement::Insert(stmt)
+}
+
+fn buffer_returning() -> sea_query::ReturningClause
+{
+    let exprs: [SimpleExpr; 2] = [Expr::col(DataBuffer::Id).into(), Expr::cust(RETURNING_INSERTED)];
+    Query::returning().exprs(exprs)
+}
+
+fn buffer_conflict_clause(conflict: DataConflict) -> Option<OnConflict>
+{
+    conflict_clause(conflict, [DataBuffer::Timestamp, DataBuffer::ModelId, DataBuffer::DeviceId, DataBuffer::Tag], DataBuffer::Data)
+}
+
 pub fn update_buffer(
     id: Option<i32>,
     device_id: Option<Uuid>,
@@ -354,6 +453,109 @@ pub fn update_buffer(
     QueryStatement::Update(stmt)
 }
 
+pub fn update_buffer_by_ids(
+    ids: &[i32],
+    data: Option<&[DataValue]>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = Query::update()
+        .table(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .to_owned();
+
+    if let Some(tag) = tag {
+        stmt = stmt.value(DataBuffer::Tag, tag).to_owned();
+    }
+    if let Some(value) = data {
+        let bytes = ArrayDataValue::from_vec(value).to_bytes();
+        stmt = stmt.value(DataBuffer::Data, bytes).to_owned();
+    }
+
+    QueryStatement::Update(stmt)
+}
+
+pub fn select_buffer_claim(
+    number: usize,
+    tag: i16
+) -> QueryStatement
+{
+    // buffers without lease or with an expired lease by database clock are claimable and rows locked by other claims are skipped
+    let stmt = Query::select()
+        .column(DataBuffer::Id)
+        .from(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::Tag).eq(tag))
+        .cond_where(
+            Condition::any()
+            .add(Expr::col(DataBuffer::LeaseExpire).is_null())
+            .add(Expr::col(DataBuffer::LeaseExpire).lte(Expr::current_timestamp()))
+        )
+        .order_by(DataBuffer::Id, Order::Asc)
+        .limit(number as u64)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn update_buffer_lease(
+    ids: &[i32],
+    worker_id: &str,
+    lease_duration: i64
+) -> QueryStatement
+{
+    // lease expire time is counted from database clock so workers with drifting clocks share one time source
+    let stmt = Query::update()
+        .table(DataBuffer::Table)
+        .value(DataBuffer::LeaseWorker, worker_id)
+        .value(DataBuffer::LeaseExpire, Expr::cust_with_values("CURRENT_TIMESTAMP + $1 * INTERVAL '1 microsecond'", [lease_duration]))
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .to_owned();
+
+    QueryStatement::Update(stmt)
+}
+
+pub fn select_buffer_lease_lock(
+    ids: &[i32],
+    worker_id: &str
+) -> QueryStatement
+{
+    // only buffers leased to the worker with an unexpired lease are locked for release
+    let stmt = Query::select()
+        .columns([
+            DataBuffer::Id,
+            DataBuffer::Tag
+        ])
+        .from(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .and_where(Expr::col(DataBuffer::LeaseWorker).eq(worker_id))
+        .and_where(Expr::col(DataBuffer::LeaseExpire).gt(Expr::current_timestamp()))
+        .order_by(DataBuffer::Id, Order::Asc)
+        .lock(LockType::Update)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn update_buffer_release(
+    ids: &[i32],
+    worker_id: &str,
+    tag: i16
+) -> QueryStatement
+{
+    // only buffers claimed by the worker are released
+    let stmt = Query::update()
+        .table(DataBuffer::Table)
+        .value(DataBuffer::Tag, tag)
+        .value(DataBuffer::LeaseWorker, Option::<String>::None)
+        .value(DataBuffer::LeaseExpire, Option::<DateTime<Utc>>::None)
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .and_where(Expr::col(DataBuffer::LeaseWorker).eq(worker_id))
+        .to_owned();
+
+    QueryStatement::Update(stmt)
+}
+
 pub fn delete_buffer(
     id: Option<i32>,
     device_id: Option<Uuid>,
@@ -384,6 +586,78 @@ pub fn delete_buffer(
     QueryStatement::Delete(stmt)
 }
 
+pub fn insert_data_from_buffer(
+    ids: &[i32],
+    target_tag: Option<i16>
+) -> QueryStatement
+{
+    let tag: SimpleExpr = match target_tag {
+        Some(tag) => Expr::val(tag).into(),
+        None => Expr::col(DataBuffer::Tag).into()
+    };
+    let select = Query::select()
+        .columns([
+            DataBuffer::DeviceId,
+            DataBuffer::ModelId,
+            DataBuffer::Timestamp
+        ])
+        .expr(tag)
+        .column(DataBuffer::Data)
+        .from(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .to_owned();
+    let stmt = Query::insert()
+        .into_table(Data::Table)
+        .columns([
+            Data::DeviceId,
+            Data::ModelId,
+            Data::Timestamp,
+            Data::Tag,
+            Data::Data
+        ])
+        .select_from(select)
+        .unwrap_or(&mut InsertStatement::default())
+        .to_owned();
+
+    QueryStatement::Insert(stmt)
+}
+
+pub fn delete_buffer_by_ids(
+    ids: &[i32]
+) -> QueryStatement
+{
+    let stmt = Query::delete()
+        .from_table(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::Id).is_in(ids.to_vec()))
+        .to_owned();
+
+    QueryStatement::Delete(stmt)
+}
+
+pub fn delete_buffer_by_range(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = Query::delete()
+        .from_table(DataBuffer::Table)
+        .and_where(Expr::col(DataBuffer::DeviceId).is_in(device_ids.to_vec()))
+        .and_where(Expr::col(DataBuffer::ModelId).is_in(model_ids.to_vec()))
+        .and_where(Expr::col(DataBuffer::Timestamp).gte(begin))
+        .and_where(Expr::col(DataBuffer::Timestamp).lte(end))
+        .to_owned();
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col(DataBuffer::Tag).in_subquery(query)).to_owned();
+    }
+
+    QueryStatement::Delete(stmt)
+}
+
 pub fn select_buffer_set(
     selector: BufferSelector,
     set_id: Uuid,
@@ -438,13 +712,35 @@ pub fn select_buffer_set(
                 .order_by((DataBuffer::Table, DataBuffer::Timestamp), Order::Asc)
                 .to_owned();
         },
+        BufferSelector::NumberBefore(timestamp, number) => {
+            let query = select_set_timestamps(set_id, tag)
+                .and_where(Expr::col((DataBuffer::Table, DataBuffer::Timestamp)).lte(timestamp))
+                .order_by((DataBuffer::Table, DataBuffer::Timestamp), Order::Desc)
+                .limit(number as u64)
+                .to_owned();
+            stmt = stmt
+                .and_where(Expr::col((DataBuffer::Table, DataBuffer::Timestamp)).in_subquery(query))
+                .order_by((DataBuffer::Table, DataBuffer::Timestamp), Order::Asc)
+                .to_owned();
+        },
+        BufferSelector::NumberAfter(timestamp, number) => {
+            let query = select_set_timestamps(set_id, tag)
+                .and_where(Expr::col((DataBuffer::Table, DataBuffer::Timestamp)).gte(timestamp))
+                .order_by((DataBuffer::Table, DataBuffer::Timestamp), Order::Asc)
+                .limit(number as u64)
+                .to_owned();
+            stmt = stmt
+                .and_where(Expr::col((DataBuffer::Table, DataBuffer::Timestamp)).in_subquery(query))
+                .order_by((DataBuffer::Table, DataBuffer::Timestamp), Order::Asc)
+                .to_owned();
+        },
         _ => {}
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag) {
-            stmt = stmt.and_where(Expr::col((DataBuffer::Table, DataBuffer::Tag)).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag)
+    {
+        stmt = stmt.and_where(Expr::col((DataBuffer::Table, DataBuffer::Tag)).in_subquery(query)).to_owned();
     }
     let stmt = stmt
         .order_by((DataBuffer::Table, DataBuffer::Tag), Order::Asc)
@@ -454,6 +750,31 @@ pub fn select_buffer_set(
     QueryStatement::Select(stmt)
 }
 
+fn select_set_timestamps(
+    set_id: Uuid,
+    tag: Option<i16>
+) -> SelectStatement
+{
+    // distinct timestamps so that a number limit counts whole set rows instead of member rows
+    let mut stmt = Query::select()
+        .distinct()
+        .column((DataBuffer::Table, DataBuffer::Timestamp))
+        .from(DataBuffer::Table)
+        .inner_join(SetMember::Table, 
+            Condition::all()
+            .add(Expr::col((DataBuffer::Table, DataBuffer::DeviceId)).equals((SetMember::Table, SetMember::DeviceId)))
+            .add(Expr::col((DataBuffer::Table, DataBuffer::ModelId)).equals((SetMember::Table, SetMember::ModelId)))
+        )
+        .and_where(Expr::col((SetMember::Table, SetMember::SetId)).eq(set_id))
+        .to_owned();
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag)
+    {
+        stmt = stmt.and_where(Expr::col((DataBuffer::Table, DataBuffer::Tag)).in_subquery(query)).to_owned();
+    }
+    stmt
+}
+
 pub fn count_buffer(
     selector: BufferSelector,
     device_ids: &[Uuid],
@@ -495,10 +816,10 @@ pub fn count_buffer(
         _ => {}
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag) {
-            stmt = stmt.and_where(Expr::col(DataBuffer::Tag).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col(DataBuffer::Tag).in_subquery(query)).to_owned();
     }
 
     QueryStatement::Select(stmt)
@@ -0,0 +1,95 @@
+use sea_query::{Query, Expr, Func, Alias, Value};
+use chrono::Duration;
+use crate::common::query_statement::QueryStatement;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionTable {
+    Data,
+    Buffer
+}
+
+impl CompressionTable {
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            Self::Data => "data",
+            Self::Buffer => "data_buffer"
+        }
+    }
+}
+
+impl TryFrom<&str> for CompressionTable {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "data" => Ok(Self::Data),
+            "buffer" | "data_buffer" => Ok(Self::Buffer),
+            _ => Err(format!("Unknown compression table '{}'", value))
+        }
+    }
+}
+
+impl From<CompressionTable> for String {
+    fn from(value: CompressionTable) -> Self {
+        match value {
+            CompressionTable::Data => String::from("data"),
+            CompressionTable::Buffer => String::from("buffer")
+        }
+    }
+}
+
+pub fn alter_compression(
+    table: CompressionTable
+) -> String
+{
+    // segment by device and model so a compressed chunk is read per device and model without decompressing others,
+    // the order by must include remaining primary key columns so insert into compressed chunk keeps the uniqueness
+    format!(
+        "ALTER TABLE \"{}\" SET (timescaledb.compress, timescaledb.compress_segmentby = 'device_id, model_id', timescaledb.compress_orderby = 'timestamp DESC, tag')",
+        table.table_name()
+    )
+}
+
+pub fn select_add_compression_policy(
+    table: CompressionTable,
+    compress_after: Duration
+) -> QueryStatement
+{
+    let values = [Value::from(table.table_name()), Value::from(compress_after.num_microseconds().unwrap_or(i64::MAX))];
+    let stmt = Query::select()
+        .expr(Expr::cust_with_values("add_compression_policy($1::regclass, compress_after => $2 * INTERVAL '1 microsecond', if_not_exists => true)", values))
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_remove_compression_policy(
+    table: CompressionTable
+) -> QueryStatement
+{
+    let stmt = Query::select()
+        .expr(Expr::cust_with_values("remove_compression_policy($1::regclass, if_exists => true)", [table.table_name()]))
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_compression_stats(
+    table: CompressionTable
+) -> QueryStatement
+{
+    let stmt = Query::select()
+        .exprs([
+            Expr::col(Alias::new("total_chunks")),
+            Expr::col(Alias::new("number_compressed_chunks")),
+            Expr::col(Alias::new("before_compression_total_bytes")),
+            Expr::col(Alias::new("after_compression_total_bytes"))
+        ])
+        .from_function(
+            Func::cust(Alias::new("hypertable_compression_stats"))
+                .arg(Expr::val(table.table_name()).cast_as(Alias::new("regclass"))),
+            Alias::new("stats")
+        )
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
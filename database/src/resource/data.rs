@@ -1,11 +1,14 @@
-use sea_query::{Iden, Query, Expr, Order, Condition};
+use sea_query::{Iden, IntoIden, Query, Expr, Order, Condition, Alias, SelectStatement, OnConflict, LockType, CaseStatement};
 use sqlx::types::chrono::{DateTime, Utc};
 use uuid::Uuid;
+use std::sync::Arc;
+use tokio::sync::{broadcast, Mutex};
 use crate::common::query_statement::QueryStatement;
 use crate::common::tag as Tag;
-use crate::common::type_value::{DataValue, ArrayDataValue};
+use crate::common::type_value::{DataType, DataValue, ArrayDataValue};
 use crate::resource::model::{self, Model};
 use crate::resource::set::SetMember;
+use crate::resource::_schema::DataSchema;
 
 #[derive(Iden)]
 pub(crate) enum Data {
@@ -26,6 +29,142 @@ pub enum DataSelector {
     NumberAfter(DateTime<Utc>, usize)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataConflict {
+    #[default]
+    Error,
+    Ignore,
+    Overwrite
+}
+
+impl TryFrom<&str> for DataConflict {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "ignore" => Ok(Self::Ignore),
+            "overwrite" => Ok(Self::Overwrite),
+            _ => Err(format!("Unknown conflict policy '{}'", value))
+        }
+    }
+}
+
+impl From<DataConflict> for String {
+    fn from(value: DataConflict) -> Self {
+        match value {
+            DataConflict::Error => String::from("error"),
+            DataConflict::Ignore => String::from("ignore"),
+            DataConflict::Overwrite => String::from("overwrite")
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DataConflictCount {
+    pub inserted: usize,
+    pub skipped: usize,
+    pub overwritten: usize,
+    // rows written in integrity warn mode with a model which is not linked to the device type
+    pub violated: usize
+}
+
+impl DataConflictCount {
+    pub(crate) fn from_inserted(number: usize, inserted: &[bool]) -> Self {
+        // skipped rows are not returned and overwritten rows have a non zero xmax
+        let count = inserted.iter().filter(|&&i| i).count();
+        Self {
+            inserted: count,
+            skipped: number - inserted.len(),
+            overwritten: inserted.len() - count,
+            violated: 0
+        }
+    }
+}
+
+// returned by insert statement to tell newly inserted rows from overwritten rows
+pub(crate) const RETURNING_INSERTED: &str = "xmax = 0";
+
+pub(crate) fn conflict_clause<C: IntoIden>(conflict: DataConflict, keys: [C; 4], data: C) -> Option<OnConflict>
+{
+    match conflict {
+        DataConflict::Error => None,
+        DataConflict::Ignore => Some(OnConflict::columns(keys).do_nothing().to_owned()),
+        DataConflict::Overwrite => Some(OnConflict::columns(keys).update_column(data).to_owned())
+    }
+}
+
+// rewrite of one field index of the data rows inside a time range
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataRangeUpdate {
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub begin: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub tag: Option<i16>,
+    pub index: usize,
+    pub value: DataValue
+}
+
+pub(crate) const DATA_NOTIFY_CHANNEL: &str = "data_insert";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataNotify {
+    pub buffer_id: Option<i32>,
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub tag: i16,
+    pub data: Option<Vec<DataValue>>
+}
+
+impl DataNotify {
+    // payload format: kind,buffer_id,device_id,model_id,timestamp_micros,tag[,types_hex,data_hex]
+    // row data is omitted by the trigger when it doesn't fit in the notification payload
+    pub fn from_payload(payload: &str) -> Option<Self> {
+        let parts: Vec<&str> = payload.split(',').collect();
+        if parts.len() != 6 && parts.len() != 8 {
+            return None;
+        }
+        let buffer_id = match parts[0] {
+            "data" => None,
+            "buffer" => Some(parts[1].parse().ok()?),
+            _ => return None
+        };
+        let data = match parts.len() {
+            8 => {
+                let types: Vec<DataType> = decode_hex(parts[6])?.into_iter().map(|t| t.into()).collect();
+                Some(ArrayDataValue::from_bytes(&decode_hex(parts[7])?, &types).to_vec())
+            },
+            _ => None
+        };
+        Some(Self {
+            buffer_id,
+            device_id: Uuid::parse_str(parts[2]).ok()?,
+            model_id: Uuid::parse_str(parts[3]).ok()?,
+            timestamp: DateTime::from_timestamp_micros(parts[4].parse().ok()?)?,
+            tag: parts[5].parse().ok()?,
+            data
+        })
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len()).step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub(crate) type DataNotifyItem = Result<Arc<DataSchema>, String>;
+
+// one shared insert listener per resource, subscribers receive the notified rows through a broadcast channel
+#[derive(Debug, Clone, Default)]
+pub struct DataNotifier {
+    pub(crate) sender: Arc<Mutex<Option<broadcast::Sender<DataNotifyItem>>>>
+}
+
 pub fn select_data(
     selector: DataSelector,
     device_ids: &[Uuid],
@@ -98,12 +237,204 @@ pub fn select_data(
         }
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag) {
-            stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
+    }
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_data_latest(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    tag: Option<i16>
+) -> QueryStatement
+{
+    // distinct on device and model with descending timestamp order keeps only the newest row of every pair
+    let mut stmt = Query::select()
+        .distinct_on([(Data::Table, Data::DeviceId), (Data::Table, Data::ModelId)])
+        .columns([
+            (Data::Table, Data::DeviceId),
+            (Data::Table, Data::ModelId),
+            (Data::Table, Data::Timestamp),
+            (Data::Table, Data::Tag),
+            (Data::Table, Data::Data)
+        ])
+        .column((Model::Table, Model::DataType))
+        .from(Data::Table)
+        .inner_join(Model::Table, 
+            Expr::col((Data::Table, Data::ModelId))
+            .equals((Model::Table, Model::ModelId)))
+        .to_owned();
+
+    if device_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).eq(device_ids[0])).to_owned();
+    }
+    else if device_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).is_in(device_ids.to_vec())).to_owned();
+    }
+    if model_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).eq(model_ids[0])).to_owned();
+    }
+    else if model_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).is_in(model_ids.to_vec())).to_owned();
+    }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
+    }
+    stmt = stmt
+        .order_by((Data::Table, Data::DeviceId), Order::Asc)
+        .order_by((Data::Table, Data::ModelId), Order::Asc)
+        .order_by((Data::Table, Data::Timestamp), Order::Desc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_data_page(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>,
+    after: Option<(DateTime<Utc>, Uuid, Uuid, i16)>,
+    limit: usize
+) -> QueryStatement
+{
+    let mut stmt = match select_data(DataSelector::Range(begin, end), device_ids, model_ids, tag) {
+        QueryStatement::Select(stmt) => stmt,
+        qs => return qs
+    };
+
+    // keyset pagination using the last timestamp and primary key of previous page
+    if let Some((timestamp, device_id, model_id, tag)) = after {
+        stmt = stmt.and_where(
+            Expr::tuple([
+                Expr::col((Data::Table, Data::Timestamp)).into(),
+                Expr::col((Data::Table, Data::DeviceId)).into(),
+                Expr::col((Data::Table, Data::ModelId)).into(),
+                Expr::col((Data::Table, Data::Tag)).into()
+            ])
+            .gt(Expr::tuple([
+                Expr::value(timestamp),
+                Expr::value(device_id),
+                Expr::value(model_id),
+                Expr::value(tag)
+            ]))
+        ).to_owned();
+    }
+    stmt = stmt
+        .order_by((Data::Table, Data::DeviceId), Order::Asc)
+        .order_by((Data::Table, Data::ModelId), Order::Asc)
+        .order_by((Data::Table, Data::Tag), Order::Asc)
+        .limit(limit as u64)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_data_tag_page(
+    device_id: Uuid,
+    model_id: Uuid,
+    begin: Option<DateTime<Utc>>,
+    end: DateTime<Utc>,
+    tag: i16,
+    limit: usize
+) -> QueryStatement
+{
+    // timestamp is unique for one device, model, and tag so the next page begins after the last timestamp
+    let mut stmt = Query::select()
+        .columns([
+            (Data::Table, Data::DeviceId),
+            (Data::Table, Data::ModelId),
+            (Data::Table, Data::Timestamp),
+            (Data::Table, Data::Tag),
+            (Data::Table, Data::Data)
+        ])
+        .column((Model::Table, Model::DataType))
+        .from(Data::Table)
+        .inner_join(Model::Table, 
+            Expr::col((Data::Table, Data::ModelId))
+            .equals((Model::Table, Model::ModelId)))
+        .and_where(Expr::col((Data::Table, Data::DeviceId)).eq(device_id))
+        .and_where(Expr::col((Data::Table, Data::ModelId)).eq(model_id))
+        .and_where(Expr::col((Data::Table, Data::Tag)).eq(tag))
+        .and_where(Expr::col((Data::Table, Data::Timestamp)).lte(end))
+        .to_owned();
+    if let Some(begin) = begin {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Timestamp)).gte(begin)).to_owned();
+    }
+    stmt = stmt
+        .order_by((Data::Table, Data::Timestamp), Order::Asc)
+        .limit(limit as u64)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_data_bucketed(
+    interval: i64,
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    // interval in microseconds, data timestamp replaced by timestamp of the bucket
+    let mut stmt = Query::select()
+        .columns([
+            (Data::Table, Data::DeviceId),
+            (Data::Table, Data::ModelId)
+        ])
+        .expr_as(
+            Expr::cust_with_values("time_bucket($1 * INTERVAL '1 microsecond', \"data\".\"timestamp\")", [interval]),
+            Alias::new("bucket")
+        )
+        .columns([
+            (Data::Table, Data::Tag),
+            (Data::Table, Data::Data)
+        ])
+        .column((Model::Table, Model::DataType))
+        .from(Data::Table)
+        .inner_join(Model::Table, 
+            Expr::col((Data::Table, Data::ModelId))
+            .equals((Model::Table, Model::ModelId)))
+        .and_where(Expr::col((Data::Table, Data::Timestamp)).gte(begin))
+        .and_where(Expr::col((Data::Table, Data::Timestamp)).lte(end))
+        .to_owned();
+
+    if device_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).eq(device_ids[0])).to_owned();
+    }
+    else if device_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::DeviceId)).is_in(device_ids.to_vec())).to_owned();
+    }
+    if model_ids.len() == 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).eq(model_ids[0])).to_owned();
+    }
+    else if model_ids.len() > 1 {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::ModelId)).is_in(model_ids.to_vec())).to_owned();
+    }
+
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
     }
 
+    stmt = stmt
+        .order_by((Data::Table, Data::DeviceId), Order::Asc)
+        .order_by((Data::Table, Data::ModelId), Order::Asc)
+        .order_by(Alias::new("bucket"), Order::Asc)
+        .order_by((Data::Table, Data::Tag), Order::Asc)
+        .order_by((Data::Table, Data::Timestamp), Order::Asc)
+        .to_owned();
+
     QueryStatement::Select(stmt)
 }
 
@@ -156,10 +487,10 @@ pub fn select_data_timestamp(
         _ => {}
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag) {
-            stmt = stmt.and_where(Expr::col(Data::Tag).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col(Data::Tag).in_subquery(query)).to_owned();
     }
 
     QueryStatement::Select(stmt)
@@ -184,12 +515,13 @@ pub fn insert_data(
     model_id: Uuid,
     timestamp: DateTime<Utc>,
     data: &[DataValue],
-    tag: Option<i16>
+    tag: Option<i16>,
+    conflict: DataConflict
 ) -> QueryStatement
 {
     let bytes = ArrayDataValue::from_vec(data).to_bytes();
     let tag = tag.unwrap_or(Tag::DEFAULT);
-    let stmt = Query::insert()
+    let mut stmt = Query::insert()
         .into_table(Data::Table)
         .columns([
             Data::DeviceId,
@@ -206,7 +538,11 @@ pub fn insert_data(
             bytes.into()
         ])
         .unwrap_or(&mut sea_query::InsertStatement::default())
+        .returning(Query::returning().expr(Expr::cust(RETURNING_INSERTED)))
         .to_owned();
+    if let Some(on_conflict) = data_conflict_clause(conflict) {
+        stmt.on_conflict(on_conflict);
+    }
 
     QueryStatement::Insert(stmt)
 }
@@ -216,7 +552,8 @@ pub fn insert_data_multiple(
     model_ids: &[Uuid],
     timestamps: &[DateTime<Utc>],
     data: &[&[DataValue]],
-    tags: Option<&[i16]>
+    tags: Option<&[i16]>,
+    conflict: DataConflict
 ) -> QueryStatement
 {
     let numbers = [device_ids.len(), model_ids.len(), timestamps.len(), data.len()];
@@ -248,10 +585,130 @@ pub fn insert_data_multiple(
         .unwrap_or(&mut sea_query::InsertStatement::default())
         .to_owned();
     }
+    stmt.returning(Query::returning().expr(Expr::cust(RETURNING_INSERTED)));
+    if let Some(on_conflict) = data_conflict_clause(conflict) {
+        stmt.on_conflict(on_conflict);
+    }
+
+    QueryStatement::Insert(stmt)
+}
+
+// device id, model id, timestamp in microseconds, and tag of an inserted row
+pub(crate) type DataKey = (Uuid, Uuid, i64, i16);
+
+pub fn insert_data_ingest(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    timestamps: &[DateTime<Utc>],
+    data: &[&[DataValue]],
+    tags: &[i16]
+) -> QueryStatement
+{
+    // conflicting rows are skipped and only the keys of inserted rows are returned
+    let mut stmt = match insert_data_multiple(device_ids, model_ids, timestamps, data, Some(tags), DataConflict::Ignore) {
+        QueryStatement::Insert(stmt) => stmt,
+        qs => return qs
+    };
+    stmt.returning(Query::returning().columns([Data::DeviceId, Data::ModelId, Data::Timestamp, Data::Tag]));
 
     QueryStatement::Insert(stmt)
 }
 
+fn data_conflict_clause(conflict: DataConflict) -> Option<OnConflict>
+{
+    conflict_clause(conflict, [Data::Timestamp, Data::ModelId, Data::DeviceId, Data::Tag], Data::Data)
+}
+
+pub fn update_data(
+    device_id: Uuid,
+    model_id: Uuid,
+    timestamp: DateTime<Utc>,
+    tag: Option<i16>,
+    data: Option<&[DataValue]>,
+    new_tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = Query::update()
+        .table(Data::Table)
+        .and_where(Expr::col(Data::DeviceId).eq(device_id))
+        .and_where(Expr::col(Data::ModelId).eq(model_id))
+        .and_where(Expr::col(Data::Timestamp).eq(timestamp))
+        .to_owned();
+    if let Some(t) = tag {
+        stmt = stmt.and_where(Expr::col(Data::Tag).eq(t)).to_owned();
+    }
+
+    if let Some(value) = data {
+        let bytes = ArrayDataValue::from_vec(value).to_bytes();
+        stmt = stmt.value(Data::Data, bytes).to_owned();
+    }
+    if let Some(t) = new_tag {
+        stmt = stmt.value(Data::Tag, t).to_owned();
+    }
+
+    QueryStatement::Update(stmt)
+}
+
+pub fn select_data_range_lock(
+    device_id: Uuid,
+    model_id: Uuid,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>,
+    after: Option<(DateTime<Utc>, i16)>,
+    limit: usize
+) -> QueryStatement
+{
+    let mut qs = select_data(DataSelector::Range(begin, end), &[device_id], &[model_id], tag);
+    // keyset pagination using the last timestamp and tag of previous page,
+    // only data rows are locked, the model row is joined for the data types
+    if let QueryStatement::Select(stmt) = &mut qs {
+        if let Some((timestamp, tag)) = after {
+            stmt.and_where(
+                Expr::tuple([
+                    Expr::col((Data::Table, Data::Timestamp)).into(),
+                    Expr::col((Data::Table, Data::Tag)).into()
+                ])
+                .gt(Expr::tuple([
+                    Expr::value(timestamp),
+                    Expr::value(tag)
+                ]))
+            );
+        }
+        stmt.order_by((Data::Table, Data::Tag), Order::Asc)
+            .limit(limit as u64)
+            .lock_with_tables(LockType::Update, [Data::Table]);
+    }
+    qs
+}
+
+pub fn update_data_multiple(
+    device_id: Uuid,
+    model_id: Uuid,
+    rows: &[(DateTime<Utc>, i16, &[DataValue])]
+) -> QueryStatement
+{
+    // every row gets its own data in one statement using a case expression keyed by timestamp and tag
+    let mut condition = Condition::any();
+    let mut case = CaseStatement::new();
+    for &(timestamp, tag, data) in rows {
+        let key = Condition::all()
+            .add(Expr::col(Data::Timestamp).eq(timestamp))
+            .add(Expr::col(Data::Tag).eq(tag));
+        condition = condition.add(key.clone());
+        case = case.case(key, ArrayDataValue::from_vec(data).to_bytes());
+    }
+    let stmt = Query::update()
+        .table(Data::Table)
+        .value(Data::Data, case.finally(Expr::col(Data::Data)))
+        .and_where(Expr::col(Data::DeviceId).eq(device_id))
+        .and_where(Expr::col(Data::ModelId).eq(model_id))
+        .cond_where(condition)
+        .to_owned();
+
+    QueryStatement::Update(stmt)
+}
+
 pub fn delete_data(
     device_id: Uuid,
     model_id: Uuid,
@@ -272,6 +729,31 @@ pub fn delete_data(
     QueryStatement::Delete(stmt)
 }
 
+pub fn delete_data_by_range(
+    device_ids: &[Uuid],
+    model_ids: &[Uuid],
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = Query::delete()
+        .from_table(Data::Table)
+        .and_where(Expr::col(Data::DeviceId).is_in(device_ids.to_vec()))
+        .and_where(Expr::col(Data::ModelId).is_in(model_ids.to_vec()))
+        .and_where(Expr::col(Data::Timestamp).gte(begin))
+        .and_where(Expr::col(Data::Timestamp).lte(end))
+        .to_owned();
+    // use the same tag filter as count_data so a dry run reports the exact number of deleted rows
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col(Data::Tag).in_subquery(query)).to_owned();
+    }
+
+    QueryStatement::Delete(stmt)
+}
+
 pub fn select_data_set(
     selector: DataSelector,
     set_id: Uuid,
@@ -327,13 +809,34 @@ pub fn select_data_set(
                 .order_by((Data::Table, Data::Timestamp), Order::Asc)
                 .to_owned();
         },
-        _ => {}
+        DataSelector::NumberBefore(time, number) => {
+            let query = select_set_timestamps(set_id, tag)
+                .and_where(Expr::col((Data::Table, Data::Timestamp)).lte(time))
+                .order_by((Data::Table, Data::Timestamp), Order::Desc)
+                .limit(number as u64)
+                .to_owned();
+            stmt = stmt
+                .and_where(Expr::col((Data::Table, Data::Timestamp)).in_subquery(query))
+                .order_by((Data::Table, Data::Timestamp), Order::Asc)
+                .to_owned();
+        },
+        DataSelector::NumberAfter(time, number) => {
+            let query = select_set_timestamps(set_id, tag)
+                .and_where(Expr::col((Data::Table, Data::Timestamp)).gte(time))
+                .order_by((Data::Table, Data::Timestamp), Order::Asc)
+                .limit(number as u64)
+                .to_owned();
+            stmt = stmt
+                .and_where(Expr::col((Data::Table, Data::Timestamp)).in_subquery(query))
+                .order_by((Data::Table, Data::Timestamp), Order::Asc)
+                .to_owned();
+        }
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag) {
-            stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag)
+    {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
     }
     let stmt = stmt
         .order_by((Data::Table, Data::Tag), Order::Asc)
@@ -343,6 +846,31 @@ pub fn select_data_set(
     QueryStatement::Select(stmt)
 }
 
+fn select_set_timestamps(
+    set_id: Uuid,
+    tag: Option<i16>
+) -> SelectStatement
+{
+    // distinct timestamps so that a number limit counts whole set rows instead of member rows
+    let mut stmt = Query::select()
+        .distinct()
+        .column((Data::Table, Data::Timestamp))
+        .from(Data::Table)
+        .inner_join(SetMember::Table, 
+            Condition::all()
+            .add(Expr::col((Data::Table, Data::DeviceId)).equals((SetMember::Table, SetMember::DeviceId)))
+            .add(Expr::col((Data::Table, Data::ModelId)).equals((SetMember::Table, SetMember::ModelId)))
+        )
+        .and_where(Expr::col((SetMember::Table, SetMember::SetId)).eq(set_id))
+        .to_owned();
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members_set(set_id, tag)
+    {
+        stmt = stmt.and_where(Expr::col((Data::Table, Data::Tag)).in_subquery(query)).to_owned();
+    }
+    stmt
+}
+
 pub fn count_data(
     selector: DataSelector,
     device_ids: &[Uuid],
@@ -384,10 +912,10 @@ pub fn count_data(
         _ => {}
     }
 
-    if let Some(tag) = tag {
-        if let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag) {
-            stmt = stmt.and_where(Expr::col(Data::Tag).in_subquery(query)).to_owned();
-        }
+    if let Some(tag) = tag
+        && let QueryStatement::Select(query) = model::select_tag_members(model_ids, tag)
+    {
+        stmt = stmt.and_where(Expr::col(Data::Tag).in_subquery(query)).to_owned();
     }
 
     QueryStatement::Select(stmt)
@@ -139,6 +139,39 @@ pub fn select_device(
     QueryStatement::Select(stmt)
 }
 
+pub fn select_device_page(
+    kind: DeviceKind,
+    gateway_id: Option<Uuid>,
+    type_id: Option<Uuid>,
+    name: Option<&str>,
+    after: Option<Uuid>,
+    limit: usize
+) -> QueryStatement
+{
+    let mut stmt = match select_device(kind, None, None, None, gateway_id, type_id, name) {
+        QueryStatement::Select(stmt) => stmt,
+        qs => return qs
+    };
+
+    // select device ids of a page first because a device has multiple rows of type models and configs
+    let mut page = stmt.clone()
+        .clear_selects()
+        .clear_order_by()
+        .distinct()
+        .column((Device::Table, Device::DeviceId))
+        .to_owned();
+    if let Some(id) = after {
+        page = page.and_where(Expr::col((Device::Table, Device::DeviceId)).gt(id)).to_owned();
+    }
+    page = page
+        .order_by((Device::Table, Device::DeviceId), Order::Asc)
+        .limit(limit as u64)
+        .to_owned();
+    stmt = stmt.and_where(Expr::col((Device::Table, Device::DeviceId)).in_subquery(page)).to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
 pub fn insert_device(
     id: Uuid,
     gateway_id: Uuid,
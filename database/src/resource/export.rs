@@ -0,0 +1,517 @@
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::{Stream, StreamExt};
+use tokio_stream::wrappers::ReceiverStream;
+use sqlx::Error;
+use sqlx::types::chrono::{DateTime, Utc};
+use chrono::SecondsFormat;
+use uuid::Uuid;
+use parquet::basic::{Type as PhysicalType, LogicalType, Repetition, TimeUnit};
+use parquet::format::MicroSeconds;
+use parquet::data_type::{BoolType, Int32Type, Int64Type, FloatType, DoubleType, ByteArrayType, ByteArray};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::{Type, TypePtr};
+use crate::common::type_value::{DataType, DataValue};
+use crate::resource::_schema::{ModelSchema, SetSchema, DataSchema, DataSetSchema, BufferSchema};
+
+// model configuration category which value is used as the column name of a data field
+pub const COLUMN_CATEGORY: &str = "SCALE";
+const EXPORT_BATCH_SIZE: usize = 1000;
+const EXPORT_CHANNEL_SIZE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Ndjson,
+    Parquet
+}
+
+impl TryFrom<&str> for ExportFormat {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "ndjson" | "jsonl" => Ok(Self::Ndjson),
+            "parquet" => Ok(Self::Parquet),
+            _ => Err(format!("Unknown export format '{}'", value))
+        }
+    }
+}
+
+impl From<ExportFormat> for String {
+    fn from(value: ExportFormat) -> Self {
+        match value {
+            ExportFormat::Csv => String::from("csv"),
+            ExportFormat::Ndjson => String::from("ndjson"),
+            ExportFormat::Parquet => String::from("parquet")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportSource {
+    #[default]
+    Data,
+    Buffer,
+    Set
+}
+
+impl TryFrom<&str> for ExportSource {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "data" => Ok(Self::Data),
+            "buffer" | "data_buffer" => Ok(Self::Buffer),
+            "set" | "data_set" => Ok(Self::Set),
+            _ => Err(format!("Unknown export source '{}'", value))
+        }
+    }
+}
+
+impl From<ExportSource> for String {
+    fn from(value: ExportSource) -> Self {
+        match value {
+            ExportSource::Data => String::from("data"),
+            ExportSource::Buffer => String::from("buffer"),
+            ExportSource::Set => String::from("set")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportColumn {
+    pub name: String,
+    pub data_type: DataType
+}
+
+pub fn model_columns(model: &ModelSchema) -> Vec<ExportColumn>
+{
+    model.data_type.iter().enumerate().map(|(i, data_type)| {
+        let name = model.configs.get(i)
+            .and_then(|configs| configs.iter().find(|c| c.category == COLUMN_CATEGORY))
+            .and_then(|config| match &config.value {
+                DataValue::String(name) => Some(name.clone()),
+                _ => None
+            })
+            .unwrap_or(format!("field_{}", i));
+        ExportColumn { name, data_type: data_type.clone() }
+    }).collect()
+}
+
+pub fn set_columns(set: &SetSchema, models: &[ModelSchema]) -> Vec<ExportColumn>
+{
+    let mut columns: Vec<ExportColumn> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    for member in &set.members {
+        let model_columns = models.iter()
+            .find(|m| m.id == member.model_id)
+            .map(model_columns)
+            .unwrap_or_default();
+        for &index in &member.data_index {
+            let mut column = model_columns.get(index as usize).cloned()
+                .unwrap_or(ExportColumn { name: format!("field_{}", index), data_type: DataType::NullT });
+            // the same field of different members are distinguished by a number suffix
+            let count = names.iter().filter(|&name| *name == column.name).count();
+            names.push(column.name.clone());
+            if count > 0 {
+                column.name = format!("{}_{}", column.name, count);
+            }
+            columns.push(column);
+        }
+    }
+    columns
+}
+
+pub struct ExportRecord<'a> {
+    pub ids: Vec<Uuid>,
+    pub timestamp: DateTime<Utc>,
+    pub tag: i16,
+    pub data: &'a [DataValue]
+}
+
+pub trait ExportRow {
+    const ID_COLUMNS: &'static [&'static str];
+    fn record(&self) -> ExportRecord<'_>;
+}
+
+impl ExportRow for DataSchema {
+    const ID_COLUMNS: &'static [&'static str] = &["device_id", "model_id"];
+    fn record(&self) -> ExportRecord<'_> {
+        ExportRecord { ids: vec![self.device_id, self.model_id], timestamp: self.timestamp, tag: self.tag, data: &self.data }
+    }
+}
+
+impl ExportRow for BufferSchema {
+    const ID_COLUMNS: &'static [&'static str] = &["device_id", "model_id"];
+    fn record(&self) -> ExportRecord<'_> {
+        ExportRecord { ids: vec![self.device_id, self.model_id], timestamp: self.timestamp, tag: self.tag, data: &self.data }
+    }
+}
+
+impl ExportRow for DataSetSchema {
+    const ID_COLUMNS: &'static [&'static str] = &["set_id"];
+    fn record(&self) -> ExportRecord<'_> {
+        ExportRecord { ids: vec![self.set_id], timestamp: self.timestamp, tag: self.tag, data: &self.data }
+    }
+}
+
+enum ExportWriter {
+    Csv,
+    Ndjson,
+    Parquet(Box<SerializedFileWriter<Vec<u8>>>)
+}
+
+pub struct Exporter {
+    ids: &'static [&'static str],
+    columns: Vec<ExportColumn>,
+    writer: ExportWriter,
+    buffer: Vec<u8>
+}
+
+impl Exporter {
+
+    pub fn new<R: ExportRow>(format: ExportFormat, columns: &[ExportColumn]) -> Result<Self, Error>
+    {
+        let ids = R::ID_COLUMNS;
+        let mut buffer = Vec::new();
+        let writer = match format {
+            ExportFormat::Csv => {
+                // header line of the key columns followed by the data field columns
+                let names: Vec<String> = ids.iter().map(|s| s.to_string())
+                    .chain([String::from("timestamp"), String::from("tag")])
+                    .chain(columns.iter().map(|c| csv_escape(&c.name)))
+                    .collect();
+                buffer.extend_from_slice(names.join(",").as_bytes());
+                buffer.push(b'\n');
+                ExportWriter::Csv
+            },
+            ExportFormat::Ndjson => ExportWriter::Ndjson,
+            ExportFormat::Parquet => {
+                let schema = parquet_schema(ids, columns).map_err(parquet_error)?;
+                let properties = Arc::new(WriterProperties::builder().build());
+                let writer = SerializedFileWriter::new(Vec::new(), schema, properties).map_err(parquet_error)?;
+                ExportWriter::Parquet(Box::new(writer))
+            }
+        };
+        Ok(Self { ids, columns: columns.to_vec(), writer, buffer })
+    }
+
+    pub fn write<R: ExportRow>(&mut self, rows: &[R]) -> Result<Vec<u8>, Error>
+    {
+        let records: Vec<ExportRecord> = rows.iter().map(|r| r.record()).collect();
+        match &mut self.writer {
+            ExportWriter::Csv => {
+                for record in &records {
+                    let cells: Vec<String> = record.ids.iter().map(|id| id.to_string())
+                        .chain([format_timestamp(record.timestamp), record.tag.to_string()])
+                        .chain((0..self.columns.len()).map(|i| csv_value(record.data.get(i))))
+                        .collect();
+                    self.buffer.extend_from_slice(cells.join(",").as_bytes());
+                    self.buffer.push(b'\n');
+                }
+            },
+            ExportWriter::Ndjson => {
+                for record in &records {
+                    let fields: Vec<String> = self.ids.iter().zip(record.ids.iter())
+                        .map(|(name, id)| format!("{}:{}", json_string(name), json_string(&id.to_string())))
+                        .chain([
+                            format!("\"timestamp\":{}", json_string(&format_timestamp(record.timestamp))),
+                            format!("\"tag\":{}", record.tag)
+                        ])
+                        .chain(self.columns.iter().enumerate()
+                            .map(|(i, c)| format!("{}:{}", json_string(&c.name), json_value(record.data.get(i))))
+                        )
+                        .collect();
+                    self.buffer.push(b'{');
+                    self.buffer.extend_from_slice(fields.join(",").as_bytes());
+                    self.buffer.extend_from_slice(b"}\n");
+                }
+            },
+            ExportWriter::Parquet(writer) => {
+                if !records.is_empty() {
+                    write_row_group(writer, &records, self.ids.len(), &self.columns).map_err(parquet_error)?;
+                }
+                // take the bytes of finished row groups, remaining bytes are appended to the emptied writer
+                self.buffer.append(writer.inner_mut());
+            }
+        }
+        Ok(std::mem::take(&mut self.buffer))
+    }
+
+    pub fn finish(self) -> Result<Vec<u8>, Error>
+    {
+        let mut buffer = self.buffer;
+        if let ExportWriter::Parquet(writer) = self.writer {
+            buffer.append(&mut writer.into_inner().map_err(parquet_error)?);
+        }
+        Ok(buffer)
+    }
+
+}
+
+pub(crate) fn export_stream<R, S>(stream: S, mut exporter: Exporter) -> ReceiverStream<Result<Vec<u8>, Error>>
+where
+    R: ExportRow + Send + 'static,
+    S: Stream<Item = Result<R, Error>> + Send + 'static
+{
+    let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_SIZE);
+    tokio::spawn(async move {
+        let mut stream = std::pin::pin!(stream);
+        let mut batch = Vec::with_capacity(EXPORT_BATCH_SIZE);
+        loop {
+            let item = stream.next().await;
+            let end = item.is_none();
+            match item {
+                Some(Ok(row)) => batch.push(row),
+                Some(Err(e)) => {
+                    let _ = tx.send(Err(e)).await;
+                    return;
+                },
+                None => ()
+            }
+            // send a chunk every batch size rows and stop when the receiver is dropped
+            if batch.len() >= EXPORT_BATCH_SIZE || (end && !batch.is_empty()) {
+                let rows = std::mem::replace(&mut batch, Vec::with_capacity(EXPORT_BATCH_SIZE));
+                let chunk = match exporter.write(&rows) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                };
+                if !chunk.is_empty() && tx.send(Ok(chunk)).await.is_err() {
+                    return;
+                }
+            }
+            if end {
+                break;
+            }
+        }
+        let _ = tx.send(exporter.finish()).await;
+    });
+    ReceiverStream::new(rx)
+}
+
+fn parquet_error(e: parquet::errors::ParquetError) -> Error {
+    Error::Encode(Box::new(e))
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn bytes_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn value_string(value: &DataValue) -> Option<String> {
+    match value {
+        DataValue::I8(v) => Some(v.to_string()),
+        DataValue::I16(v) => Some(v.to_string()),
+        DataValue::I32(v) => Some(v.to_string()),
+        DataValue::I64(v) => Some(v.to_string()),
+        DataValue::I128(v) => Some(v.to_string()),
+        DataValue::U8(v) => Some(v.to_string()),
+        DataValue::U16(v) => Some(v.to_string()),
+        DataValue::U32(v) => Some(v.to_string()),
+        DataValue::U64(v) => Some(v.to_string()),
+        DataValue::U128(v) => Some(v.to_string()),
+        DataValue::F32(v) => Some(v.to_string()),
+        DataValue::F64(v) => Some(v.to_string()),
+        DataValue::Bool(v) => Some(v.to_string()),
+        DataValue::Char(v) => Some(v.to_string()),
+        DataValue::String(v) => Some(v.clone()),
+        DataValue::Bytes(v) => Some(bytes_hex(v)),
+        DataValue::Null => None
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn csv_value(value: Option<&DataValue>) -> String {
+    value.and_then(value_string).map(|s| csv_escape(&s)).unwrap_or_default()
+}
+
+fn json_string(value: &str) -> String {
+    let mut s = String::with_capacity(value.len() + 2);
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c)
+        }
+    }
+    s.push('"');
+    s
+}
+
+fn json_value(value: Option<&DataValue>) -> String {
+    match value {
+        // json has no representation of infinite and nan number
+        Some(DataValue::F32(v)) if !v.is_finite() => String::from("null"),
+        Some(DataValue::F64(v)) if !v.is_finite() => String::from("null"),
+        Some(DataValue::Char(_)) | Some(DataValue::String(_)) | Some(DataValue::Bytes(_)) =>
+            json_string(&value.and_then(value_string).unwrap_or_default()),
+        // 128 bit integers are written as string to keep their precision
+        Some(DataValue::I128(_)) | Some(DataValue::U128(_)) =>
+            json_string(&value.and_then(value_string).unwrap_or_default()),
+        Some(value) => value_string(value).unwrap_or(String::from("null")),
+        None => String::from("null")
+    }
+}
+
+fn parquet_schema(ids: &[&str], columns: &[ExportColumn]) -> parquet::errors::Result<TypePtr>
+{
+    let mut fields = Vec::new();
+    for &id in ids {
+        fields.push(Arc::new(Type::primitive_type_builder(id, PhysicalType::BYTE_ARRAY)
+            .with_repetition(Repetition::REQUIRED)
+            .with_logical_type(Some(LogicalType::String))
+            .build()?));
+    }
+    fields.push(Arc::new(Type::primitive_type_builder("timestamp", PhysicalType::INT64)
+        .with_repetition(Repetition::REQUIRED)
+        .with_logical_type(Some(LogicalType::Timestamp { is_adjusted_to_u_t_c: true, unit: TimeUnit::MICROS(MicroSeconds {}) }))
+        .build()?));
+    fields.push(Arc::new(Type::primitive_type_builder("tag", PhysicalType::INT32)
+        .with_repetition(Repetition::REQUIRED)
+        .with_logical_type(Some(LogicalType::Integer { bit_width: 16, is_signed: true }))
+        .build()?));
+    for column in columns {
+        let (physical, logical) = match column.data_type {
+            DataType::I8T => (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 8, is_signed: true })),
+            DataType::I16T => (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 16, is_signed: true })),
+            DataType::I32T => (PhysicalType::INT32, None),
+            DataType::I64T => (PhysicalType::INT64, None),
+            DataType::U8T => (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 8, is_signed: false })),
+            DataType::U16T => (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 16, is_signed: false })),
+            DataType::U32T => (PhysicalType::INT32, Some(LogicalType::Integer { bit_width: 32, is_signed: false })),
+            DataType::U64T => (PhysicalType::INT64, Some(LogicalType::Integer { bit_width: 64, is_signed: false })),
+            DataType::F32T => (PhysicalType::FLOAT, None),
+            DataType::F64T => (PhysicalType::DOUBLE, None),
+            DataType::BoolT => (PhysicalType::BOOLEAN, None),
+            DataType::BytesT => (PhysicalType::BYTE_ARRAY, None),
+            // 128 bit integers have no parquet integer type so they are written as string
+            _ => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String))
+        };
+        fields.push(Arc::new(Type::primitive_type_builder(&column.name, physical)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(logical)
+            .build()?));
+    }
+    let schema = Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()?;
+    Ok(Arc::new(schema))
+}
+
+// values of an optional column with definition level 1 for a present value and 0 for null
+fn column_values<T, F>(records: &[ExportRecord], index: usize, f: F) -> (Vec<T>, Vec<i16>)
+where
+    F: Fn(&DataValue) -> Option<T>
+{
+    let mut values = Vec::with_capacity(records.len());
+    let mut levels = Vec::with_capacity(records.len());
+    for record in records {
+        match record.data.get(index).and_then(&f) {
+            Some(value) => {
+                values.push(value);
+                levels.push(1);
+            },
+            None => levels.push(0)
+        }
+    }
+    (values, levels)
+}
+
+fn write_row_group(writer: &mut SerializedFileWriter<Vec<u8>>, records: &[ExportRecord], id_number: usize, columns: &[ExportColumn])
+    -> parquet::errors::Result<()>
+{
+    let mut row_group = writer.next_row_group()?;
+    let mut position = 0;
+    while let Some(mut column) = row_group.next_column()? {
+        if position < id_number {
+            let values: Vec<ByteArray> = records.iter().map(|r| ByteArray::from(r.ids[position].to_string().as_str())).collect();
+            column.typed::<ByteArrayType>().write_batch(&values, None, None)?;
+        } else if position == id_number {
+            let values: Vec<i64> = records.iter().map(|r| r.timestamp.timestamp_micros()).collect();
+            column.typed::<Int64Type>().write_batch(&values, None, None)?;
+        } else if position == id_number + 1 {
+            let values: Vec<i32> = records.iter().map(|r| r.tag as i32).collect();
+            column.typed::<Int32Type>().write_batch(&values, None, None)?;
+        } else {
+            let index = position - id_number - 2;
+            match columns[index].data_type {
+                DataType::I8T | DataType::I16T | DataType::I32T | DataType::U8T | DataType::U16T | DataType::U32T => {
+                    let (values, levels) = column_values(records, index, |v| match *v {
+                        DataValue::I8(v) => Some(v as i32),
+                        DataValue::I16(v) => Some(v as i32),
+                        DataValue::I32(v) => Some(v),
+                        DataValue::U8(v) => Some(v as i32),
+                        DataValue::U16(v) => Some(v as i32),
+                        DataValue::U32(v) => Some(v as i32),
+                        _ => None
+                    });
+                    column.typed::<Int32Type>().write_batch(&values, Some(&levels), None)?;
+                },
+                DataType::I64T | DataType::U64T => {
+                    let (values, levels) = column_values(records, index, |v| match *v {
+                        DataValue::I64(v) => Some(v),
+                        DataValue::U64(v) => Some(v as i64),
+                        _ => None
+                    });
+                    column.typed::<Int64Type>().write_batch(&values, Some(&levels), None)?;
+                },
+                DataType::F32T => {
+                    let (values, levels) = column_values(records, index, |v| match *v {
+                        DataValue::F32(v) => Some(v),
+                        _ => None
+                    });
+                    column.typed::<FloatType>().write_batch(&values, Some(&levels), None)?;
+                },
+                DataType::F64T => {
+                    let (values, levels) = column_values(records, index, |v| match *v {
+                        DataValue::F64(v) => Some(v),
+                        _ => None
+                    });
+                    column.typed::<DoubleType>().write_batch(&values, Some(&levels), None)?;
+                },
+                DataType::BoolT => {
+                    let (values, levels) = column_values(records, index, |v| match *v {
+                        DataValue::Bool(v) => Some(v),
+                        _ => None
+                    });
+                    column.typed::<BoolType>().write_batch(&values, Some(&levels), None)?;
+                },
+                DataType::BytesT => {
+                    let (values, levels) = column_values(records, index, |v| match v {
+                        DataValue::Bytes(v) => Some(ByteArray::from(v.clone())),
+                        _ => None
+                    });
+                    column.typed::<ByteArrayType>().write_batch(&values, Some(&levels), None)?;
+                },
+                _ => {
+                    let (values, levels) = column_values(records, index, |v| value_string(v).map(|s| ByteArray::from(s.as_str())));
+                    column.typed::<ByteArrayType>().write_batch(&values, Some(&levels), None)?;
+                }
+            }
+        }
+        column.close()?;
+        position += 1;
+    }
+    row_group.close()?;
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use sea_query::{IntoIden, DynIden, Query, Expr, Order, Asterisk};
+use crate::common::query_statement::QueryStatement;
+use crate::resource::device::{Device, DeviceTypeModel};
+use crate::resource::data::Data;
+use crate::resource::buffer::DataBuffer;
+use crate::resource::set::SetMember;
+
+// strict mode rejects a write of a model which is not linked to the type of the device,
+// warn mode accepts the write, the number of violating rows is returned by the write
+// and the rows are reported by the integrity violation list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegrityMode {
+    #[default]
+    Warn,
+    Strict
+}
+
+impl TryFrom<&str> for IntegrityMode {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "warn" => Ok(Self::Warn),
+            "strict" => Ok(Self::Strict),
+            _ => Err(format!("Unknown integrity mode '{}'", value))
+        }
+    }
+}
+
+impl From<IntegrityMode> for String {
+    fn from(value: IntegrityMode) -> Self {
+        match value {
+            IntegrityMode::Warn => String::from("warn"),
+            IntegrityMode::Strict => String::from("strict")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrityTable {
+    Data,
+    Buffer,
+    Set
+}
+
+impl IntegrityTable {
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            Self::Data => "data",
+            Self::Buffer => "data_buffer",
+            Self::Set => "set_member"
+        }
+    }
+    fn columns(&self) -> (DynIden, DynIden, DynIden) {
+        match self {
+            Self::Data => (Data::Table.into_iden(), Data::DeviceId.into_iden(), Data::ModelId.into_iden()),
+            Self::Buffer => (DataBuffer::Table.into_iden(), DataBuffer::DeviceId.into_iden(), DataBuffer::ModelId.into_iden()),
+            Self::Set => (SetMember::Table.into_iden(), SetMember::DeviceId.into_iden(), SetMember::ModelId.into_iden())
+        }
+    }
+}
+
+impl TryFrom<&str> for IntegrityTable {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "data" => Ok(Self::Data),
+            "buffer" | "data_buffer" => Ok(Self::Buffer),
+            "set" | "set_member" => Ok(Self::Set),
+            _ => Err(format!("Unknown integrity table '{}'", value))
+        }
+    }
+}
+
+impl From<IntegrityTable> for String {
+    fn from(value: IntegrityTable) -> Self {
+        match value {
+            IntegrityTable::Data => String::from("data"),
+            IntegrityTable::Buffer => String::from("buffer"),
+            IntegrityTable::Set => String::from("set")
+        }
+    }
+}
+
+pub fn select_integrity_violation(
+    table: IntegrityTable
+) -> QueryStatement
+{
+    let (table, device_id, model_id) = table.columns();
+    // a row is valid when its model is linked to the type of its device
+    let type_model = Query::select()
+        .expr(Expr::val(1))
+        .from(Device::Table)
+        .inner_join(DeviceTypeModel::Table,
+            Expr::col((Device::Table, Device::TypeId))
+            .equals((DeviceTypeModel::Table, DeviceTypeModel::TypeId))
+        )
+        .and_where(Expr::col((Device::Table, Device::DeviceId)).equals((table.clone(), device_id.clone())))
+        .and_where(Expr::col((DeviceTypeModel::Table, DeviceTypeModel::ModelId)).equals((table.clone(), model_id.clone())))
+        .to_owned();
+    let stmt = Query::select()
+        .column((table.clone(), device_id.clone()))
+        .column((table.clone(), model_id.clone()))
+        .expr(Expr::col(Asterisk).count())
+        .from(table.clone())
+        .and_where(Expr::exists(type_model).not())
+        .group_by_col((table.clone(), device_id.clone()))
+        .group_by_col((table.clone(), model_id.clone()))
+        .order_by((table.clone(), device_id), Order::Asc)
+        .order_by((table, model_id), Order::Asc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
@@ -0,0 +1,185 @@
+use sea_query::{Iden, Query, Expr, Order, Condition};
+use sqlx::types::chrono::{DateTime, Utc};
+use chrono::Duration;
+use uuid::Uuid;
+use crate::common::query_statement::QueryStatement;
+use crate::resource::_schema::RetentionSchema;
+use crate::resource::data::Data;
+use crate::resource::device::Device;
+
+#[derive(Iden)]
+pub(crate) enum DataRetention {
+    Table,
+    Id,
+    ModelId,
+    TypeId,
+    Tag,
+    Retention
+}
+
+pub fn select_retention(
+    ids: Option<&[i32]>,
+    model_id: Option<Uuid>,
+    type_id: Option<Uuid>,
+    tag: Option<i16>
+) -> QueryStatement
+{
+    let mut stmt = Query::select()
+        .columns([
+            DataRetention::Id,
+            DataRetention::ModelId,
+            DataRetention::TypeId,
+            DataRetention::Tag,
+            DataRetention::Retention
+        ])
+        .from(DataRetention::Table)
+        .to_owned();
+
+    if let Some(ids) = ids {
+        if ids.len() == 1 {
+            stmt = stmt.and_where(Expr::col(DataRetention::Id).eq(ids[0])).to_owned();
+        } else {
+            stmt = stmt.and_where(Expr::col(DataRetention::Id).is_in(ids.to_vec())).to_owned();
+        }
+    }
+    else {
+        if let Some(id) = model_id {
+            stmt = stmt.and_where(Expr::col(DataRetention::ModelId).eq(id)).to_owned();
+        }
+        if let Some(id) = type_id {
+            stmt = stmt.and_where(Expr::col(DataRetention::TypeId).eq(id)).to_owned();
+        }
+        if let Some(tag) = tag {
+            stmt = stmt.and_where(Expr::col(DataRetention::Tag).eq(tag)).to_owned();
+        }
+    }
+    stmt = stmt.order_by(DataRetention::Id, Order::Asc).to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn insert_retention(
+    model_id: Option<Uuid>,
+    type_id: Option<Uuid>,
+    tag: Option<i16>,
+    retention: Duration
+) -> QueryStatement
+{
+    let stmt = Query::insert()
+        .into_table(DataRetention::Table)
+        .columns([
+            DataRetention::ModelId,
+            DataRetention::TypeId,
+            DataRetention::Tag,
+            DataRetention::Retention
+        ])
+        .values([
+            model_id.into(),
+            type_id.into(),
+            tag.into(),
+            retention.num_seconds().into()
+        ])
+        .unwrap_or(&mut sea_query::InsertStatement::default())
+        .returning(Query::returning().column(DataRetention::Id))
+        .to_owned();
+
+    QueryStatement::Insert(stmt)
+}
+
+pub fn update_retention(
+    id: i32,
+    retention: Duration
+) -> QueryStatement
+{
+    let stmt = Query::update()
+        .table(DataRetention::Table)
+        .value(DataRetention::Retention, retention.num_seconds())
+        .and_where(Expr::col(DataRetention::Id).eq(id))
+        .to_owned();
+
+    QueryStatement::Update(stmt)
+}
+
+pub fn delete_retention(
+    id: i32
+) -> QueryStatement
+{
+    let stmt = Query::delete()
+        .from_table(DataRetention::Table)
+        .and_where(Expr::col(DataRetention::Id).eq(id))
+        .to_owned();
+
+    QueryStatement::Delete(stmt)
+}
+
+fn retention_keys(rule: &RetentionSchema) -> Condition
+{
+    let mut condition = Condition::all();
+    if let Some(model_id) = rule.model_id {
+        condition = condition.add(Expr::col((Data::Table, Data::ModelId)).eq(model_id));
+    }
+    if let Some(type_id) = rule.type_id {
+        let devices = Query::select()
+            .column(Device::DeviceId)
+            .from(Device::Table)
+            .and_where(Expr::col(Device::TypeId).eq(type_id))
+            .to_owned();
+        condition = condition.add(Expr::col((Data::Table, Data::DeviceId)).in_subquery(devices));
+    }
+    if let Some(tag) = rule.tag {
+        condition = condition.add(Expr::col((Data::Table, Data::Tag)).eq(tag));
+    }
+    condition
+}
+
+fn is_narrower(rule: &RetentionSchema, other: &RetentionSchema) -> bool
+{
+    // a rule is narrower when it has every key of other rule with the same value plus additional keys
+    let keys = |r: &RetentionSchema| [r.model_id.is_some(), r.type_id.is_some(), r.tag.is_some()].into_iter().filter(|&k| k).count();
+    rule.id != other.id
+        && other.model_id.is_none_or(|id| rule.model_id == Some(id))
+        && other.type_id.is_none_or(|id| rule.type_id == Some(id))
+        && other.tag.is_none_or(|tag| rule.tag == Some(tag))
+        && keys(rule) > keys(other)
+}
+
+fn retention_condition(rule: &RetentionSchema, rules: &[RetentionSchema], now: DateTime<Utc>) -> Condition
+{
+    let mut condition = Condition::all()
+        .add(Expr::col((Data::Table, Data::Timestamp)).lt(now - rule.retention))
+        .add(retention_keys(rule));
+    // data matched by a more specific rule is kept by that rule instead
+    for other in rules.iter().filter(|r| is_narrower(r, rule)) {
+        condition = condition.add(retention_keys(other).not());
+    }
+    condition
+}
+
+pub fn count_retention_data(
+    rule: &RetentionSchema,
+    rules: &[RetentionSchema],
+    now: DateTime<Utc>
+) -> QueryStatement
+{
+    let stmt = Query::select()
+        .expr(Expr::col((Data::Table, Data::Timestamp)).count())
+        .from(Data::Table)
+        .cond_where(retention_condition(rule, rules, now))
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn delete_retention_data(
+    rule: &RetentionSchema,
+    rules: &[RetentionSchema],
+    now: DateTime<Utc>
+) -> QueryStatement
+{
+    let stmt = Query::delete()
+        .from_table(Data::Table)
+        .cond_where(retention_condition(rule, rules, now))
+        .to_owned();
+
+    QueryStatement::Delete(stmt)
+}
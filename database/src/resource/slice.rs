@@ -1,7 +1,9 @@
 use sea_query::{Iden, Query, Expr, Order};
 use sqlx::types::chrono::{DateTime, Utc};
+use chrono::Duration;
 use uuid::Uuid;
 use crate::common::query_statement::QueryStatement;
+use crate::resource::_schema::{DataSchema, SliceRuleSchema};
 
 #[derive(Iden)]
 pub(crate) enum SliceData {
@@ -102,6 +104,29 @@ pub fn select_slice(
     QueryStatement::Select(stmt)
 }
 
+pub fn select_slice_page(
+    selector: SliceSelector,
+    device_ids: Option<&[Uuid]>,
+    model_ids: Option<&[Uuid]>,
+    name: Option<&str>,
+    after: Option<i32>,
+    limit: usize
+) -> QueryStatement
+{
+    let mut stmt = match select_slice(selector, None, device_ids, model_ids, name) {
+        QueryStatement::Select(stmt) => stmt,
+        qs => return qs
+    };
+
+    // keyset pagination using the last id of previous page
+    if let Some(id) = after {
+        stmt = stmt.and_where(Expr::col(SliceData::Id).gt(id)).to_owned();
+    }
+    stmt = stmt.limit(limit as u64).to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
 pub fn insert_slice(
     device_id: Uuid,
     model_id: Uuid,
@@ -307,3 +332,268 @@ pub fn delete_slice_set(
 
     QueryStatement::Delete(stmt)
 }
+
+#[derive(Iden)]
+pub(crate) enum SliceRule {
+    Table,
+    Id,
+    DeviceId,
+    ModelId,
+    Index,
+    Condition,
+    Threshold,
+    MinDuration,
+    MaxGap,
+    Name,
+    Checkpoint
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliceCondition {
+    #[default]
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    Equal,
+    NotEqual
+}
+
+impl SliceCondition {
+    pub fn compare(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Self::Greater => value > threshold,
+            Self::GreaterEqual => value >= threshold,
+            Self::Less => value < threshold,
+            Self::LessEqual => value <= threshold,
+            Self::Equal => value == threshold,
+            Self::NotEqual => value != threshold
+        }
+    }
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Greater => ">",
+            Self::GreaterEqual => ">=",
+            Self::Less => "<",
+            Self::LessEqual => "<=",
+            Self::Equal => "==",
+            Self::NotEqual => "!="
+        }
+    }
+}
+
+impl TryFrom<&str> for SliceCondition {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "gt" | ">" => Ok(Self::Greater),
+            "gte" | ">=" => Ok(Self::GreaterEqual),
+            "lt" | "<" => Ok(Self::Less),
+            "lte" | "<=" => Ok(Self::LessEqual),
+            "eq" | "==" | "=" => Ok(Self::Equal),
+            "ne" | "!=" => Ok(Self::NotEqual),
+            _ => Err(format!("Unknown slice condition '{}'", value))
+        }
+    }
+}
+
+impl From<SliceCondition> for String {
+    fn from(value: SliceCondition) -> Self {
+        match value {
+            SliceCondition::Greater => String::from("gt"),
+            SliceCondition::GreaterEqual => String::from("gte"),
+            SliceCondition::Less => String::from("lt"),
+            SliceCondition::LessEqual => String::from("lte"),
+            SliceCondition::Equal => String::from("eq"),
+            SliceCondition::NotEqual => String::from("ne")
+        }
+    }
+}
+
+// detection rule of a data field index of a device and model to create
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceRuleCreate {
+    pub device_id: Uuid,
+    pub model_id: Uuid,
+    pub index: usize,
+    pub condition: SliceCondition,
+    pub threshold: f64,
+    pub min_duration: Duration,
+    pub max_gap: Duration,
+    pub name: String
+}
+
+pub fn select_slice_rule(
+    ids: Option<&[i32]>,
+    device_id: Option<Uuid>,
+    model_id: Option<Uuid>
+) -> QueryStatement
+{
+    let mut stmt = Query::select()
+        .columns([
+            SliceRule::Id,
+            SliceRule::DeviceId,
+            SliceRule::ModelId,
+            SliceRule::Index,
+            SliceRule::Condition,
+            SliceRule::Threshold,
+            SliceRule::MinDuration,
+            SliceRule::MaxGap,
+            SliceRule::Name,
+            SliceRule::Checkpoint
+        ])
+        .from(SliceRule::Table)
+        .to_owned();
+
+    if let Some(ids) = ids {
+        if ids.len() == 1 {
+            stmt = stmt.and_where(Expr::col(SliceRule::Id).eq(ids[0])).to_owned();
+        } else {
+            stmt = stmt.and_where(Expr::col(SliceRule::Id).is_in(ids.to_vec())).to_owned();
+        }
+    }
+    else {
+        if let Some(id) = device_id {
+            stmt = stmt.and_where(Expr::col(SliceRule::DeviceId).eq(id)).to_owned();
+        }
+        if let Some(id) = model_id {
+            stmt = stmt.and_where(Expr::col(SliceRule::ModelId).eq(id)).to_owned();
+        }
+    }
+    stmt = stmt.order_by(SliceRule::Id, Order::Asc).to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn insert_slice_rule(
+    rule: &SliceRuleCreate
+) -> QueryStatement
+{
+    let stmt = Query::insert()
+        .into_table(SliceRule::Table)
+        .columns([
+            SliceRule::DeviceId,
+            SliceRule::ModelId,
+            SliceRule::Index,
+            SliceRule::Condition,
+            SliceRule::Threshold,
+            SliceRule::MinDuration,
+            SliceRule::MaxGap,
+            SliceRule::Name
+        ])
+        .values([
+            rule.device_id.into(),
+            rule.model_id.into(),
+            (rule.index as i16).into(),
+            String::from(rule.condition).into(),
+            rule.threshold.into(),
+            rule.min_duration.num_microseconds().unwrap_or(i64::MAX).into(),
+            rule.max_gap.num_microseconds().unwrap_or(i64::MAX).into(),
+            rule.name.as_str().into()
+        ])
+        .unwrap_or(&mut sea_query::InsertStatement::default())
+        .returning(Query::returning().column(SliceRule::Id))
+        .to_owned();
+
+    QueryStatement::Insert(stmt)
+}
+
+pub fn update_slice_rule(
+    id: i32,
+    condition: Option<SliceCondition>,
+    threshold: Option<f64>,
+    min_duration: Option<Duration>,
+    max_gap: Option<Duration>,
+    name: Option<&str>,
+    checkpoint: Option<DateTime<Utc>>
+) -> QueryStatement
+{
+    let mut stmt = Query::update()
+        .table(SliceRule::Table)
+        .to_owned();
+
+    if let Some(condition) = condition {
+        stmt = stmt.value(SliceRule::Condition, String::from(condition)).to_owned();
+    }
+    if let Some(threshold) = threshold {
+        stmt = stmt.value(SliceRule::Threshold, threshold).to_owned();
+    }
+    if let Some(duration) = min_duration {
+        stmt = stmt.value(SliceRule::MinDuration, duration.num_microseconds().unwrap_or(i64::MAX)).to_owned();
+    }
+    if let Some(duration) = max_gap {
+        stmt = stmt.value(SliceRule::MaxGap, duration.num_microseconds().unwrap_or(i64::MAX)).to_owned();
+    }
+    if let Some(name) = name {
+        stmt = stmt.value(SliceRule::Name, name).to_owned();
+    }
+    if let Some(timestamp) = checkpoint {
+        stmt = stmt.value(SliceRule::Checkpoint, timestamp).to_owned();
+    }
+    let stmt = stmt
+        .and_where(Expr::col(SliceRule::Id).eq(id))
+        .to_owned();
+
+    QueryStatement::Update(stmt)
+}
+
+pub fn delete_slice_rule(
+    id: i32
+) -> QueryStatement
+{
+    let stmt = Query::delete()
+        .from_table(SliceRule::Table)
+        .and_where(Expr::col(SliceRule::Id).eq(id))
+        .to_owned();
+
+    QueryStatement::Delete(stmt)
+}
+
+// begin and end timestamp of matched data run
+pub type SliceRun = (DateTime<Utc>, DateTime<Utc>);
+
+// detector state is kept between pages of scanned data so a run can span several pages
+#[derive(Debug, Clone)]
+pub struct SliceDetector<'a> {
+    rule: &'a SliceRuleSchema,
+    current: Option<SliceRun>,
+    interrupted: bool
+}
+
+impl<'a> SliceDetector<'a> {
+
+    pub fn new(rule: &'a SliceRuleSchema) -> Self
+    {
+        Self { rule, current: None, interrupted: false }
+    }
+
+    pub fn push(&mut self, data: &DataSchema) -> Option<SliceRun>
+    {
+        // a run of matched data continues over unmatched data as long as the gap to the next matched data is within max gap
+        let matched = data.data.get(self.rule.index as usize)
+            .and_then(|v| v.to_f64())
+            .map(|v| self.rule.condition.compare(v, self.rule.threshold))
+            .unwrap_or(false);
+        let closed = match self.current {
+            // the run is closed as soon as unmatched data beyond max gap is found
+            Some((_, end)) if !matched && data.timestamp - end > self.rule.max_gap => self.current.take(),
+            Some((_, end)) if matched && self.interrupted && data.timestamp - end > self.rule.max_gap => self.current.take(),
+            _ => None
+        };
+        self.interrupted = !matched && self.current.is_some();
+        if matched {
+            self.current = match self.current {
+                Some((begin, _)) => Some((begin, data.timestamp)),
+                None => Some((data.timestamp, data.timestamp))
+            };
+        }
+        closed.filter(|(begin, end)| *end - *begin >= self.rule.min_duration)
+    }
+
+    pub fn open(&self) -> Option<SliceRun>
+    {
+        // the last run may still grow with later data
+        self.current
+    }
+
+}
@@ -0,0 +1,147 @@
+use sea_query::{Iden, Query, Expr, Order, Func, Alias};
+use crate::common::query_statement::QueryStatement;
+
+#[derive(Iden)]
+pub(crate) enum BufferTransition {
+    Table,
+    Tag,
+    NewTag
+}
+
+#[derive(Iden)]
+pub(crate) enum BufferHistory {
+    Table,
+    Id,
+    BufferId,
+    Tag,
+    NewTag,
+    ChangedBy,
+    ChangedAt
+}
+
+// open mode lets a tag without any configured transition be changed to any tag so existing tag updates keep working.
+// strict mode only allows the configured transitions so a tag without any configured transition can't be changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionMode {
+    Strict,
+    #[default]
+    Open
+}
+
+impl TryFrom<&str> for TransitionMode {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "open" => Ok(Self::Open),
+            _ => Err(format!("Unknown transition mode '{}'", value))
+        }
+    }
+}
+
+impl From<TransitionMode> for String {
+    fn from(value: TransitionMode) -> Self {
+        match value {
+            TransitionMode::Strict => String::from("strict"),
+            TransitionMode::Open => String::from("open")
+        }
+    }
+}
+
+// session setting read by the buffer history trigger to record who changed a buffer tag
+pub(crate) const CHANGED_BY_SETTING: &str = "bbthings.changed_by";
+
+pub fn select_buffer_transition(
+    tags: Option<&[i16]>
+) -> QueryStatement
+{
+    let mut stmt = Query::select()
+        .columns([
+            BufferTransition::Tag,
+            BufferTransition::NewTag
+        ])
+        .from(BufferTransition::Table)
+        .to_owned();
+
+    if let Some(tags) = tags {
+        stmt = stmt.and_where(Expr::col(BufferTransition::Tag).is_in(tags.to_vec())).to_owned();
+    }
+    let stmt = stmt
+        .order_by(BufferTransition::Tag, Order::Asc)
+        .order_by(BufferTransition::NewTag, Order::Asc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn insert_buffer_transition(
+    tag: i16,
+    new_tag: i16
+) -> QueryStatement
+{
+    let stmt = Query::insert()
+        .into_table(BufferTransition::Table)
+        .columns([
+            BufferTransition::Tag,
+            BufferTransition::NewTag
+        ])
+        .values([
+            tag.into(),
+            new_tag.into()
+        ])
+        .unwrap_or(&mut sea_query::InsertStatement::default())
+        .to_owned();
+
+    QueryStatement::Insert(stmt)
+}
+
+pub fn delete_buffer_transition(
+    tag: i16,
+    new_tag: i16
+) -> QueryStatement
+{
+    let stmt = Query::delete()
+        .from_table(BufferTransition::Table)
+        .and_where(Expr::col(BufferTransition::Tag).eq(tag))
+        .and_where(Expr::col(BufferTransition::NewTag).eq(new_tag))
+        .to_owned();
+
+    QueryStatement::Delete(stmt)
+}
+
+pub fn select_buffer_history(
+    buffer_id: i32
+) -> QueryStatement
+{
+    let stmt = Query::select()
+        .columns([
+            BufferHistory::Id,
+            BufferHistory::BufferId,
+            BufferHistory::Tag,
+            BufferHistory::NewTag,
+            BufferHistory::ChangedBy,
+            BufferHistory::ChangedAt
+        ])
+        .from(BufferHistory::Table)
+        .and_where(Expr::col(BufferHistory::BufferId).eq(buffer_id))
+        .order_by(BufferHistory::Id, Order::Asc)
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
+
+pub fn select_changed_by(
+    changed_by: &str
+) -> QueryStatement
+{
+    // the setting is local to the transaction so it doesn't leak to other queries of a pooled connection
+    let stmt = Query::select()
+        .expr(Func::cust(Alias::new("set_config")).args([
+            Expr::val(CHANGED_BY_SETTING).into(),
+            Expr::val(changed_by).into(),
+            Expr::val(true).into()
+        ]))
+        .to_owned();
+
+    QueryStatement::Select(stmt)
+}
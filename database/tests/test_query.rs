@@ -6,6 +6,7 @@ mod tests {
     // use bbthings_database::auth::{api, role, user, profile, token};
     use bbthings_database::auth::{api, role, user, profile, token};
     use bbthings_database::resource::{model, device, group, set, data, buffer, slice};
+    use bbthings_database::resource::data::DataConflict;
     use bbthings_database::{DataType, DataValue, SetMember, SetTemplateMember};
     use bbthings_database::common::tag as Tag;
 
@@ -813,21 +814,89 @@ mod tests {
             ORDER BY "data"."timestamp" ASC
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
-        let qs = data::insert_data(device_id, model_id, timestamp, &data_value, Some(tag));
+        let qs = data::select_data_latest(&device_ids, &model_ids, None);
+        let s = r#"
+            SELECT DISTINCT ON ("data"."device_id", "data"."model_id") 
+                "data"."device_id", "data"."model_id", "data"."timestamp", "data"."tag", "data"."data", "model"."data_type" 
+            FROM "data" 
+            INNER JOIN "model" ON "data"."model_id" = "model"."model_id" 
+            WHERE "data"."device_id" IN ('a2be5346-4014-4844-9a60-56e3392c1ce3', 'f55c7ded-3615-4ab4-9fa3-c05f71668f68') 
+            AND "data"."model_id" IN ('38723da0-768d-4570-9be7-f8808f7c10c1', '183550a1-e55e-421d-9e34-fb7bf834195c') 
+            ORDER BY "data"."device_id" ASC, "data"."model_id" ASC, "data"."timestamp" DESC
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let qs = data::insert_data(device_id, model_id, timestamp, &data_value, Some(tag), DataConflict::Error);
         let s = r#"
             INSERT INTO "data" ("device_id", "model_id", "timestamp", "tag", "data") 
             VALUES ('773fe850-10d0-4012-8c0a-495dc6990b18', 'df467d0a-4904-4162-b08b-bd4b992cdefe', '2023-05-07 07:08:48.123456 +00:00', -1, 
-                '\x000003E8BFBF9A6B50B0F27C03616263')
+                '\x000003E8BFBF9A6B50B0F27C03616263') 
+            RETURNING xmax = 0
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
-        let qs = data::insert_data_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]));
+        let qs = data::insert_data_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]), DataConflict::Error);
         let s = r#"
             INSERT INTO "data" ("device_id", "model_id", "timestamp", "tag", "data") 
             VALUES 
                 ('a2be5346-4014-4844-9a60-56e3392c1ce3', '38723da0-768d-4570-9be7-f8808f7c10c1', '2023-05-07 07:08:48.123456 +00:00', -1, 
                 '\x000003E8BFBF9A6B50B0F27C03616263'),
                 ('f55c7ded-3615-4ab4-9fa3-c05f71668f68', '183550a1-e55e-421d-9e34-fb7bf834195c', '2023-05-07 07:08:48.123456 +00:00', -1, 
-                '\x000003E8BFBF9A6B50B0F27C03616263')
+                '\x000003E8BFBF9A6B50B0F27C03616263') 
+            RETURNING xmax = 0
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let qs = data::insert_data_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]), DataConflict::Ignore);
+        let s = r#"
+            INSERT INTO "data" ("device_id", "model_id", "timestamp", "tag", "data") 
+            VALUES 
+                ('a2be5346-4014-4844-9a60-56e3392c1ce3', '38723da0-768d-4570-9be7-f8808f7c10c1', '2023-05-07 07:08:48.123456 +00:00', -1, 
+                '\x000003E8BFBF9A6B50B0F27C03616263'),
+                ('f55c7ded-3615-4ab4-9fa3-c05f71668f68', '183550a1-e55e-421d-9e34-fb7bf834195c', '2023-05-07 07:08:48.123456 +00:00', -1, 
+                '\x000003E8BFBF9A6B50B0F27C03616263') 
+            ON CONFLICT ("timestamp", "model_id", "device_id", "tag") DO NOTHING 
+            RETURNING xmax = 0
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let begin = DateTime::parse_from_str("2023-05-07 07:00:00.000000 +0000", "%Y-%m-%d %H:%M:%S.%6f %z").unwrap().into();
+        let qs = data::select_data_range_lock(device_id, model_id, begin, timestamp, None, Some((begin, Tag::DEFAULT)), 1000);
+        let s = r#"
+            SELECT "data"."device_id", "data"."model_id", "data"."timestamp", "data"."tag", "data"."data", "model"."data_type" 
+            FROM "data" 
+            INNER JOIN "model" ON "data"."model_id" = "model"."model_id" 
+            WHERE "data"."device_id" = '773fe850-10d0-4012-8c0a-495dc6990b18' 
+            AND "data"."model_id" = 'df467d0a-4904-4162-b08b-bd4b992cdefe' 
+            AND "data"."timestamp" >= '2023-05-07 07:00:00.000000 +00:00' 
+            AND "data"."timestamp" <= '2023-05-07 07:08:48.123456 +00:00' 
+            AND ("data"."timestamp", "data"."tag") > ('2023-05-07 07:00:00.000000 +00:00', 0) 
+            ORDER BY "data"."timestamp" ASC, "data"."tag" ASC 
+            LIMIT 1000 
+            FOR UPDATE OF "data"
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let qs = data::select_data_tag_page(device_id, model_id, Some(begin), timestamp, Tag::DEFAULT, 100);
+        let s = r#"
+            SELECT "data"."device_id", "data"."model_id", "data"."timestamp", "data"."tag", "data"."data", "model"."data_type" 
+            FROM "data" 
+            INNER JOIN "model" ON "data"."model_id" = "model"."model_id" 
+            WHERE "data"."device_id" = '773fe850-10d0-4012-8c0a-495dc6990b18' 
+            AND "data"."model_id" = 'df467d0a-4904-4162-b08b-bd4b992cdefe' 
+            AND "data"."tag" = 0 
+            AND "data"."timestamp" <= '2023-05-07 07:08:48.123456 +00:00' 
+            AND "data"."timestamp" >= '2023-05-07 07:00:00.000000 +00:00' 
+            ORDER BY "data"."timestamp" ASC 
+            LIMIT 100
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let qs = data::update_data_multiple(device_id, model_id, &[(begin, Tag::DEFAULT, &data_value), (timestamp, tag, &data_value)]);
+        let s = r#"
+            UPDATE "data" 
+            SET "data" = (CASE 
+                WHEN ("timestamp" = '2023-05-07 07:00:00.000000 +00:00' AND "tag" = 0) THEN '\x000003E8BFBF9A6B50B0F27C03616263' 
+                WHEN ("timestamp" = '2023-05-07 07:08:48.123456 +00:00' AND "tag" = -1) THEN '\x000003E8BFBF9A6B50B0F27C03616263' 
+                ELSE "data" END) 
+            WHERE "device_id" = '773fe850-10d0-4012-8c0a-495dc6990b18' 
+            AND "model_id" = 'df467d0a-4904-4162-b08b-bd4b992cdefe' 
+            AND (("timestamp" = '2023-05-07 07:00:00.000000 +00:00' AND "tag" = 0) 
+                OR ("timestamp" = '2023-05-07 07:08:48.123456 +00:00' AND "tag" = -1))
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
         let qs = data::delete_data(device_id, model_id, timestamp, Some(tag));
@@ -935,20 +1004,30 @@ mod tests {
             LIMIT 100 OFFSET 200
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
-        let qs = buffer::insert_buffer(device_id, model_id, timestamp, &data_value, Some(tag));
+        let qs = buffer::insert_buffer(device_id, model_id, timestamp, &data_value, Some(tag), DataConflict::Error);
         let s = r#"
             INSERT INTO "data_buffer" ("device_id", "model_id", "timestamp", "tag", "data") 
             VALUES ('e81a6fb3-731d-45b7-9195-8a1c6690f31b', '0dcb2faa-12a0-4a81-a3e2-5c7dc5252c61', '2023-05-07 07:08:48.123456 +00:00', -1, '\xFFFFFC183FBF9A6B50B0F27C025F2D') 
-            RETURNING "id"
+            RETURNING "id", xmax = 0
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
-        let qs = buffer::insert_buffer_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]));
+        let qs = buffer::insert_buffer_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]), DataConflict::Error);
         let s = r#"
             INSERT INTO "data_buffer" ("device_id", "model_id", "timestamp", "tag", "data") 
             VALUES 
                 ('30bd8a90-1669-4d56-a67d-b709b2497156', 'e3933119-f5e9-47f5-892b-7dde010df420', '2023-05-07 07:08:48.123456 +00:00', -1, '\xFFFFFC183FBF9A6B50B0F27C025F2D'), 
                 ('a3e8f20e-acf2-403d-a3cb-22210a2c68b7', '7811e22a-6c83-4b47-aeb5-758b01bc4701', '2023-05-07 07:08:48.123456 +00:00', -1, '\xFFFFFC183FBF9A6B50B0F27C025F2D') 
-            RETURNING "id"
+            RETURNING "id", xmax = 0
+        "#;
+        assert_eq!(qs.to_string(), clean_string(s));
+        let qs = buffer::insert_buffer_multiple(&device_ids, &model_ids, &[timestamp, timestamp], &[&data_value, &data_value], Some(&[tag, tag]), DataConflict::Overwrite);
+        let s = r#"
+            INSERT INTO "data_buffer" ("device_id", "model_id", "timestamp", "tag", "data") 
+            VALUES 
+                ('30bd8a90-1669-4d56-a67d-b709b2497156', 'e3933119-f5e9-47f5-892b-7dde010df420', '2023-05-07 07:08:48.123456 +00:00', -1, '\xFFFFFC183FBF9A6B50B0F27C025F2D'), 
+                ('a3e8f20e-acf2-403d-a3cb-22210a2c68b7', '7811e22a-6c83-4b47-aeb5-758b01bc4701', '2023-05-07 07:08:48.123456 +00:00', -1, '\xFFFFFC183FBF9A6B50B0F27C025F2D') 
+            ON CONFLICT ("timestamp", "model_id", "device_id", "tag") DO UPDATE SET "data" = "excluded"."data" 
+            RETURNING "id", xmax = 0
         "#;
         assert_eq!(qs.to_string(), clean_string(s));
         let qs = buffer::update_buffer(None, Some(device_id), Some(model_id), Some(timestamp), Some(&data_value), Some(tag));
@@ -1,6 +1,6 @@
 use bbthings_database::{DataValue, ArrayDataValue};
 use bbthings_database::DataType::{I8T, I16T, I32T, I64T, U8T, U16T, U32T, U64T, F32T, F64T, BoolT, CharT, StringT, BytesT};
-use bbthings_database::DataValue::{I8, I16, I32, I64, U8, U16, U32, U64, F32, F64, Bool, Char};
+use bbthings_database::DataValue::{I8, I16, I32, I64, U8, U16, U32, U64, I128, U128, F32, F64, Bool, Char};
 
 #[cfg(test)]
 mod tests {
@@ -161,4 +161,49 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn array_data_value_validate()
+    {
+        let types = [F32T, I32T, BoolT];
+        let data = ArrayDataValue::from_vec(&[F64(1.5), I64(-7), Bool(true)]);
+        assert_eq!(data.validate(&types).unwrap().to_vec(), [F32(1.5), I32(-7), Bool(true)]);
+
+        let data = ArrayDataValue::from_vec(&[F32(1.5), Bool(true), Bool(false)]);
+        assert_eq!(data.validate(&types), Err(1));
+        let data = ArrayDataValue::from_vec(&[F32(1.5), I32(-7)]);
+        assert_eq!(data.validate(&types), Err(2));
+        let data = ArrayDataValue::from_vec(&[F32(1.5), I32(-7), Bool(true), U8(1)]);
+        assert_eq!(data.validate(&types), Err(3));
+
+        // integer values outside of the field type range are rejected instead of wrapped
+        let types = [U8T, I8T, U64T, I64T];
+        let data = ArrayDataValue::from_vec(&[I32(200), I64(-128), U128(u64::MAX as u128), I128(-1)]);
+        assert_eq!(data.validate(&types).unwrap().to_vec(), [U8(200), I8(-128), U64(u64::MAX), I64(-1)]);
+        let data = ArrayDataValue::from_vec(&[I32(-7), I8(0), U64(0), I64(0)]);
+        assert_eq!(data.validate(&types), Err(0));
+        let data = ArrayDataValue::from_vec(&[U8(0), I64(300), U64(0), I64(0)]);
+        assert_eq!(data.validate(&types), Err(1));
+        let data = ArrayDataValue::from_vec(&[U8(0), I8(0), U128(u64::MAX as u128 + 1), I64(0)]);
+        assert_eq!(data.validate(&types), Err(2));
+        let data = ArrayDataValue::from_vec(&[U8(0), I8(0), U64(0), I128(i64::MAX as i128 + 1)]);
+        assert_eq!(data.validate(&types), Err(3));
+    }
+
+    #[test]
+    fn data_value_parse()
+    {
+        assert_eq!(DataValue::parse(" -12 ", I16T), Some(I16(-12)));
+        assert_eq!(DataValue::parse("300", U8T), None);
+        assert_eq!(DataValue::parse("2.5", F64T), Some(F64(2.5)));
+        assert_eq!(DataValue::parse("1.5e2", F32T), Some(F32(150.0)));
+        assert_eq!(DataValue::parse("TRUE", BoolT), Some(Bool(true)));
+        assert_eq!(DataValue::parse("0", BoolT), Some(Bool(false)));
+        assert_eq!(DataValue::parse("ab", CharT), None);
+        assert_eq!(DataValue::parse("a", CharT), Some(Char('a')));
+        assert_eq!(DataValue::parse(" text ", StringT), Some(DataValue::String(" text ".to_owned())));
+        assert_eq!(DataValue::parse("0a14ff", BytesT), Some(DataValue::Bytes(vec![10, 20, 255])));
+        assert_eq!(DataValue::parse("0a1", BytesT), None);
+        assert_eq!(DataValue::parse("", I32T), None);
+    }
+
 }
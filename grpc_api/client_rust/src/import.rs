@@ -0,0 +1,171 @@
+use std::io::Read;
+use std::collections::HashMap;
+use chrono::{DateTime, Utc, TimeZone};
+use uuid::Uuid;
+use tonic::Status;
+use bbthings_database::{DataSchema, DataValue};
+use bbthings_database::common::tag as Tag;
+use bbthings_database::resource::export::{ExportFormat, ExportColumn};
+
+const FORMAT_UNSUPPORTED: &str = "Import only supports csv and ndjson format";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportMapping {
+    pub device_id: Option<Uuid>,
+    pub model_id: Option<Uuid>,
+    pub device_column: String,
+    pub model_column: String,
+    pub timestamp_column: String,
+    pub tag_column: String,
+    pub field_columns: Vec<String>
+}
+
+impl Default for ImportMapping {
+    fn default() -> Self {
+        Self {
+            device_id: None,
+            model_id: None,
+            device_column: String::from("device_id"),
+            model_column: String::from("model_id"),
+            timestamp_column: String::from("timestamp"),
+            tag_column: String::from("tag"),
+            field_columns: Vec::new()
+        }
+    }
+}
+
+impl ImportMapping {
+    pub fn new(device_id: Uuid, model_id: Uuid) -> Self {
+        Self { device_id: Some(device_id), model_id: Some(model_id), ..Default::default() }
+    }
+}
+
+// line number and reason of a rejected line
+pub(crate) type ImportReject = (u64, String);
+
+// text value of every column of a line in the input file
+pub(crate) struct ImportRecord {
+    line: u64,
+    values: HashMap<String, String>
+}
+
+pub(crate) fn read_records<R: Read>(mut reader: R, format: ExportFormat)
+    -> Result<(Vec<ImportRecord>, Vec<ImportReject>), Status>
+{
+    let mut text = String::new();
+    reader.read_to_string(&mut text).map_err(|e| Status::invalid_argument(e.to_string()))?;
+    let mut records = Vec::new();
+    let mut rejects = Vec::new();
+    match format {
+        ExportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(text.as_bytes());
+            let headers = reader.headers().map_err(|e| Status::invalid_argument(e.to_string()))?.clone();
+            for result in reader.records() {
+                match result {
+                    Ok(record) => records.push(ImportRecord {
+                        line: record.position().map(|p| p.line()).unwrap_or_default(),
+                        values: headers.iter().zip(record.iter()).map(|(h, v)| (h.to_owned(), v.to_owned())).collect()
+                    }),
+                    Err(e) => rejects.push((e.position().map(|p| p.line()).unwrap_or_default(), e.to_string()))
+                }
+            }
+        },
+        ExportFormat::Ndjson => {
+            for (i, line) in text.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let object = match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(line) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        rejects.push((i as u64 + 1, e.to_string()));
+                        continue;
+                    }
+                };
+                // json string is taken without quotes and json null is an empty text
+                let values = object.into_iter().map(|(k, v)| {
+                    let text = match v {
+                        serde_json::Value::String(s) => s,
+                        serde_json::Value::Null => String::new(),
+                        v => v.to_string()
+                    };
+                    (k, text)
+                }).collect();
+                records.push(ImportRecord { line: i as u64 + 1, values });
+            }
+        },
+        ExportFormat::Parquet => return Err(Status::invalid_argument(FORMAT_UNSUPPORTED))
+    }
+    Ok((records, rejects))
+}
+
+fn record_id(record: &ImportRecord, id: Option<Uuid>, column: &str) -> Result<Uuid, String>
+{
+    match id {
+        Some(id) => Ok(id),
+        None => {
+            let text = record.values.get(column).ok_or(format!("Column '{}' is missing", column))?;
+            Uuid::parse_str(text.trim()).map_err(|_| format!("Invalid {} '{}'", column, text))
+        }
+    }
+}
+
+pub(crate) fn record_model_ids(records: &[ImportRecord], mapping: &ImportMapping) -> Vec<Uuid>
+{
+    let mut model_ids: Vec<Uuid> = records.iter()
+        .filter_map(|r| record_id(r, mapping.model_id, &mapping.model_column).ok())
+        .collect();
+    model_ids.sort();
+    model_ids.dedup();
+    model_ids
+}
+
+fn parse_timestamp(text: &str) -> Option<DateTime<Utc>>
+{
+    // timestamp is either a rfc 3339 text or a unix timestamp in microseconds
+    let text = text.trim();
+    match DateTime::parse_from_rfc3339(text) {
+        Ok(value) => Some(value.into()),
+        Err(_) => text.parse::<i64>().ok().map(|t| Utc.timestamp_nanos(t * 1000))
+    }
+}
+
+fn convert_record(record: &ImportRecord, mapping: &ImportMapping, models: &HashMap<Uuid, Option<Vec<ExportColumn>>>)
+    -> Result<DataSchema, String>
+{
+    let device_id = record_id(record, mapping.device_id, &mapping.device_column)?;
+    let model_id = record_id(record, mapping.model_id, &mapping.model_column)?;
+    let columns = models.get(&model_id).cloned().flatten()
+        .ok_or(format!("Model '{}' is not found", model_id))?;
+    let text = record.values.get(&mapping.timestamp_column)
+        .ok_or(format!("Column '{}' is missing", mapping.timestamp_column))?;
+    let timestamp = parse_timestamp(text).ok_or(format!("Invalid timestamp '{}'", text))?;
+    let tag = match record.values.get(&mapping.tag_column) {
+        Some(text) => text.trim().parse().map_err(|_| format!("Invalid tag '{}'", text))?,
+        None => Tag::DEFAULT
+    };
+    // field columns are taken from the mapping or from the model field names
+    let mut data = Vec::with_capacity(columns.len());
+    for (i, column) in columns.iter().enumerate() {
+        let name = mapping.field_columns.get(i).unwrap_or(&column.name);
+        let text = record.values.get(name).ok_or(format!("Column '{}' is missing", name))?;
+        let value = DataValue::parse(text, column.data_type.clone())
+            .ok_or(format!("Can not convert '{}' to {:?} at field index {}", text, column.data_type, i))?;
+        data.push(value);
+    }
+    Ok(DataSchema { device_id, model_id, timestamp, data, tag })
+}
+
+pub(crate) fn convert_records(records: &[ImportRecord], mapping: &ImportMapping, models: &HashMap<Uuid, Option<Vec<ExportColumn>>>)
+    -> (Vec<(u64, DataSchema)>, Vec<ImportReject>)
+{
+    let mut rows = Vec::new();
+    let mut rejects = Vec::new();
+    for record in records {
+        match convert_record(record, mapping, models) {
+            Ok(value) => rows.push((record.line, value)),
+            Err(reason) => rejects.push((record.line, reason))
+        }
+    }
+    (rows, rejects)
+}
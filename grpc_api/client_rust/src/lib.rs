@@ -1,8 +1,10 @@
 pub mod auth;
 pub mod resource;
+pub mod import;
 
 pub use auth::Auth;
 pub use resource::Resource;
+pub use import::ImportMapping;
 pub use bbthings_database::{
     ApiSchema, ProcedureSchema, RoleSchema, UserSchema, UserRoleSchema,
     RoleProfileSchema, UserProfileSchema, TokenSchema,
@@ -10,14 +12,27 @@ pub use bbthings_database::{
     DeviceSchema, GatewaySchema, TypeSchema, DeviceConfigSchema, GatewayConfigSchema,
     GroupModelSchema, GroupDeviceSchema, GroupGatewaySchema,
     SetSchema, SetMember, SetTemplateSchema, SetTemplateMember,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema,
-    SliceSchema, SliceSetSchema
+    DataSchema, DataSetSchema, DataSetAlignedSchema, DataAuditSchema, DataStatsSchema, BufferSchema, BufferSetSchema, BufferTransitionSchema, BufferHistorySchema,
+    SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, IntegrityViolationSchema
 };
 pub use bbthings_database::common::type_value::{DataType, DataValue, ArrayDataValue};
 pub use bbthings_database::common::tag;
+pub use bbthings_database::common::cursor::{Cursor, Page};
+pub use bbthings_database::resource::aggregate::{DataAggregate, DataAlign};
+pub use bbthings_database::resource::slice::{SliceCondition, SliceRuleCreate};
+pub use bbthings_database::resource::compression::CompressionTable;
+pub use bbthings_database::resource::integrity::{IntegrityMode, IntegrityTable};
+pub use bbthings_database::resource::export::{ExportFormat, ExportSource};
+pub use bbthings_database::resource::data::{DataConflict, DataConflictCount, DataRangeUpdate};
 pub use bbthings_grpc_server::proto::auth::auth::{
     UserLoginResponse, UserRefreshResponse, UserLogoutResponse, AccessTokenMap
 };
+pub use bbthings_grpc_server::proto::resource::data::{
+    DataIngestResponse, DataIngestBatch, DataIngestReject, DataImportResponse, DataImportReject
+};
+pub use bbthings_grpc_server::proto::resource::buffer::{
+    BufferIngestResponse, BufferIngestBatch, BufferIngestReject
+};
 
 pub mod utility {
     pub use bbthings_database::common::utility::{
@@ -6,20 +6,35 @@ pub mod set;
 pub mod data;
 pub mod buffer;
 pub mod slice;
+pub mod aggregate;
+pub mod retention;
+pub mod compression;
 
+use std::io::Read;
+use std::collections::HashMap;
 use tonic::{Status, transport::Channel};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc, Duration};
 use uuid::Uuid;
+use futures_util::stream::{Stream, StreamExt};
 use bbthings_database::{
     ModelSchema, TagSchema, ModelConfigSchema,
     DeviceSchema, GatewaySchema, TypeSchema, DeviceConfigSchema, GatewayConfigSchema, TypeConfigSchema,
     GroupModelSchema, GroupDeviceSchema, GroupGatewaySchema, SetSchema, SetTemplateSchema,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema, SliceSchema, SliceSetSchema,
-    DataValue, DataType
+    DataSchema, DataSetSchema, DataSetAlignedSchema, BufferSchema, BufferSetSchema, BufferTransitionSchema, BufferHistorySchema, SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, DataAuditSchema, DataStatsSchema, IntegrityViolationSchema,
+    DataValue, DataType, Cursor, Page
 };
+use bbthings_database::resource::aggregate::{DataAggregate, DataAlign};
+use bbthings_database::resource::slice::{SliceCondition, SliceRuleCreate};
+use bbthings_database::resource::compression::CompressionTable;
+use bbthings_database::resource::integrity::IntegrityTable;
+use bbthings_database::resource::export::{self, ExportFormat, ExportSource};
+use bbthings_database::resource::data::{DataConflict, DataConflictCount, DataRangeUpdate};
 use bbthings_grpc_server::proto::resource::config::{ProcedureAcces, RoleAcces};
+use bbthings_grpc_server::proto::resource::data::{DataIngestResponse, DataImportResponse, DataImportReject};
+use bbthings_grpc_server::proto::resource::buffer::BufferIngestResponse;
 use bbthings_grpc_server::common::config::ROOT_ID;
 use crate::auth::auth;
+use crate::import::{self, ImportMapping};
 
 #[derive(Debug, Clone)]
 pub struct Resource {
@@ -292,6 +307,13 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_device_option_page(&self, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>, page: &Page)
+        -> Result<(Vec<DeviceSchema>, Option<Cursor>), Status>
+    {
+        device::list_device_option_page(self, gateway_id, type_id, name, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
     pub async fn create_device(&self, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: &str)
         -> Result<Uuid, Status>
     {
@@ -544,6 +566,13 @@ impl Resource {
             .await
     }
 
+    pub async fn list_integrity_violation(&self, table: Option<IntegrityTable>)
+        -> Result<Vec<IntegrityViolationSchema>, Status>
+    {
+        device::list_integrity_violation(self, table).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
     pub async fn read_group_model(&self, id: Uuid)
         -> Result<GroupModelSchema, Status>
     {
@@ -811,7 +840,7 @@ impl Resource {
     }
 
     pub async fn add_set_member(&self, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
-        -> Result<(), Status>
+        -> Result<usize, Status>
     {
         set::add_set_member(&self, id, device_id, model_id, data_index)
             .await
@@ -978,6 +1007,20 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_data_by_range_page(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<DataSchema>, Option<Cursor>), Status>
+    {
+        data::list_data_by_range_page(self, device_id, model_id, begin, end, tag, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
+    pub async fn list_data_group_by_range_page(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<DataSchema>, Option<Cursor>), Status>
+    {
+        data::list_data_group_by_range_page(self, device_ids, model_ids, begin, end, tag, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
     pub async fn list_data_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<DataSchema>, Status>
     {
@@ -992,6 +1035,94 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_data_latest(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Status>
+    {
+        data::list_data_latest(self, device_ids, model_ids, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn stats_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Status>
+    {
+        data::stats_data_by_range(self, device_id, model_id, begin, end, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn stats_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Status>
+    {
+        data::stats_data_group_by_range(self, device_ids, model_ids, begin, end, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn list_data_bucketed(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, aggregate: DataAggregate, tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Status>
+    {
+        data::list_data_bucketed(self, device_id, model_id, begin, end, interval, aggregate, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn stream_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+    {
+        data::stream_data_by_range(self, device_id, model_id, begin, end, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
+    pub async fn stream_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+    {
+        data::stream_data_group_by_range(self, device_ids, model_ids, begin, end, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
+    pub async fn list_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataSchema>, Status>
+    {
+        data::list_data_by_slice(self, slice_id, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn stream_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+    {
+        data::stream_data_by_slice(self, slice_id, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
+    pub async fn stats_data_by_slice(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Status>
+    {
+        data::stats_data_by_slice(self, slice_id, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn subscribe_data(&self, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+    {
+        data::subscribe_data(self, device_ids, model_ids, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
+    pub async fn export_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Status>> + use<>, Status>
+    {
+        data::export_data(self, ExportSource::Data, device_id, model_id, begin, end, format, tag).await
+    }
+
+    pub async fn export_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Status>> + use<>, Status>
+    {
+        data::export_data(self, ExportSource::Buffer, device_id, model_id, begin, end, format, tag).await
+    }
+
+    pub async fn export_data_set_by_range(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<Vec<u8>, Status>> + use<>, Status>
+    {
+        data::export_data(self, ExportSource::Set, set_id, Uuid::nil(), begin, end, format, tag).await
+    }
+
     pub async fn read_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DataSetSchema, Status>
     {
@@ -1027,20 +1158,123 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_data_by_slice_set(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Status>
+    {
+        data::list_data_by_slice_set(self, slice_id, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn stats_data_by_slice_set(&self, slice_id: i32, tag: Option<i16>)
+        -> Result<Vec<DataStatsSchema>, Status>
+    {
+        data::stats_data_by_slice_set(self, slice_id, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn list_data_set_aligned(&self, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, align: DataAlign, tag: Option<i16>)
+        -> Result<Vec<DataSetAlignedSchema>, Status>
+    {
+        data::list_data_set_aligned(self, set_id, begin, end, interval, align, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn list_data_set_by_number_before(&self, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Status>
+    {
+        data::list_data_set_by_number_before(&self, set_id, before, number, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn list_data_set_by_number_after(&self, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<DataSetSchema>, Status>
+    {
+        data::list_data_set_by_number_after(&self, set_id, after, number, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
     pub async fn create_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-        -> Result<(), Status>
+        -> Result<usize, Status>
     {
         data::create_data(&self, device_id, model_id, timestamp, data, tag)
             .await
     }
 
     pub async fn create_data_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
+        -> Result<usize, Status>
+    {
+        data::create_data_multiple(&self, device_ids, model_ids, timestamps, data, tags, None)
+            .await
+            .map(|count| count.violated)
+    }
+
+    pub async fn create_data_multiple_conflict(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: DataConflict)
+        -> Result<DataConflictCount, Status>
+    {
+        data::create_data_multiple(self, device_ids, model_ids, timestamps, data, tags, Some(conflict))
+            .await
+    }
+
+    pub async fn create_data_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> Result<usize, Status>
+    {
+        data::create_data_set(&self, set_id, timestamp, data, tag)
+            .await
+    }
+
+    pub async fn ingest_data<S>(&self, data: S)
+        -> Result<DataIngestResponse, Status>
+    where
+        S: Stream<Item = DataSchema> + Send + 'static
+    {
+        data::ingest_data(self, data.map(|s| s.into()))
+            .await
+    }
+
+    pub async fn import_data<R: Read>(&self, reader: R, format: ExportFormat, mapping: &ImportMapping, conflict: DataConflict)
+        -> Result<DataImportResponse, Status>
+    {
+        let (records, mut rejects) = import::read_records(reader, format)?;
+        // get field columns of every model in the records, unknown models are rejected on conversion
+        let mut models = HashMap::new();
+        for model_id in import::record_model_ids(&records, mapping) {
+            let columns = match self.read_model(model_id).await {
+                Ok(model) => Some(export::model_columns(&model)),
+                Err(e) if e.code() == tonic::Code::NotFound => None,
+                Err(e) => return Err(e)
+            };
+            models.insert(model_id, columns);
+        }
+        let (rows, convert_rejects) = import::convert_records(&records, mapping, &models);
+        rejects.extend(convert_rejects);
+        let rows = rows.into_iter().map(|(line, d)| (line, d.into())).collect();
+        let mut response = data::import_data(self, rows, conflict).await?;
+        response.rejects.extend(rejects.into_iter().map(|(line, reason)| DataImportReject { line, reason }));
+        response.rejects.sort_by_key(|r| r.line);
+        Ok(response)
+    }
+
+    pub async fn update_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>, data: Option<&[DataValue]>, new_tag: Option<i16>)
         -> Result<(), Status>
     {
-        data::create_data_multiple(&self, device_ids, model_ids, timestamps, data, tags)
+        data::update_data(self, device_id, model_id, timestamp, tag, data, new_tag)
             .await
     }
 
+    pub async fn update_data_by_range(&self, update: DataRangeUpdate)
+        -> Result<usize, Status>
+    {
+        data::update_data_by_range(self, update)
+            .await
+    }
+
+    pub async fn list_data_audit_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+        -> Result<Vec<DataAuditSchema>, Status>
+    {
+        data::list_data_audit_by_range(self, device_id, model_id, begin, end).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
     pub async fn delete_data(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<(), Status>
     {
@@ -1048,6 +1282,20 @@ impl Resource {
             .await
     }
 
+    pub async fn delete_data_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Status>
+    {
+        data::delete_data_by_range(self, device_id, model_id, begin, end, tag, dry_run)
+            .await
+    }
+
+    pub async fn delete_data_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Status>
+    {
+        data::delete_data_group_by_range(self, device_ids, model_ids, begin, end, tag, dry_run)
+            .await
+    }
+
     pub async fn read_data_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
@@ -1160,6 +1408,20 @@ impl Resource {
             .await
     }
 
+    pub async fn aggregate_data(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+        -> Result<usize, Status>
+    {
+        aggregate::aggregate_data(self, device_id, model_id, begin, end, tag)
+            .await
+    }
+
+    pub async fn aggregate_data_group(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+        -> Result<usize, Status>
+    {
+        aggregate::aggregate_data_group(self, device_ids, model_ids, begin, end, tag)
+            .await
+    }
+
     pub async fn read_buffer(&self, id: i32)
         -> Result<BufferSchema, Status>
     {
@@ -1293,6 +1555,20 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_buffer_by_range_page(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<BufferSchema>, Option<Cursor>), Status>
+    {
+        buffer::list_buffer_by_range_page(self, device_id, model_id, begin, end, tag, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
+    pub async fn list_buffer_group_by_range_page(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+        -> Result<(Vec<BufferSchema>, Option<Cursor>), Status>
+    {
+        buffer::list_buffer_group_by_range_page(self, device_ids, model_ids, begin, end, tag, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
     pub async fn list_buffer_group_by_number_before(&self, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
         -> Result<Vec<BufferSchema>, Status>
     {
@@ -1307,6 +1583,20 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn stream_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<BufferSchema, Status>> + use<>, Status>
+    {
+        buffer::stream_buffer_by_range(self, device_id, model_id, begin, end, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
+    pub async fn stream_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+        -> Result<impl Stream<Item = Result<BufferSchema, Status>> + use<>, Status>
+    {
+        buffer::stream_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag).await
+            .map(|s| s.map(|r| r.map(|v| v.into())))
+    }
+
     pub async fn read_buffer_group_first(&self, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
         -> Result<BufferSchema, Status>
     {
@@ -1384,17 +1674,55 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_buffer_set_by_number_before(&self, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<BufferSetSchema>, Status>
+    {
+        buffer::list_buffer_set_by_number_before(&self, set_id, before, number, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn list_buffer_set_by_number_after(&self, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+        -> Result<Vec<BufferSetSchema>, Status>
+    {
+        buffer::list_buffer_set_by_number_after(&self, set_id, after, number, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
     pub async fn create_buffer(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-        -> Result<i32, Status>
+        -> Result<(i32, usize), Status>
     {
         buffer::create_buffer(&self, device_id, model_id, timestamp, data, tag)
             .await
     }
 
     pub async fn create_buffer_multiple(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
-        -> Result<Vec<i32>, Status>
+        -> Result<(Vec<i32>, usize), Status>
+    {
+        buffer::create_buffer_multiple(&self, device_ids, model_ids, timestamps, data, tags, None)
+            .await
+            .map(|(ids, count)| (ids, count.violated))
+    }
+
+    pub async fn create_buffer_multiple_conflict(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: DataConflict)
+        -> Result<(Vec<i32>, DataConflictCount), Status>
+    {
+        buffer::create_buffer_multiple(self, device_ids, model_ids, timestamps, data, tags, Some(conflict))
+            .await
+    }
+
+    pub async fn create_buffer_set(&self, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+        -> Result<(Vec<i32>, usize), Status>
+    {
+        buffer::create_buffer_set(&self, set_id, timestamp, data, tag)
+            .await
+    }
+
+    pub async fn ingest_buffer<S>(&self, buffers: S)
+        -> Result<BufferIngestResponse, Status>
+    where
+        S: Stream<Item = BufferSchema> + Send + 'static
     {
-        buffer::create_buffer_multiple(&self, device_ids, model_ids, timestamps, data, tags)
+        buffer::ingest_buffer(self, buffers.map(|s| s.into()))
             .await
     }
 
@@ -1426,6 +1754,90 @@ impl Resource {
             .await
     }
 
+    pub async fn delete_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Status>
+    {
+        buffer::delete_buffer_by_range(self, device_id, model_id, begin, end, tag, dry_run)
+            .await
+    }
+
+    pub async fn delete_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+        -> Result<usize, Status>
+    {
+        buffer::delete_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag, dry_run)
+            .await
+    }
+
+    pub async fn transfer_buffer_by_ids(&self, ids: &[i32], target_tag: Option<i16>)
+        -> Result<usize, Status>
+    {
+        buffer::transfer_buffer_by_ids(self, ids, target_tag)
+            .await
+    }
+
+    pub async fn transfer_buffer_by_range(&self, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, target_tag: Option<i16>)
+        -> Result<usize, Status>
+    {
+        buffer::transfer_buffer_group_by_range(self, &[device_id], &[model_id], begin, end, tag, target_tag)
+            .await
+    }
+
+    pub async fn transfer_buffer_group_by_range(&self, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, target_tag: Option<i16>)
+        -> Result<usize, Status>
+    {
+        buffer::transfer_buffer_group_by_range(self, device_ids, model_ids, begin, end, tag, target_tag)
+            .await
+    }
+
+    pub async fn claim_buffer(&self, number: usize, tag: i16, lease_duration: Duration, worker_id: &str)
+        -> Result<Vec<BufferSchema>, Status>
+    {
+        buffer::claim_buffer(self, number, tag, lease_duration, worker_id).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn ack_buffer(&self, ids: &[i32], worker_id: &str, tag: i16)
+        -> Result<(usize, Vec<i32>), Status>
+    {
+        buffer::ack_buffer(self, ids, worker_id, tag)
+            .await
+    }
+
+    pub async fn nack_buffer(&self, ids: &[i32], worker_id: &str)
+        -> Result<(usize, Vec<i32>), Status>
+    {
+        buffer::nack_buffer(self, ids, worker_id)
+            .await
+    }
+
+    pub async fn list_buffer_transition(&self, tag: Option<i16>)
+        -> Result<Vec<BufferTransitionSchema>, Status>
+    {
+        buffer::list_buffer_transition(self, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn create_buffer_transition(&self, tag: i16, new_tag: i16)
+        -> Result<(), Status>
+    {
+        buffer::create_buffer_transition(self, tag, new_tag)
+            .await
+    }
+
+    pub async fn delete_buffer_transition(&self, tag: i16, new_tag: i16)
+        -> Result<(), Status>
+    {
+        buffer::delete_buffer_transition(self, tag, new_tag)
+            .await
+    }
+
+    pub async fn list_buffer_history(&self, buffer_id: i32)
+        -> Result<Vec<BufferHistorySchema>, Status>
+    {
+        buffer::list_buffer_history(self, buffer_id).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
     pub async fn read_buffer_timestamp(&self, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
         -> Result<DateTime<Utc>, Status>
     {
@@ -1616,6 +2028,13 @@ impl Resource {
             .map(|v| v.into_iter().map(|s| s.into()).collect())
     }
 
+    pub async fn list_slice_option_page(&self, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>, page: &Page)
+        -> Result<(Vec<SliceSchema>, Option<Cursor>), Status>
+    {
+        slice::list_slice_option_page(self, device_id, model_id, name, begin_or_timestamp, end, page).await
+            .map(|(v, c)| (v.into_iter().map(|s| s.into()).collect(), c))
+    }
+
     pub async fn list_slice_group_by_time(&self, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
         -> Result<Vec<SliceSchema>, Status>
     {
@@ -1729,4 +2148,126 @@ impl Resource {
             .await
     }
 
+    pub async fn read_slice_rule(&self, id: i32)
+        -> Result<SliceRuleSchema, Status>
+    {
+        slice::read_slice_rule(self, id)
+            .await
+            .map(|s| s.into())
+    }
+
+    pub async fn list_slice_rule_option(&self, device_id: Option<Uuid>, model_id: Option<Uuid>)
+        -> Result<Vec<SliceRuleSchema>, Status>
+    {
+        slice::list_slice_rule_option(self, device_id, model_id)
+            .await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn create_slice_rule(&self, rule: &SliceRuleCreate)
+        -> Result<i32, Status>
+    {
+        slice::create_slice_rule(self, rule)
+            .await
+    }
+
+    pub async fn update_slice_rule(&self, id: i32, condition: Option<SliceCondition>, threshold: Option<f64>, min_duration: Option<Duration>, max_gap: Option<Duration>, name: Option<&str>)
+        -> Result<(), Status>
+    {
+        slice::update_slice_rule(self, id, condition, threshold, min_duration, max_gap, name)
+            .await
+    }
+
+    pub async fn delete_slice_rule(&self, id: i32)
+        -> Result<(), Status>
+    {
+        slice::delete_slice_rule(self, id)
+            .await
+    }
+
+    pub async fn generate_slice_by_range(&self, rule_id: i32, begin: DateTime<Utc>, end: DateTime<Utc>, dry_run: bool)
+        -> Result<Vec<SliceSchema>, Status>
+    {
+        slice::generate_slice_by_range(self, rule_id, begin, end, dry_run)
+            .await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn apply_slice_rule(&self, dry_run: bool)
+        -> Result<Vec<(i32, usize)>, Status>
+    {
+        slice::apply_slice_rule(self, dry_run)
+            .await
+    }
+
+    pub async fn read_retention(&self, id: i32)
+        -> Result<RetentionSchema, Status>
+    {
+        retention::read_retention(self, id).await
+            .map(|s| s.into())
+    }
+
+    pub async fn list_retention_option(&self, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>)
+        -> Result<Vec<RetentionSchema>, Status>
+    {
+        retention::list_retention_option(self, model_id, type_id, tag).await
+            .map(|v| v.into_iter().map(|s| s.into()).collect())
+    }
+
+    pub async fn create_retention(&self, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>, retention: Duration)
+        -> Result<i32, Status>
+    {
+        retention::create_retention(self, model_id, type_id, tag, retention)
+            .await
+    }
+
+    pub async fn update_retention(&self, id: i32, retention: Duration)
+        -> Result<(), Status>
+    {
+        retention::update_retention(self, id, retention)
+            .await
+    }
+
+    pub async fn delete_retention(&self, id: i32)
+        -> Result<(), Status>
+    {
+        retention::delete_retention(self, id)
+            .await
+    }
+
+    pub async fn apply_retention(&self, dry_run: bool)
+        -> Result<Vec<(i32, usize)>, Status>
+    {
+        retention::apply_retention(self, dry_run)
+            .await
+    }
+
+    pub async fn enable_compression(&self, table: CompressionTable)
+        -> Result<(), Status>
+    {
+        compression::enable_compression(self, table)
+            .await
+    }
+
+    pub async fn set_compression_policy(&self, table: CompressionTable, compress_after: Duration)
+        -> Result<(), Status>
+    {
+        compression::set_compression_policy(self, table, compress_after)
+            .await
+    }
+
+    pub async fn remove_compression_policy(&self, table: CompressionTable)
+        -> Result<(), Status>
+    {
+        compression::remove_compression_policy(self, table)
+            .await
+    }
+
+    pub async fn read_compression_stats(&self, table: CompressionTable)
+        -> Result<CompressionSchema, Status>
+    {
+        compression::read_compression_stats(self, table).await
+            .map(|s| s.into())
+    }
+
 }
@@ -0,0 +1,47 @@
+use tonic::{Request, Status};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use bbthings_grpc_server::proto::resource::aggregate::aggregate_service_client::AggregateServiceClient;
+use bbthings_grpc_server::proto::resource::aggregate::{
+    AggregateRange, AggregateGroupRange
+};
+use crate::resource::Resource;
+use bbthings_grpc_server::common::interceptor::TokenInterceptor;
+
+pub(crate) async fn aggregate_data(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        AggregateServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(AggregateRange {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag as i32
+    });
+    let response = client.aggregate_data(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+pub(crate) async fn aggregate_data_group(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: i16)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        AggregateServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(AggregateGroupRange {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag as i32
+    });
+    let response = client.aggregate_data_group(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
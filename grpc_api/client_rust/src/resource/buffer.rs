@@ -1,15 +1,19 @@
 use tonic::{Request, Status};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc, Duration};
 use uuid::Uuid;
+use futures_util::stream::{self, Stream, StreamExt};
 use bbthings_database::common::type_value::{DataValue, ArrayDataValue};
 use bbthings_database::common::tag as Tag;
+use bbthings_database::resource::data::{DataConflict, DataConflictCount};
 use bbthings_grpc_server::proto::resource::buffer::buffer_service_client::BufferServiceClient;
 use bbthings_grpc_server::proto::resource::buffer::{
-    BufferSchema, BufferMultipleSchema, BufferId, BufferIds, BufferTime, BufferEarlier, BufferLater, BufferRange, BufferNumber, 
+    BufferSchema, BufferMultipleSchema, BufferId, BufferIds, BufferTime, BufferEarlier, BufferLater, BufferRange, BufferRangeDelete, BufferNumber, 
     BufferSelector, BuffersSelector, BufferUpdate, BufferUpdateTime, 
-    BufferGroupTime, BufferGroupEarlier, BufferGroupLater, BufferGroupRange, BufferGroupNumber, BufferGroupSelector, BuffersGroupSelector,
-    BufferSetSchema, BufferSetTime, BufferSetEarlier, BufferSetLater, BufferSetRange
+    BufferGroupTime, BufferGroupEarlier, BufferGroupLater, BufferGroupRange, BufferGroupRangeDelete, BufferTransfer, BufferClaim, BufferAck, BufferNack, BufferTransitionSchema, BufferTransitionTag, BufferHistorySchema, BufferGroupNumber, BufferRangePage, BufferGroupRangePage, BufferGroupSelector, BuffersGroupSelector,
+    BufferSetSchema, BufferSetTime, BufferSetEarlier, BufferSetLater, BufferSetRange, BufferSetNumber,
+    BufferIngestResponse
 };
+use bbthings_database::{Cursor, Page};
 use crate::resource::Resource;
 use bbthings_grpc_server::common::interceptor::TokenInterceptor;
 
@@ -358,6 +362,50 @@ pub(crate) async fn list_buffer_group_by_range(resource: &Resource, device_ids:
     Ok(response.results)
 }
 
+pub(crate) async fn list_buffer_by_range_page(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+    -> Result<(Vec<BufferSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferRangePage {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_buffer_by_range_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
+pub(crate) async fn list_buffer_group_by_range_page(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+    -> Result<(Vec<BufferSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferGroupRangePage {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_buffer_group_by_range_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
 pub(crate) async fn list_buffer_group_by_number_before(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<BufferSchema>, Status>
 {
@@ -396,6 +444,58 @@ pub(crate) async fn list_buffer_group_by_number_after(resource: &Resource, devic
     Ok(response.results)
 }
 
+pub(crate) async fn stream_buffer_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<BufferSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferRange {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|i| i as i32)
+    });
+    let response = client.stream_buffer_by_range(request)
+        .await?
+        .into_inner();
+    // flatten each batch message into a stream of single buffer
+    Ok(response.flat_map(|result| {
+        let results: Vec<Result<BufferSchema, Status>> = match result {
+            Ok(value) => value.results.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)]
+        };
+        stream::iter(results)
+    }))
+}
+
+pub(crate) async fn stream_buffer_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<BufferSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferGroupRange {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|i| i as i32)
+    });
+    let response = client.stream_buffer_group_by_range(request)
+        .await?
+        .into_inner();
+    // flatten each batch message into a stream of single buffer
+    Ok(response.flat_map(|result| {
+        let results: Vec<Result<BufferSchema, Status>> = match result {
+            Ok(value) => value.results.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)]
+        };
+        stream::iter(results)
+    }))
+}
+
 pub(crate) async fn read_buffer_group_first(resource: &Resource, device_ids: Option<&[Uuid]>, model_ids: Option<&[Uuid]>, tag: Option<i16>)
     -> Result<BufferSchema, Status>
 {
@@ -616,8 +716,44 @@ pub(crate) async fn list_buffer_set_by_range(resource: &Resource, set_id: Uuid,
     Ok(response.results)
 }
 
+pub(crate) async fn list_buffer_set_by_number_before(resource: &Resource, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+    -> Result<Vec<BufferSetSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferSetNumber {
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: before.timestamp_micros(),
+        number: number as u32,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_buffer_set_by_number_before(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn list_buffer_set_by_number_after(resource: &Resource, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+    -> Result<Vec<BufferSetSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferSetNumber {
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: after.timestamp_micros(),
+        number: number as u32,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_buffer_set_by_number_after(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
 pub(crate) async fn create_buffer(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-    -> Result<i32, Status>
+    -> Result<(i32, usize), Status>
 {
     let interceptor = TokenInterceptor(resource.access_token.clone());
     let mut client = 
@@ -634,11 +770,11 @@ pub(crate) async fn create_buffer(resource: &Resource, device_id: Uuid, model_id
     let response = client.create_buffer(request)
         .await?
         .into_inner();
-    Ok(response.id)
+    Ok((response.id, response.violated as usize))
 }
 
-pub(crate) async fn create_buffer_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
-    -> Result<Vec<i32>, Status>
+pub(crate) async fn create_buffer_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: Option<DataConflict>)
+    -> Result<(Vec<i32>, DataConflictCount), Status>
 {
     let interceptor = TokenInterceptor(resource.access_token.clone());
     let mut client = 
@@ -661,11 +797,54 @@ pub(crate) async fn create_buffer_multiple(resource: &Resource, device_ids: &[Uu
         data_type: ArrayDataValue::from_vec(data[i]).get_types().into_iter().map(|el| el.into()).collect(),
         tag: tags[i] as i32
     }).collect();
-    let request = Request::new(BufferMultipleSchema { schemas });
+    let request = Request::new(BufferMultipleSchema {
+        schemas,
+        conflict: conflict.map(|c| c.into())
+    });
     let response = client.create_buffer_multiple(request)
         .await?
         .into_inner();
-    Ok(response.ids)
+    let count = DataConflictCount {
+        inserted: response.inserted as usize,
+        skipped: response.skipped as usize,
+        overwritten: response.overwritten as usize,
+        violated: response.violated as usize
+    };
+    Ok((response.ids, count))
+}
+
+pub(crate) async fn create_buffer_set(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+    -> Result<(Vec<i32>, usize), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferSetSchema {
+        ids: Vec::new(),
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: timestamp.timestamp_micros(),
+        data_bytes: ArrayDataValue::from_vec(data).to_bytes(),
+        data_type: ArrayDataValue::from_vec(data).get_types().into_iter().map(|el| el.into()).collect(),
+        tag: tag.unwrap_or(Tag::DEFAULT) as i32
+    });
+    let response = client.create_buffer_set(request)
+        .await?
+        .into_inner();
+    Ok((response.ids, response.violated as usize))
+}
+
+pub(crate) async fn ingest_buffer<S>(resource: &Resource, buffers: S)
+    -> Result<BufferIngestResponse, Status>
+where
+    S: Stream<Item = BufferSchema> + Send + 'static
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let response = client.ingest_buffer(buffers)
+        .await?
+        .into_inner();
+    Ok(response)
 }
 
 pub(crate) async fn update_buffer(resource: &Resource, id: i32, data: Option<&[DataValue]>, tag: Option<i16>)
@@ -735,6 +914,199 @@ pub(crate) async fn delete_buffer_by_time(resource: &Resource, device_id: Uuid,
     Ok(())
 }
 
+pub(crate) async fn delete_buffer_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferRangeDelete {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        dry_run
+    });
+    let response = client.delete_buffer_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+pub(crate) async fn delete_buffer_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferGroupRangeDelete {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        dry_run
+    });
+    let response = client.delete_buffer_group_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+async fn transfer_buffer(resource: &Resource, request: BufferTransfer)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let response = client.transfer_buffer(Request::new(request))
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+pub(crate) async fn transfer_buffer_by_ids(resource: &Resource, ids: &[i32], target_tag: Option<i16>)
+    -> Result<usize, Status>
+{
+    let request = BufferTransfer {
+        ids: ids.to_vec(),
+        device_ids: Vec::new(),
+        model_ids: Vec::new(),
+        begin: None,
+        end: None,
+        tag: None,
+        target_tag: target_tag.map(|t| t as i32)
+    };
+    transfer_buffer(resource, request).await
+}
+
+pub(crate) async fn transfer_buffer_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, target_tag: Option<i16>)
+    -> Result<usize, Status>
+{
+    let request = BufferTransfer {
+        ids: Vec::new(),
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: Some(begin.timestamp_micros()),
+        end: Some(end.timestamp_micros()),
+        tag: tag.map(|t| t as i32),
+        target_tag: target_tag.map(|t| t as i32)
+    };
+    transfer_buffer(resource, request).await
+}
+
+pub(crate) async fn claim_buffer(resource: &Resource, number: usize, tag: i16, lease_duration: Duration, worker_id: &str)
+    -> Result<Vec<BufferSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferClaim {
+        number: number as u32,
+        tag: tag as i32,
+        lease: lease_duration.num_microseconds().unwrap_or(i64::MAX),
+        worker_id: worker_id.to_owned()
+    });
+    let response = client.claim_buffer(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn ack_buffer(resource: &Resource, ids: &[i32], worker_id: &str, tag: i16)
+    -> Result<(usize, Vec<i32>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferAck {
+        ids: ids.to_vec(),
+        worker_id: worker_id.to_owned(),
+        tag: tag as i32
+    });
+    let response = client.ack_buffer(request)
+        .await?
+        .into_inner();
+    Ok((response.count as usize, response.unreleased_ids))
+}
+
+pub(crate) async fn nack_buffer(resource: &Resource, ids: &[i32], worker_id: &str)
+    -> Result<(usize, Vec<i32>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferNack {
+        ids: ids.to_vec(),
+        worker_id: worker_id.to_owned()
+    });
+    let response = client.nack_buffer(request)
+        .await?
+        .into_inner();
+    Ok((response.count as usize, response.unreleased_ids))
+}
+
+pub(crate) async fn list_buffer_transition(resource: &Resource, tag: Option<i16>)
+    -> Result<Vec<BufferTransitionSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferTransitionTag {
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_buffer_transition(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn create_buffer_transition(resource: &Resource, tag: i16, new_tag: i16)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferTransitionSchema {
+        tag: tag as i32,
+        new_tag: new_tag as i32
+    });
+    client.create_buffer_transition(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn delete_buffer_transition(resource: &Resource, tag: i16, new_tag: i16)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferTransitionSchema {
+        tag: tag as i32,
+        new_tag: new_tag as i32
+    });
+    client.delete_buffer_transition(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn list_buffer_history(resource: &Resource, buffer_id: i32)
+    -> Result<Vec<BufferHistorySchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        BufferServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(BufferId {
+        id: buffer_id
+    });
+    let response = client.list_buffer_history(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
 pub(crate) async fn read_buffer_timestamp(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
@@ -0,0 +1,69 @@
+use tonic::{Request, Status};
+use chrono::Duration;
+use bbthings_database::resource::compression::CompressionTable;
+use bbthings_grpc_server::proto::resource::compression::compression_service_client::CompressionServiceClient;
+use bbthings_grpc_server::proto::resource::compression::{
+    CompressionTarget, CompressionPolicy, CompressionStats
+};
+use crate::resource::Resource;
+use bbthings_grpc_server::common::interceptor::TokenInterceptor;
+
+const COMPRESSION_NOT_FOUND: &str = "requested compression stats not found";
+
+pub(crate) async fn enable_compression(resource: &Resource, table: CompressionTable)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        CompressionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(CompressionTarget {
+        table: table.into()
+    });
+    client.enable_compression(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn set_compression_policy(resource: &Resource, table: CompressionTable, compress_after: Duration)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        CompressionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(CompressionPolicy {
+        table: table.into(),
+        compress_after: compress_after.num_microseconds().unwrap_or(i64::MAX)
+    });
+    client.set_compression_policy(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn remove_compression_policy(resource: &Resource, table: CompressionTable)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        CompressionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(CompressionTarget {
+        table: table.into()
+    });
+    client.remove_compression_policy(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn read_compression_stats(resource: &Resource, table: CompressionTable)
+    -> Result<CompressionStats, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        CompressionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(CompressionTarget {
+        table: table.into()
+    });
+    let response = client.read_compression_stats(request)
+        .await?
+        .into_inner();
+    response.result.ok_or(Status::not_found(COMPRESSION_NOT_FOUND))
+}
@@ -1,18 +1,25 @@
 use tonic::{Request, Status};
-use chrono::{DateTime, Utc, TimeZone};
+use chrono::{DateTime, Utc, TimeZone, Duration};
 use uuid::Uuid;
+use futures_util::stream::{self, Stream, StreamExt};
 use bbthings_database::common::type_value::{DataValue, ArrayDataValue};
 use bbthings_database::common::tag as Tag;
+use bbthings_database::resource::data::{DataConflict, DataConflictCount, DataRangeUpdate};
+use bbthings_database::resource::export::{ExportFormat, ExportSource};
+use bbthings_database::resource::aggregate::{DataAggregate, DataAlign};
 use bbthings_grpc_server::proto::resource::data::data_service_client::DataServiceClient;
 use bbthings_grpc_server::proto::resource::data::{
-    DataSchema, DataMultipleSchema, DataTime, DataEarlier, DataLater, DataRange, DataNumber, 
-    DataGroupTime, DataGroupEarlier, DataGroupLater, DataGroupRange, DataGroupNumber,
-    DataSetSchema, DataSetTime, DataSetEarlier, DataSetLater, DataSetRange
+    DataSchema, DataAuditSchema, DataStatsSchema, DataMultipleSchema, DataTime, DataEarlier, DataLater, DataRange, DataRangeDelete, DataUpdate, DataUpdateRange, DataNumber, DataLatest, DataBucketed, DataSubscribe, DataSlice, DataExport,
+    DataGroupTime, DataGroupEarlier, DataGroupLater, DataGroupRange, DataGroupRangeDelete, DataGroupNumber, DataRangePage, DataGroupRangePage,
+    DataSetSchema, DataSetTime, DataSetEarlier, DataSetLater, DataSetRange, DataSetAligned, DataSetAlignedSchema, DataSetNumber,
+    DataIngestResponse, DataImport, DataImportResponse
 };
+use bbthings_database::{Cursor, Page};
 use crate::resource::Resource;
 use bbthings_grpc_server::common::interceptor::TokenInterceptor;
 
 const DATA_NOT_FOUND: &str = "requested data not found";
+const IMPORT_BATCH_SIZE: usize = 1000;
 pub(crate) const EMPTY_LENGTH_UNMATCH: &str = "One or more input array arguments are empty or doesn't have the same length";
 
 pub(crate) async fn read_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
@@ -217,6 +224,50 @@ pub(crate) async fn list_data_group_by_range(resource: &Resource, device_ids: &[
     Ok(response.results)
 }
 
+pub(crate) async fn list_data_by_range_page(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+    -> Result<(Vec<DataSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataRangePage {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_data_by_range_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
+pub(crate) async fn list_data_group_by_range_page(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, page: &Page)
+    -> Result<(Vec<DataSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataGroupRangePage {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_data_group_by_range_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
 pub(crate) async fn list_data_group_by_number_before(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], before: DateTime<Utc>, number: usize, tag: Option<i16>)
     -> Result<Vec<DataSchema>, Status>
 {
@@ -255,6 +306,233 @@ pub(crate) async fn list_data_group_by_number_after(resource: &Resource, device_
     Ok(response.results)
 }
 
+pub(crate) async fn list_data_latest(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+    -> Result<Vec<DataSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataLatest {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_latest(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn stats_data_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<Vec<DataStatsSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataRange {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stats_data_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn stats_data_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<Vec<DataStatsSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataGroupRange {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stats_data_group_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn list_data_bucketed(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, aggregate: DataAggregate, tag: Option<i16>)
+    -> Result<Vec<DataSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataBucketed {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        interval: interval.num_microseconds().unwrap_or_default(),
+        aggregate: aggregate.into(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_bucketed(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn stream_data_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataRange {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stream_data_by_range(request)
+        .await?
+        .into_inner();
+    // flatten each batch message into a stream of single data
+    Ok(response.flat_map(|result| {
+        let results: Vec<Result<DataSchema, Status>> = match result {
+            Ok(value) => value.results.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)]
+        };
+        stream::iter(results)
+    }))
+}
+
+pub(crate) async fn stream_data_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataGroupRange {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stream_data_group_by_range(request)
+        .await?
+        .into_inner();
+    // flatten each batch message into a stream of single data
+    Ok(response.flat_map(|result| {
+        let results: Vec<Result<DataSchema, Status>> = match result {
+            Ok(value) => value.results.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)]
+        };
+        stream::iter(results)
+    }))
+}
+
+pub(crate) async fn list_data_by_slice(resource: &Resource, slice_id: i32, tag: Option<i16>)
+    -> Result<Vec<DataSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSlice {
+        slice_id,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_by_slice(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn stream_data_by_slice(resource: &Resource, slice_id: i32, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSlice {
+        slice_id,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stream_data_by_slice(request)
+        .await?
+        .into_inner();
+    // flatten each batch message into a stream of single data
+    Ok(response.flat_map(|result| {
+        let results: Vec<Result<DataSchema, Status>> = match result {
+            Ok(value) => value.results.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)]
+        };
+        stream::iter(results)
+    }))
+}
+
+pub(crate) async fn stats_data_by_slice(resource: &Resource, slice_id: i32, tag: Option<i16>)
+    -> Result<Vec<DataStatsSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSlice {
+        slice_id,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stats_data_by_slice(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn subscribe_data(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<DataSchema, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSubscribe {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.subscribe_data(request)
+        .await?
+        .into_inner();
+    Ok(response)
+}
+
+pub(crate) async fn export_data(resource: &Resource, source: ExportSource, id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, format: ExportFormat, tag: Option<i16>)
+    -> Result<impl Stream<Item = Result<Vec<u8>, Status>> + use<>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    // the id is a set id for set export and a device id for data and buffer export
+    let (device_id, set_id) = match source {
+        ExportSource::Set => (Vec::new(), id.as_bytes().to_vec()),
+        _ => (id.as_bytes().to_vec(), Vec::new())
+    };
+    let request = Request::new(DataExport {
+        source: source.into(),
+        device_id,
+        model_id: model_id.as_bytes().to_vec(),
+        set_id,
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        format: format.into(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.export_data(request)
+        .await?
+        .into_inner();
+    Ok(response.map(|r| r.map(|c| c.chunk)))
+}
+
 pub(crate) async fn read_data_set(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DataSetSchema, Status>
 {
@@ -341,8 +619,96 @@ pub(crate) async fn list_data_set_by_range(resource: &Resource, set_id: Uuid, be
     Ok(response.results)
 }
 
+pub(crate) async fn list_data_by_slice_set(resource: &Resource, slice_id: i32, tag: Option<i16>)
+    -> Result<Vec<DataSetSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSlice {
+        slice_id,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_by_slice_set(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn stats_data_by_slice_set(resource: &Resource, slice_id: i32, tag: Option<i16>)
+    -> Result<Vec<DataStatsSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSlice {
+        slice_id,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.stats_data_by_slice_set(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn list_data_set_aligned(resource: &Resource, set_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, interval: Duration, align: DataAlign, tag: Option<i16>)
+    -> Result<Vec<DataSetAlignedSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSetAligned {
+        set_id: set_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        interval: interval.num_microseconds().unwrap_or_default(),
+        align: align.into(),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_set_aligned(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn list_data_set_by_number_before(resource: &Resource, set_id: Uuid, before: DateTime<Utc>, number: usize, tag: Option<i16>)
+    -> Result<Vec<DataSetSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSetNumber {
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: before.timestamp_micros(),
+        number: number as u32,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_set_by_number_before(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn list_data_set_by_number_after(resource: &Resource, set_id: Uuid, after: DateTime<Utc>, number: usize, tag: Option<i16>)
+    -> Result<Vec<DataSetSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSetNumber {
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: after.timestamp_micros(),
+        number: number as u32,
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_data_set_by_number_after(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
 pub(crate) async fn create_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
-    -> Result<(), Status>
+    -> Result<usize, Status>
 {
     let interceptor = TokenInterceptor(resource.access_token.clone());
     let mut client = 
@@ -355,13 +721,14 @@ pub(crate) async fn create_data(resource: &Resource, device_id: Uuid, model_id:
         data_type: ArrayDataValue::from_vec(data).get_types().into_iter().map(|el| el.into()).collect(),
         tag: tag.unwrap_or(Tag::DEFAULT) as i32
     });
-    client.create_data(request)
-        .await?;
-    Ok(())
+    let response = client.create_data(request)
+        .await?
+        .into_inner();
+    Ok(response.violated as usize)
 }
 
-pub(crate) async fn create_data_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>)
-    -> Result<(), Status>
+pub(crate) async fn create_data_multiple(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamps: &[DateTime<Utc>], data: &[&[DataValue]], tags: Option<&[i16]>, conflict: Option<DataConflict>)
+    -> Result<DataConflictCount, Status>
 {
     let interceptor = TokenInterceptor(resource.access_token.clone());
     let mut client = 
@@ -383,12 +750,135 @@ pub(crate) async fn create_data_multiple(resource: &Resource, device_ids: &[Uuid
         data_type: ArrayDataValue::from_vec(data[i]).get_types().into_iter().map(|el| el.into()).collect(),
         tag: tags[i] as i32
     }).collect();
-    let request = Request::new(DataMultipleSchema { schemas });
-    client.create_data_multiple(request)
+    let request = Request::new(DataMultipleSchema {
+        schemas,
+        conflict: conflict.map(|c| c.into())
+    });
+    let response = client.create_data_multiple(request)
+        .await?
+        .into_inner();
+    Ok(DataConflictCount {
+        inserted: response.inserted as usize,
+        skipped: response.skipped as usize,
+        overwritten: response.overwritten as usize,
+        violated: response.violated as usize
+    })
+}
+
+pub(crate) async fn create_data_set(resource: &Resource, set_id: Uuid, timestamp: DateTime<Utc>, data: &[DataValue], tag: Option<i16>)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataSetSchema {
+        set_id: set_id.as_bytes().to_vec(),
+        timestamp: timestamp.timestamp_micros(),
+        data_bytes: ArrayDataValue::from_vec(data).to_bytes(),
+        data_type: ArrayDataValue::from_vec(data).get_types().into_iter().map(|el| el.into()).collect(),
+        tag: tag.unwrap_or(Tag::DEFAULT) as i32
+    });
+    let response = client.create_data_set(request)
+        .await?
+        .into_inner();
+    Ok(response.violated as usize)
+}
+
+pub(crate) async fn ingest_data<S>(resource: &Resource, data: S)
+    -> Result<DataIngestResponse, Status>
+where
+    S: Stream<Item = DataSchema> + Send + 'static
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let response = client.ingest_data(data)
+        .await?
+        .into_inner();
+    Ok(response)
+}
+
+pub(crate) async fn import_data(resource: &Resource, rows: Vec<(u64, DataSchema)>, conflict: DataConflict)
+    -> Result<DataImportResponse, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    // send the rows in batches with their line numbers
+    let conflict: String = conflict.into();
+    let messages: Vec<DataImport> = rows.chunks(IMPORT_BATCH_SIZE).map(|chunk| DataImport {
+        lines: chunk.iter().map(|(line, _)| *line).collect(),
+        data: chunk.iter().map(|(_, d)| d.clone()).collect(),
+        conflict: conflict.clone()
+    }).collect();
+    let response = client.import_data(stream::iter(messages))
+        .await?
+        .into_inner();
+    Ok(response)
+}
+
+pub(crate) async fn update_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>, data: Option<&[DataValue]>, new_tag: Option<i16>)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataUpdate {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        timestamp: timestamp.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        data_bytes: data.map(|v| ArrayDataValue::from_vec(v).to_bytes()),
+        data_type: ArrayDataValue::from_vec(data.unwrap_or_default()).get_types().into_iter().map(|el| el.into()).collect(),
+        new_tag: new_tag.map(|t| t as i32)
+    });
+    client.update_data(request)
         .await?;
     Ok(())
 }
 
+pub(crate) async fn update_data_by_range(resource: &Resource, update: DataRangeUpdate)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let value = ArrayDataValue::from_vec(&[update.value]);
+    let request = Request::new(DataUpdateRange {
+        device_id: update.device_id.as_bytes().to_vec(),
+        model_id: update.model_id.as_bytes().to_vec(),
+        begin: update.begin.timestamp_micros(),
+        end: update.end.timestamp_micros(),
+        tag: update.tag.map(|t| t as i32),
+        index: update.index as u32,
+        data_bytes: value.to_bytes(),
+        data_type: value.get_types().into_iter().map(|el| el.into()).collect()
+    });
+    let response = client.update_data_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+pub(crate) async fn list_data_audit_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>)
+    -> Result<Vec<DataAuditSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataRange {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: None
+    });
+    let response = client.list_data_audit_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
 pub(crate) async fn delete_data(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<(), Status>
 {
@@ -406,6 +896,46 @@ pub(crate) async fn delete_data(resource: &Resource, device_id: Uuid, model_id:
     Ok(())
 }
 
+pub(crate) async fn delete_data_by_range(resource: &Resource, device_id: Uuid, model_id: Uuid, begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataRangeDelete {
+        device_id: device_id.as_bytes().to_vec(),
+        model_id: model_id.as_bytes().to_vec(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        dry_run
+    });
+    let response = client.delete_data_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
+pub(crate) async fn delete_data_group_by_range(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], begin: DateTime<Utc>, end: DateTime<Utc>, tag: Option<i16>, dry_run: bool)
+    -> Result<usize, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DataServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DataGroupRangeDelete {
+        device_ids: device_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        model_ids: model_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        tag: tag.map(|t| t as i32),
+        dry_run
+    });
+    let response = client.delete_data_group_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.count as usize)
+}
+
 pub(crate) async fn read_data_timestamp(resource: &Resource, device_id: Uuid, model_id: Uuid, timestamp: DateTime<Utc>, tag: Option<i16>)
     -> Result<DateTime<Utc>, Status>
 {
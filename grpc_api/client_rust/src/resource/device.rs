@@ -3,12 +3,15 @@ use uuid::Uuid;
 use bbthings_database::common::type_value::{DataType, DataValue};
 use bbthings_grpc_server::proto::resource::device::device_service_client::DeviceServiceClient;
 use bbthings_grpc_server::proto::resource::device::{
-    DeviceSchema, DeviceId, DeviceIds, DeviceName, DeviceOption, DeviceUpdate,
+    DeviceSchema, DeviceId, DeviceIds, DeviceName, DeviceOption, DeviceOptionPage, DeviceUpdate,
     GatewaySchema, GatewayId, GatewayIds, GatewayName, SerialNumber, GatewayOption, GatewayUpdate,
     ConfigSchema, ConfigId, ConfigUpdate,
     TypeSchema, TypeId, TypeIds, TypeName, TypeOption, TypeUpdate, TypeModel,
-    TypeConfigSchema, TypeConfigId, TypeConfigUpdate
+    TypeConfigSchema, TypeConfigId, TypeConfigUpdate,
+    IntegrityOption, IntegrityViolationSchema
 };
+use bbthings_database::{Cursor, Page};
+use bbthings_database::resource::integrity::IntegrityTable;
 use crate::resource::Resource;
 use bbthings_grpc_server::common::interceptor::TokenInterceptor;
 
@@ -124,6 +127,26 @@ pub(crate) async fn list_device_option(resource: &Resource, gateway_id: Option<U
     Ok(response.results)
 }
 
+pub(crate) async fn list_device_option_page(resource: &Resource, gateway_id: Option<Uuid>, type_id: Option<Uuid>, name: Option<&str>, page: &Page)
+    -> Result<(Vec<DeviceSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(DeviceOptionPage {
+        gateway_id: gateway_id.map(|id| id.as_bytes().to_vec()),
+        type_id: type_id.map(|id| id.as_bytes().to_vec()),
+        name: name.map(|s| s.to_owned()),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_device_option_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
 pub(crate) async fn create_device(resource: &Resource, id: Uuid, gateway_id: Uuid, type_id: Uuid, serial_number: &str, name: &str, description: &str)
     -> Result<Uuid, Status>
 {
@@ -707,3 +730,18 @@ pub(crate) async fn delete_type_config(resource: &Resource, id: i32)
         .await?;
     Ok(())
 }
+
+pub(crate) async fn list_integrity_violation(resource: &Resource, table: Option<IntegrityTable>)
+    -> Result<Vec<IntegrityViolationSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        DeviceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(IntegrityOption {
+        table: table.map(|t| t.into())
+    });
+    let response = client.list_integrity_violation(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
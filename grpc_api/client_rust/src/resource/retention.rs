@@ -0,0 +1,106 @@
+use tonic::{Request, Status};
+use chrono::Duration;
+use uuid::Uuid;
+use bbthings_grpc_server::proto::resource::retention::retention_service_client::RetentionServiceClient;
+use bbthings_grpc_server::proto::resource::retention::{
+    RetentionSchema, RetentionId, RetentionOption, RetentionUpdate, RetentionApply
+};
+use crate::resource::Resource;
+use bbthings_grpc_server::common::interceptor::TokenInterceptor;
+
+const RETENTION_NOT_FOUND: &str = "requested retention not found";
+
+pub(crate) async fn read_retention(resource: &Resource, id: i32)
+    -> Result<RetentionSchema, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionId {
+        id
+    });
+    let response = client.read_retention(request)
+        .await?
+        .into_inner();
+    response.result.ok_or(Status::not_found(RETENTION_NOT_FOUND))
+}
+
+pub(crate) async fn list_retention_option(resource: &Resource, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>)
+    -> Result<Vec<RetentionSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionOption {
+        model_id: model_id.map(|id| id.as_bytes().to_vec()),
+        type_id: type_id.map(|id| id.as_bytes().to_vec()),
+        tag: tag.map(|t| t as i32)
+    });
+    let response = client.list_retention_option(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn create_retention(resource: &Resource, model_id: Option<Uuid>, type_id: Option<Uuid>, tag: Option<i16>, retention: Duration)
+    -> Result<i32, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionSchema {
+        id: 0,
+        model_id: model_id.map(|id| id.as_bytes().to_vec()),
+        type_id: type_id.map(|id| id.as_bytes().to_vec()),
+        tag: tag.map(|t| t as i32),
+        retention: retention.num_microseconds().unwrap_or(i64::MAX)
+    });
+    let response = client.create_retention(request)
+        .await?
+        .into_inner();
+    Ok(response.id)
+}
+
+pub(crate) async fn update_retention(resource: &Resource, id: i32, retention: Duration)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionUpdate {
+        id,
+        retention: retention.num_microseconds().unwrap_or(i64::MAX)
+    });
+    client.update_retention(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn delete_retention(resource: &Resource, id: i32)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionId {
+        id
+    });
+    client.delete_retention(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn apply_retention(resource: &Resource, dry_run: bool)
+    -> Result<Vec<(i32, usize)>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        RetentionServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(RetentionApply {
+        dry_run
+    });
+    let response = client.apply_retention(request)
+        .await?
+        .into_inner();
+    Ok(response.results.into_iter().map(|r| (r.id, r.count as usize)).collect())
+}
@@ -138,7 +138,7 @@ pub(crate) async fn delete_set(resource: &Resource, id: Uuid)
 }
 
 pub(crate) async fn add_set_member(resource: &Resource, id: Uuid, device_id: Uuid, model_id: Uuid, data_index: &[u8])
-    -> Result<(), Status>
+    -> Result<usize, Status>
 {
     let interceptor = TokenInterceptor(resource.access_token.clone());
     let mut client = 
@@ -149,9 +149,10 @@ pub(crate) async fn add_set_member(resource: &Resource, id: Uuid, device_id: Uui
         model_id: model_id.as_bytes().to_vec(),
         data_index: data_index.to_owned()
     });
-    client.add_set_member(request)
-        .await?;
-    Ok(())
+    let response = client.add_set_member(request)
+        .await?
+        .into_inner();
+    Ok(response.violated as usize)
 }
 
 pub(crate) async fn remove_set_member(resource: &Resource, id: Uuid, device_id: Uuid, model_id: Uuid)
@@ -1,12 +1,15 @@
 use tonic::{Request, Status};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc, Duration};
 use uuid::Uuid;
 use bbthings_grpc_server::proto::resource::slice::slice_service_client::SliceServiceClient;
 use bbthings_grpc_server::proto::resource::slice::{
-    SliceSchema, SliceId, SliceIds, SliceTime, SliceRange, SliceNameTime, SliceNameRange, SliceUpdate, SliceOption,
+    SliceSchema, SliceId, SliceIds, SliceTime, SliceRange, SliceNameTime, SliceNameRange, SliceUpdate, SliceOption, SliceOptionPage,
     SliceGroupTime, SliceGroupRange, SliceGroupOption,
-    SliceSetSchema, SliceSetTime, SliceSetRange, SliceSetOption
+    SliceSetSchema, SliceSetTime, SliceSetRange, SliceSetOption,
+    SliceRuleSchema, SliceRuleOption, SliceRuleUpdate, SliceRuleRange, SliceRuleApply
 };
+use bbthings_database::resource::slice::{SliceCondition, SliceRuleCreate};
+use bbthings_database::{Cursor, Page};
 use crate::resource::Resource;
 use bbthings_grpc_server::common::interceptor::TokenInterceptor;
 
@@ -129,6 +132,28 @@ pub(crate) async fn list_slice_option(resource: &Resource, device_id: Option<Uui
     Ok(response.results)
 }
 
+pub(crate) async fn list_slice_option_page(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>, name: Option<&str>, begin_or_timestamp: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>, page: &Page)
+    -> Result<(Vec<SliceSchema>, Option<Cursor>), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceOptionPage {
+        device_id: device_id.map(|id| id.as_bytes().to_vec()),
+        model_id: model_id.map(|id| id.as_bytes().to_vec()),
+        name: name.map(|s| s.to_owned()),
+        begin: begin_or_timestamp.map(|t| t.timestamp_micros()),
+        end: end.map(|t| t.timestamp_micros()),
+        cursor: page.cursor.as_ref().map(|c| c.to_bytes()),
+        limit: page.limit as u32
+    });
+    let response = client.list_slice_option_page(request)
+        .await?
+        .into_inner();
+    let cursor = response.cursor.and_then(|c| Cursor::from_bytes(&c));
+    Ok((response.results, cursor))
+}
+
 pub(crate) async fn list_slice_group_by_time(resource: &Resource, device_ids: &[Uuid], model_ids: &[Uuid], timestamp: DateTime<Utc>)
     -> Result<Vec<SliceSchema>, Status>
 {
@@ -405,3 +430,124 @@ pub(crate) async fn delete_slice_set(resource: &Resource, id: i32)
         .await?;
     Ok(())
 }
+
+pub(crate) async fn read_slice_rule(resource: &Resource, id: i32)
+    -> Result<SliceRuleSchema, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceId {
+        id
+    });
+    let response = client.read_slice_rule(request)
+        .await?
+        .into_inner();
+    response.result.ok_or(Status::not_found(SLICE_NOT_FOUND))
+}
+
+pub(crate) async fn list_slice_rule_option(resource: &Resource, device_id: Option<Uuid>, model_id: Option<Uuid>)
+    -> Result<Vec<SliceRuleSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceRuleOption {
+        device_id: device_id.map(|id| id.as_bytes().to_vec()),
+        model_id: model_id.map(|id| id.as_bytes().to_vec())
+    });
+    let response = client.list_slice_rule_option(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn create_slice_rule(resource: &Resource, rule: &SliceRuleCreate)
+    -> Result<i32, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceRuleSchema {
+        id: 0,
+        device_id: rule.device_id.as_bytes().to_vec(),
+        model_id: rule.model_id.as_bytes().to_vec(),
+        index: rule.index as u32,
+        condition: rule.condition.into(),
+        threshold: rule.threshold,
+        min_duration: rule.min_duration.num_microseconds().unwrap_or(i64::MAX),
+        max_gap: rule.max_gap.num_microseconds().unwrap_or(i64::MAX),
+        name: rule.name.clone(),
+        checkpoint: None
+    });
+    let response = client.create_slice_rule(request)
+        .await?
+        .into_inner();
+    Ok(response.id)
+}
+
+pub(crate) async fn update_slice_rule(resource: &Resource, id: i32, condition: Option<SliceCondition>, threshold: Option<f64>, min_duration: Option<Duration>, max_gap: Option<Duration>, name: Option<&str>)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceRuleUpdate {
+        id,
+        condition: condition.map(|c| c.into()),
+        threshold,
+        min_duration: min_duration.map(|d| d.num_microseconds().unwrap_or(i64::MAX)),
+        max_gap: max_gap.map(|d| d.num_microseconds().unwrap_or(i64::MAX)),
+        name: name.map(|s| s.to_owned())
+    });
+    client.update_slice_rule(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn delete_slice_rule(resource: &Resource, id: i32)
+    -> Result<(), Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceId {
+        id
+    });
+    client.delete_slice_rule(request)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn generate_slice_by_range(resource: &Resource, id: i32, begin: DateTime<Utc>, end: DateTime<Utc>, dry_run: bool)
+    -> Result<Vec<SliceSchema>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceRuleRange {
+        id,
+        begin: begin.timestamp_micros(),
+        end: end.timestamp_micros(),
+        dry_run
+    });
+    let response = client.generate_slice_by_range(request)
+        .await?
+        .into_inner();
+    Ok(response.results)
+}
+
+pub(crate) async fn apply_slice_rule(resource: &Resource, dry_run: bool)
+    -> Result<Vec<(i32, usize)>, Status>
+{
+    let interceptor = TokenInterceptor(resource.access_token.clone());
+    let mut client = 
+        SliceServiceClient::with_interceptor(resource.channel.to_owned(), interceptor);
+    let request = Request::new(SliceRuleApply {
+        dry_run
+    });
+    let response = client.apply_slice_rule(request)
+        .await?
+        .into_inner();
+    Ok(response.results.into_iter().map(|r| (r.id, r.count as usize)).collect())
+}
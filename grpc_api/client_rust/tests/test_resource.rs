@@ -1,11 +1,20 @@
 #[cfg(test)]
 mod tests {
     use uuid::Uuid;
-    use chrono::DateTime;
+    use futures_util::StreamExt;
+    use chrono::{DateTime, Utc, Duration};
     use bbthings_grpc_client::Resource;
-    use bbthings_grpc_client::{ModelConfigSchema, DeviceConfigSchema, SetMember};
+    use bbthings_grpc_client::{ModelConfigSchema, DeviceConfigSchema, SetMember, DataSchema};
     use bbthings_grpc_client::{DataType::*, DataValue::{*, self}};
     use bbthings_grpc_client::tag;
+    use bbthings_grpc_client::Page;
+    use bbthings_grpc_client::{DataAggregate, DataAlign};
+    use bbthings_grpc_client::{SliceCondition, SliceRuleCreate};
+    use bbthings_grpc_client::CompressionTable;
+    use bbthings_grpc_client::IntegrityTable;
+    use bbthings_grpc_client::ExportFormat;
+    use bbthings_grpc_client::ImportMapping;
+    use bbthings_grpc_client::{DataConflict, DataConflictCount, DataRangeUpdate};
     use bbthings_grpc_server::common::test::{TestServerKind, TestServer};
 
     #[tokio::test]
@@ -163,7 +172,10 @@ mod tests {
         let raw_2 = vec![I32(1452),I32(-341)];
         resource.create_buffer(device_id1, model_buf_id, timestamp_1, &raw_1, Some(tag::ANALYSIS_1)).await.unwrap();
         resource.create_buffer(device_id2, model_buf_id, timestamp_1, &raw_2, Some(tag::ANALYSIS_1)).await.unwrap();
-        let ids = resource.create_buffer_multiple(&[device_id1, device_id2], &[model_buf_id, model_buf_id], &[timestamp_2, timestamp_2], &[&raw_1, &raw_2], Some(&[tag::TRANSFER_LOCAL, tag::TRANSFER_LOCAL])).await.unwrap();
+        let (ids, _) = resource.create_buffer_multiple(&[device_id1, device_id2], &[model_buf_id, model_buf_id], &[timestamp_2, timestamp_2], &[&raw_1, &raw_2], Some(&[tag::TRANSFER_LOCAL, tag::TRANSFER_LOCAL])).await.unwrap();
+        let (ids_retry, count) = resource.create_buffer_multiple_conflict(&[device_id1, device_id2], &[model_buf_id, model_buf_id], &[timestamp_2, timestamp_2], &[&raw_1, &raw_2], Some(&[tag::TRANSFER_LOCAL, tag::TRANSFER_LOCAL]), DataConflict::Ignore).await.unwrap();
+        assert!(ids_retry.is_empty());
+        assert_eq!(count, DataConflictCount { inserted: 0, skipped: 2, overwritten: 0, violated: 0 });
 
         // read buffer
         let buffers = resource.list_buffer_first(100, None, None, None).await.unwrap();
@@ -193,7 +205,20 @@ mod tests {
         // create data
         resource.create_data(device_id1, model_id, timestamp_1, &[F32(speed1), F32(direction1)], None).await.unwrap();
         resource.create_data(device_id2, model_id, timestamp_1, &[F32(speed2), F32(direction2)], None).await.unwrap();
+        // reject data with a value not matching the model type or with a wrong number of values
+        let err = resource.create_data(device_id1, model_id, timestamp_2, &[F32(speed1), Bool(true)], None).await.unwrap_err();
+        assert!(err.message().contains("field index 1"));
+        let result = resource.create_data(device_id1, model_id, timestamp_2, &[F32(speed1)], None).await;
+        assert!(result.is_err());
         resource.create_data_multiple(&[device_id1, device_id2], &[model_id, model_id], &[timestamp_2, timestamp_2], &[&[F32(speed1), F32(direction1)], &[F32(speed2), F32(direction2)]], None).await.unwrap();
+        // retry data upload with error, ignore, and overwrite conflict policy
+        let result = resource.create_data_multiple(&[device_id1], &[model_id], &[timestamp_2], &[&[F32(speed1), F32(direction1)]], None).await;
+        assert!(result.is_err());
+        let count = resource.create_data_multiple_conflict(&[device_id1, device_id2], &[model_id, model_id], &[timestamp_2, timestamp_2], &[&[F32(speed1), F32(direction1)], &[F32(speed2), F32(direction2)]], None, DataConflict::Ignore).await.unwrap();
+        assert_eq!(count, DataConflictCount { inserted: 0, skipped: 2, overwritten: 0, violated: 0 });
+        let count = resource.create_data_multiple_conflict(&[device_id1, device_id2], &[model_id, model_id], &[timestamp_2, timestamp_2], &[&[F32(speed1), F32(direction1)], &[F32(speed2), F32(direction2)]], Some(&[tag::DEFAULT, tag::ERROR]), DataConflict::Overwrite).await.unwrap();
+        assert_eq!(count, DataConflictCount { inserted: 1, skipped: 0, overwritten: 1, violated: 0 });
+        resource.delete_data(device_id2, model_id, timestamp_2, Some(tag::ERROR)).await.unwrap();
 
         // read data
         let datas = resource.list_data_by_number_before(device_id1, model_id, timestamp_1, 100, None).await.unwrap();
@@ -202,6 +227,64 @@ mod tests {
         assert_eq!(timestamp_1, data.timestamp);
         assert_eq!(tag::DEFAULT, data.tag);
 
+        // export data, buffer, and data set to csv, json lines, and parquet
+        let chunks: Vec<Vec<u8>> = resource.export_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, ExportFormat::Csv, None).await.unwrap()
+            .map(|c| c.unwrap()).collect().await;
+        let csv = std::string::String::from_utf8(chunks.concat()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "device_id,model_id,timestamp,tag,speed,direction");
+        assert_eq!(lines[1], format!("{},{},2023-05-07T07:08:48.123456Z,0,{},{}", device_id1, model_id, speed1, direction1));
+        let chunks: Vec<Vec<u8>> = resource.export_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, ExportFormat::Ndjson, None).await.unwrap()
+            .map(|c| c.unwrap()).collect().await;
+        let ndjson = std::string::String::from_utf8(chunks.concat()).unwrap();
+        assert_eq!(ndjson.lines().count(), 2);
+        assert!(ndjson.starts_with(&format!("{{\"device_id\":\"{}\",", device_id1)));
+        assert!(ndjson.contains(&format!("\"speed\":{},\"direction\":{}}}", speed1, direction1)));
+        let chunks: Vec<Vec<u8>> = resource.export_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, ExportFormat::Parquet, None).await.unwrap()
+            .map(|c| c.unwrap()).collect().await;
+        let parquet = chunks.concat();
+        assert!(parquet.starts_with(b"PAR1") && parquet.ends_with(b"PAR1"));
+        let chunks: Vec<Vec<u8>> = resource.export_buffer_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, ExportFormat::Csv, None).await.unwrap()
+            .map(|c| c.unwrap()).collect().await;
+        let csv = std::string::String::from_utf8(chunks.concat()).unwrap();
+        assert_eq!(csv.lines().next(), Some("device_id,model_id,timestamp,tag,field_0,field_1"));
+        assert_eq!(csv.lines().count(), 3);
+        let chunks: Vec<Vec<u8>> = resource.export_data_set_by_range(set_id, timestamp_1, timestamp_2, ExportFormat::Csv, None).await.unwrap()
+            .map(|c| c.unwrap()).collect().await;
+        let csv = std::string::String::from_utf8(chunks.concat()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "set_id,timestamp,tag,direction,direction_1");
+        assert_eq!(lines[1], format!("{},2023-05-07T07:08:48.123456Z,0,{},{}", set_id, direction1, direction2));
+
+        // read the latest data of every device and model pair, then insert newer data and delete it
+        let latest = resource.list_data_latest(&[device_id1, device_id2], &[model_id], None).await.unwrap();
+        assert_eq!(latest.len(), 2);
+        assert!(latest.iter().all(|d| d.timestamp == timestamp_2));
+        let latest = resource.list_data_latest(&[device_id1, device_id2], &[model_id], None).await.unwrap();
+        let data = latest.iter().find(|d| d.device_id == device_id1).unwrap();
+        assert_eq!(data.data, vec![F32(speed1), F32(direction1)]);
+        let timestamp_latest = timestamp_2 + Duration::seconds(1);
+        resource.create_data(device_id1, model_id, timestamp_latest, &[F64(speed2 as f64), F64(direction2 as f64)], None).await.unwrap();
+        let latest = resource.list_data_latest(&[device_id1], &[model_id], None).await.unwrap();
+        assert_eq!(latest[0].timestamp, timestamp_latest);
+        assert_eq!(latest[0].data, vec![F32(speed2), F32(direction2)]);
+        resource.delete_data(device_id1, model_id, timestamp_latest, None).await.unwrap();
+        let latest = resource.list_data_latest(&[device_id1], &[model_id], None).await.unwrap();
+        assert_eq!(latest[0].timestamp, timestamp_2);
+
+        // compute per field statistics of a device and a device group inside a range
+        let stats = resource.stats_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, None).await.unwrap();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].count, 2);
+        assert_eq!((stats[0].min.clone(), stats[0].max.clone()), (F32(speed1), F32(speed1)));
+        assert_eq!((stats[0].mean, stats[0].stddev), (speed1 as f64, 0.0));
+        assert_eq!((stats[1].first.clone(), stats[1].last.clone()), (F32(direction1), F32(direction1)));
+        let stats = resource.stats_data_group_by_range(&[device_id1, device_id2], &[model_id], timestamp_1, timestamp_2, None).await.unwrap();
+        assert_eq!(stats.len(), 4);
+        let stat = stats.iter().find(|s| s.device_id == device_id2 && s.index == 1).unwrap();
+        assert_eq!(stat.max, F32(direction2));
+
         // read data from a device group
         let data_group = resource.list_data_group_by_time(&group_device.device_ids, &[model_id], timestamp_1, None).await.unwrap();
         let data_values_vec: Vec<Vec<DataValue>> = data_group.iter().map(|d| d.data.clone()).collect();
@@ -209,10 +292,245 @@ mod tests {
         assert!(data_values.contains(&F32(speed1)));
         assert!(data_values.contains(&F32(speed2)));
 
+        // stream data and buffers of a device group and compare with listed data and buffers
+        let data_list = resource.list_data_group_by_range(&group_device.device_ids, &[model_id], timestamp_1, timestamp_2, None).await.unwrap();
+        let data_stream = resource.stream_data_group_by_range(&group_device.device_ids, &[model_id], timestamp_1, timestamp_2, None).await.unwrap();
+        let data_stream: Vec<_> = data_stream.collect::<Vec<_>>().await.into_iter().map(|d| d.unwrap()).collect();
+        assert_eq!(data_stream, data_list);
+        let buffer_list = resource.list_buffer_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, None).await.unwrap();
+        let buffer_stream = resource.stream_buffer_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, None).await.unwrap();
+        let buffer_stream: Vec<_> = buffer_stream.collect::<Vec<_>>().await.into_iter().map(|b| b.unwrap()).collect();
+        assert_eq!(buffer_stream.len(), 2);
+        assert_eq!(buffer_stream, buffer_list);
+
+        // walk data of a device group and list of devices page by page using cursor
+        let (page_1, cursor) = resource.list_data_group_by_range_page(&group_device.device_ids, &[model_id], timestamp_1, timestamp_2, None, &Page::new(None, 3)).await.unwrap();
+        assert_eq!(page_1.len(), 3);
+        let (page_2, cursor) = resource.list_data_group_by_range_page(&group_device.device_ids, &[model_id], timestamp_1, timestamp_2, None, &Page::new(cursor, 3)).await.unwrap();
+        assert_eq!(cursor, None);
+        let data_pages = [page_1, page_2].concat();
+        assert_eq!(data_pages.len(), data_list.len());
+        assert!(data_pages.iter().all(|d| data_list.contains(d)));
+        let devices = resource.list_device_option(None, None, None).await.unwrap();
+        let mut device_pages = Vec::new();
+        let mut device_page = Page::new(None, 1);
+        loop {
+            let (page, next) = resource.list_device_option_page(None, None, None, &device_page).await.unwrap();
+            device_pages.extend(page);
+            if next.is_none() {
+                break;
+            }
+            device_page.cursor = next;
+        }
+        assert_eq!(device_pages, devices);
+
+        // ingest data rows, a row with unmatched type and a duplicate row are rejected
+        let timestamp_4 = DateTime::parse_from_str("2024-01-15 10:20:30.123456 +0000", "%Y-%m-%d %H:%M:%S.%6f %z").unwrap().into();
+        let ingest_rows = vec![
+            DataSchema { device_id: device_id1, model_id, timestamp: timestamp_4, data: vec![F32(speed1), F32(direction1)], tag: tag::DEFAULT },
+            DataSchema { device_id: device_id2, model_id, timestamp: timestamp_4, data: vec![Bool(true), F32(direction2)], tag: tag::DEFAULT },
+            DataSchema { device_id: device_id1, model_id, timestamp: timestamp_4, data: vec![F32(speed2), F32(direction2)], tag: tag::DEFAULT }
+        ];
+        let ingest = resource.ingest_data(futures_util::stream::iter(ingest_rows)).await.unwrap();
+        assert_eq!(ingest.count, 1);
+        assert!(ingest.error.is_none());
+        assert_eq!(ingest.batches.len(), 1);
+        assert_eq!(ingest.rejects.iter().map(|r| r.index).collect::<Vec<u64>>(), vec![1, 2]);
+        let data = resource.read_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(speed1), F32(direction1)]);
+        resource.delete_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+
+        // import csv lines with ignore conflict policy, the duplicate line is skipped and invalid lines are rejected
+        let csv = "timestamp,speed,direction\n\
+            2024-01-15T10:20:30.123456Z,1.5,2.5\n\
+            2023-05-07T07:08:48.123456Z,1.5,2.5\n\
+            2024-01-15T10:20:31Z,abc,2.5\n\
+            yesterday,1.5,2.5\n";
+        let mapping = ImportMapping::new(device_id1, model_id);
+        let import = resource.import_data(csv.as_bytes(), ExportFormat::Csv, &mapping, DataConflict::Ignore).await.unwrap();
+        assert_eq!((import.inserted, import.skipped, import.overwritten), (1, 1, 0));
+        assert!(import.error.is_none());
+        assert_eq!(import.rejects.iter().map(|r| r.line).collect::<Vec<u64>>(), vec![4, 5]);
+        assert!(import.rejects[0].reason.contains("field index 0"));
+        let data = resource.read_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(1.5), F32(2.5)]);
+        // import json lines with device and model columns and overwrite the existing data
+        let ndjson = format!("{{\"device_id\":\"{}\",\"model_id\":\"{}\",\"timestamp\":\"2024-01-15T10:20:30.123456Z\",\"tag\":0,\"speed\":3.5,\"direction\":4.5}}\n", device_id1, model_id);
+        let import = resource.import_data(ndjson.as_bytes(), ExportFormat::Ndjson, &ImportMapping::default(), DataConflict::Overwrite).await.unwrap();
+        assert_eq!((import.inserted, import.skipped, import.overwritten), (0, 0, 1));
+        let data = resource.read_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(3.5), F32(4.5)]);
+        resource.delete_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+
+        // subscribe new data of a device then create data of other device and the subscribed device
+        let mut subscription = resource.subscribe_data(&[device_id1], &[model_id], None).await.unwrap();
+        resource.create_data(device_id2, model_id, timestamp_4, &[F32(speed2), F32(direction2)], None).await.unwrap();
+        resource.create_data(device_id1, model_id, timestamp_4, &[F32(speed1), F32(direction1)], None).await.unwrap();
+        let data = subscription.next().await.unwrap().unwrap();
+        assert_eq!(data.device_id, device_id1);
+        assert_eq!(data.timestamp, timestamp_4);
+        assert_eq!(data.data, vec![F32(speed1), F32(direction1)]);
+        drop(subscription);
+        resource.delete_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+        resource.delete_data(device_id2, model_id, timestamp_4, None).await.unwrap();
+
         // read data set
         let data_set = resource.read_data_set(set_id, timestamp_1, None).await.unwrap();
         assert_eq!(data_set.data[0], F32(direction1));
         assert_eq!(data_set.data[1], F32(direction2));
+        // list data set by number before and after, the number counts whole set rows
+        let data_sets = resource.list_data_set_by_number_before(set_id, timestamp_2, 1, None).await.unwrap();
+        assert_eq!(data_sets.len(), 1);
+        assert_eq!(data_sets[0].timestamp, timestamp_2);
+        assert_eq!(data_sets[0].data.len(), 2);
+        let data_sets = resource.list_data_set_by_number_after(set_id, timestamp_1, 2, None).await.unwrap();
+        assert_eq!(data_sets.iter().map(|d| d.timestamp).collect::<Vec<_>>(), vec![timestamp_1, timestamp_2]);
+
+        // create data set and read it back, a set which doesn't cover all model fields can't be created
+        let set_id_full = resource.create_set(Uuid::new_v4(), template_id, "multiple compass full", "").await.unwrap();
+        resource.add_set_member(set_id_full, device_id1, model_id, &[0, 1]).await.unwrap();
+        resource.add_set_member(set_id_full, device_id2, model_id, &[1, 0]).await.unwrap();
+        let set_values = vec![F32(speed1), F32(direction1), F32(direction2), F32(speed2)];
+        resource.create_data_set(set_id_full, timestamp_4, &set_values, None).await.unwrap();
+        let data_set = resource.read_data_set(set_id_full, timestamp_4, None).await.unwrap();
+        assert_eq!(data_set.data, set_values);
+        let data = resource.read_data(device_id2, model_id, timestamp_4, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(speed2), F32(direction2)]);
+        assert!(resource.create_data_set(set_id, timestamp_4, &[F32(direction1), F32(direction2)], None).await.is_err());
+        // align data set to minute buckets, the empty bucket and the missing members are filled by previous or interpolated values
+        let timestamp_5 = timestamp_4 + Duration::minutes(2);
+        resource.create_data(device_id1, model_id, timestamp_5, &[F32(speed2), F32(direction2)], None).await.unwrap();
+        let aligned = resource.list_data_set_aligned(set_id_full, timestamp_4, timestamp_5, Duration::minutes(1), DataAlign::Locf, None).await.unwrap();
+        assert_eq!(aligned.len(), 3);
+        assert_eq!(aligned[1].timestamp, timestamp_4 + Duration::minutes(1));
+        assert_eq!(aligned[1].data, set_values);
+        assert_eq!(aligned[1].present, vec![false; 4]);
+        assert_eq!(aligned[2].data, vec![F32(speed2), F32(direction2), F32(direction2), F32(speed2)]);
+        assert_eq!(aligned[2].present, vec![true, true, false, false]);
+        let aligned = resource.list_data_set_aligned(set_id_full, timestamp_4, timestamp_5, Duration::minutes(1), DataAlign::Linear, None).await.unwrap();
+        let middle = |a: f32, b: f32| F32((a as f64 + (b as f64 - a as f64) * 0.5) as f32);
+        assert_eq!(aligned[1].data, vec![middle(speed1, speed2), middle(direction1, direction2), F32(direction2), F32(speed2)]);
+        let result = resource.list_data_set_aligned(set_id_full, timestamp_4, timestamp_5, Duration::microseconds(1), DataAlign::Locf, None).await;
+        assert!(result.is_err());
+        resource.delete_data(device_id1, model_id, timestamp_5, None).await.unwrap();
+        resource.delete_data(device_id1, model_id, timestamp_4, None).await.unwrap();
+        resource.delete_data(device_id2, model_id, timestamp_4, None).await.unwrap();
+        resource.delete_set(set_id_full).await.unwrap();
+
+        // aggregate data hourly and read average, minimum, and maximum data
+        let timestamp_3 = DateTime::parse_from_str("2023-05-07 07:18:48.123456 +0000", "%Y-%m-%d %H:%M:%S.%6f %z").unwrap().into();
+        let timestamp_hour = DateTime::parse_from_str("2023-05-07 07:00:00.000000 +0000", "%Y-%m-%d %H:%M:%S.%6f %z").unwrap().into();
+        resource.create_data(device_id1, model_id, timestamp_3, &[F32(speed2), F32(direction2)], None).await.unwrap();
+        // read data bucketed hourly
+        let buckets = resource.list_data_bucketed(device_id1, model_id, timestamp_1, timestamp_3, Duration::hours(1), DataAggregate::Max, None).await.unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].timestamp, timestamp_hour);
+        assert_eq!(buckets[0].data, vec![F32(speed1.max(speed2)), F32(direction1.max(direction2))]);
+        let buckets = resource.list_data_bucketed(device_id1, model_id, timestamp_1, timestamp_3, Duration::hours(1), DataAggregate::Count, None).await.unwrap();
+        assert_eq!(buckets[0].data, vec![U64(2), U64(2)]);
+        let number = resource.aggregate_data(device_id1, model_id, timestamp_1, timestamp_3, tag::HOURLY).await.unwrap();
+        assert_eq!(number, 3);
+        let aggregates = resource.list_data_by_time(device_id1, model_id, timestamp_hour, None).await.unwrap();
+        let data_avg = aggregates.iter().find(|x| x.tag == tag::HOURLY_AVG).unwrap();
+        let data_min = aggregates.iter().find(|x| x.tag == tag::HOURLY_MIN).unwrap();
+        let data_max = aggregates.iter().find(|x| x.tag == tag::HOURLY_MAX).unwrap();
+        assert_eq!(data_avg.data[0], F32(((speed1 as f64 + speed2 as f64) / 2.0) as f32));
+        assert_eq!(data_min.data[0], F32(speed1.min(speed2)));
+        assert_eq!(data_max.data[1], F32(direction1.max(direction2)));
+        // create retention rule of hourly average data then dry run and apply the retention rules
+        let retention_id = resource.create_retention(Some(model_id), None, Some(tag::HOURLY_AVG), Duration::days(1)).await.unwrap();
+        let retention = resource.read_retention(retention_id).await.unwrap();
+        assert_eq!(retention.tag, Some(tag::HOURLY_AVG));
+        assert_eq!(retention.retention, Duration::days(1));
+        resource.update_retention(retention_id, Duration::days(30)).await.unwrap();
+        let retentions = resource.list_retention_option(Some(model_id), None, None).await.unwrap();
+        assert_eq!(retentions[0].retention, Duration::days(30));
+        let counts = resource.apply_retention(true).await.unwrap();
+        assert_eq!(counts, vec![(retention_id, 1)]);
+        // data of hourly average is kept by the narrower rule instead of the model rule
+        let retention_model_id = resource.create_retention(Some(model_id), None, None, Duration::days(1)).await.unwrap();
+        let data_model = resource.list_data_group_by_range(&[device_id1, device_id2], &[model_id], timestamp_hour, timestamp_2, None).await.unwrap();
+        let counts = resource.apply_retention(true).await.unwrap();
+        assert_eq!(counts, vec![(retention_id, 1), (retention_model_id, data_model.iter().filter(|d| d.tag != tag::HOURLY_AVG).count())]);
+        resource.delete_retention(retention_model_id).await.unwrap();
+        let counts = resource.apply_retention(false).await.unwrap();
+        assert_eq!(counts, vec![(retention_id, 1)]);
+        let aggregates = resource.list_data_by_time(device_id1, model_id, timestamp_hour, None).await.unwrap();
+        assert!(aggregates.iter().all(|x| x.tag != tag::HOURLY_AVG));
+        resource.delete_retention(retention_id).await.unwrap();
+        resource.delete_data(device_id1, model_id, timestamp_3, None).await.unwrap();
+        for tag in [tag::HOURLY_AVG, tag::HOURLY_MIN, tag::HOURLY_MAX] {
+            resource.delete_data(device_id1, model_id, timestamp_hour, Some(tag)).await.unwrap();
+        }
+
+        // compression stats and policy
+        let stats = resource.read_compression_stats(CompressionTable::Data).await.unwrap();
+        assert_eq!(stats.table, "data");
+        let result = resource.set_compression_policy(CompressionTable::Data, Duration::zero()).await;
+        assert!(result.is_err());
+        resource.remove_compression_policy(CompressionTable::Buffer).await.unwrap();
+
+        // unlink buffer model from the device type then report the violations
+        resource.remove_type_model(type_id, model_buf_id).await.unwrap();
+        let violations = resource.list_integrity_violation(Some(IntegrityTable::Buffer)).await.unwrap();
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.table == "buffer" && v.model_id == model_buf_id && v.count > 0));
+        resource.add_type_model(type_id, model_buf_id).await.unwrap();
+        let violations = resource.list_integrity_violation(None).await.unwrap();
+        assert!(violations.is_empty());
+
+        // update data value and tag, rewrite a data field inside a range, then read the audit trail
+        resource.update_data(device_id1, model_id, timestamp_2, Some(tag::DEFAULT), Some(&[F32(speed2), F32(direction2)]), None).await.unwrap();
+        let data = resource.read_data(device_id1, model_id, timestamp_2, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(speed2), F32(direction2)]);
+        let number = resource.count_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, None).await.unwrap();
+        let update = DataRangeUpdate { device_id: device_id1, model_id, begin: timestamp_1, end: timestamp_2, tag: None, index: 1, value: F32(0.0) };
+        let count = resource.update_data_by_range(update.clone()).await.unwrap();
+        assert_eq!(count, number);
+        let data = resource.read_data(device_id1, model_id, timestamp_2, None).await.unwrap();
+        assert_eq!(data.data, vec![F32(speed2), F32(0.0)]);
+        resource.update_data(device_id1, model_id, timestamp_2, Some(tag::DEFAULT), None, Some(tag::ERROR)).await.unwrap();
+        let result = resource.update_data_by_range(DataRangeUpdate { index: 2, ..update }).await;
+        assert!(result.is_err());
+        let audits = resource.list_data_audit_by_range(device_id1, model_id, timestamp_2, timestamp_2).await.unwrap();
+        // the overwritten row of conflicting data creation is recorded too
+        assert_eq!(audits.len(), 4);
+        assert_eq!(audits[1].data, vec![F32(speed1), F32(direction1)]);
+        assert_eq!(audits[1].new_data, vec![F32(speed2), F32(direction2)]);
+        assert_eq!(audits[3].tag, tag::DEFAULT);
+        assert_eq!(audits[3].new_tag, tag::ERROR);
+
+        // count data to be deleted with dry run, reject empty id lists, then delete data of a device inside a range
+        let number = resource.count_data_group_by_range(&[device_id1, device_id2], &[model_id], timestamp_1, timestamp_2, None).await.unwrap();
+        let count = resource.delete_data_group_by_range(&[device_id1, device_id2], &[model_id], timestamp_1, timestamp_2, None, true).await.unwrap();
+        assert_eq!(count, number);
+        let result = resource.delete_data_group_by_range(&[], &[model_id], timestamp_1, timestamp_2, None, true).await;
+        assert!(result.is_err());
+        let number = resource.count_data_by_range(device_id2, model_id, timestamp_1, timestamp_2, None).await.unwrap();
+        let count = resource.delete_data_by_range(device_id2, model_id, timestamp_1, timestamp_2, None, false).await.unwrap();
+        assert_eq!(count, number);
+        let count = resource.count_data_by_range(device_id2, model_id, timestamp_1, timestamp_2, None).await.unwrap();
+        assert_eq!(count, 0);
+
+        // read data, data stream, and statistics of a slice then of a set slice
+        let slice_id = resource.create_slice(device_id1, model_id, timestamp_1, timestamp_2, "Data reading slice", "").await.unwrap();
+        let datas = resource.list_data_by_slice(slice_id, None).await.unwrap();
+        assert!(!datas.is_empty());
+        assert_eq!(datas, resource.list_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, None).await.unwrap());
+        let stream = resource.stream_data_by_slice(slice_id, None).await.unwrap();
+        let datas_stream: Vec<DataSchema> = stream.map(|d| d.unwrap()).collect().await;
+        assert_eq!(datas_stream, datas);
+        let stats = resource.stats_data_by_slice(slice_id, None).await.unwrap();
+        assert_eq!(stats, resource.stats_data_by_range(device_id1, model_id, timestamp_1, timestamp_2, None).await.unwrap());
+        assert_eq!(stats[0].count, datas.len());
+        resource.delete_slice(slice_id).await.unwrap();
+        let slice_set_id = resource.create_slice_set(set_id, timestamp_1, timestamp_2, "Multiple compass slice", "").await.unwrap();
+        let data_sets = resource.list_data_by_slice_set(slice_set_id, None).await.unwrap();
+        assert_eq!(data_sets, resource.list_data_set_by_range(set_id, timestamp_1, timestamp_2, None).await.unwrap());
+        let stats = resource.stats_data_by_slice_set(slice_set_id, None).await.unwrap();
+        assert!(!stats.is_empty());
+        assert!(stats.iter().all(|s| s.model_id == model_id));
+        resource.delete_slice_set(slice_set_id).await.unwrap();
 
         // delete data
         resource.delete_data(device_id1, model_id, timestamp_1, None).await.unwrap();
@@ -228,6 +546,91 @@ mod tests {
         assert_eq!(buffers[0].data, buffer.data);
         assert_eq!(buffer.tag, tag::DELETE);
 
+        // restrict buffer tag transitions then check the rejected transition and the tag history
+        resource.create_buffer_transition(tag::DELETE, tag::HOLD).await.unwrap();
+        resource.create_buffer_transition(tag::DELETE, tag::ERROR).await.unwrap();
+        let transitions = resource.list_buffer_transition(Some(tag::DELETE)).await.unwrap();
+        assert_eq!(transitions.len(), 2);
+        let result = resource.update_buffer(buffers[0].id, None, Some(tag::DEFAULT)).await;
+        assert!(result.is_err());
+        resource.update_buffer(buffers[0].id, None, Some(tag::HOLD)).await.unwrap();
+        let histories = resource.list_buffer_history(buffers[0].id).await.unwrap();
+        assert_eq!(histories.len(), 2);
+        assert_eq!((histories[0].tag, histories[0].new_tag), (buffers[0].tag, tag::DELETE));
+        assert_eq!((histories[1].tag, histories[1].new_tag), (tag::DELETE, tag::HOLD));
+        resource.delete_buffer_transition(tag::DELETE, tag::HOLD).await.unwrap();
+        resource.delete_buffer_transition(tag::DELETE, tag::ERROR).await.unwrap();
+
+        // count buffers to be deleted with dry run then delete buffers of a device inside a range
+        let number = resource.count_buffer_group_by_range(&[device_id1, device_id2], &[model_buf_id], timestamp_1, timestamp_2, None).await.unwrap();
+        let count = resource.delete_buffer_group_by_range(&[device_id1, device_id2], &[model_buf_id], timestamp_1, timestamp_2, None, true).await.unwrap();
+        assert_eq!(count, number);
+        let count = resource.delete_buffer_by_range(device_id2, model_buf_id, timestamp_1, timestamp_2, None, false).await.unwrap();
+        assert_eq!(count, 2);
+
+        // transfer buffers to data by ids and by range then check a conflicting transfer is rolled back
+        let count = resource.transfer_buffer_by_ids(&[buffers[2].id], None).await.unwrap();
+        assert_eq!(count, 1);
+        let data = resource.read_data(device_id1, model_buf_id, timestamp_2, None).await.unwrap();
+        assert_eq!(data.data, buffers[2].data);
+        assert_eq!(data.tag, buffers[2].tag);
+        let count = resource.transfer_buffer_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, None, Some(tag::DEFAULT)).await.unwrap();
+        assert_eq!(count, 1);
+        let data = resource.read_data(device_id1, model_buf_id, timestamp_1, None).await.unwrap();
+        assert_eq!(data.data, buffers[0].data);
+        assert_eq!(data.tag, tag::DEFAULT);
+        let result = resource.read_buffer(buffers[0].id).await;
+        assert!(result.is_err());
+        let (buffer_id, _) = resource.create_buffer(device_id1, model_buf_id, timestamp_1, &buffers[0].data, None).await.unwrap();
+        let result = resource.transfer_buffer_by_ids(&[buffer_id], None).await;
+        assert!(result.is_err());
+        resource.read_buffer(buffer_id).await.unwrap();
+        resource.delete_buffer(buffer_id).await.unwrap();
+        let count = resource.delete_data_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, None, false).await.unwrap();
+        assert_eq!(count, 2);
+
+        // claim buffers as a work queue then acknowledge, reject, and reclaim buffers with expired lease
+        resource.create_buffer_transition(tag::HOLD, tag::DELETE).await.unwrap();
+        resource.create_buffer_transition(tag::HOLD, tag::ERROR).await.unwrap();
+        resource.create_buffer(device_id1, model_buf_id, timestamp_1, &buffers[0].data, Some(tag::HOLD)).await.unwrap();
+        resource.create_buffer(device_id1, model_buf_id, timestamp_2, &buffers[0].data, Some(tag::HOLD)).await.unwrap();
+        resource.create_buffer(device_id1, model_buf_id, timestamp_1, &buffers[0].data, Some(tag::SEND_UPLINK)).await.unwrap();
+        let claims_1 = resource.claim_buffer(1, tag::HOLD, Duration::seconds(60), "worker 1").await.unwrap();
+        assert_eq!(claims_1.len(), 1);
+        assert_eq!(claims_1[0].timestamp, timestamp_1);
+        let claims_2 = resource.claim_buffer(5, tag::HOLD, Duration::seconds(60), "worker 2").await.unwrap();
+        assert_eq!(claims_2.len(), 1);
+        assert_eq!(claims_2[0].timestamp, timestamp_2);
+        let claims = resource.claim_buffer(5, tag::HOLD, Duration::seconds(60), "worker 3").await.unwrap();
+        assert!(claims.is_empty());
+        let result = resource.claim_buffer(5, tag::SEND_UPLINK, Duration::zero(), "worker 3").await;
+        assert!(result.is_err());
+        let claims = resource.claim_buffer(5, tag::SEND_UPLINK, Duration::milliseconds(1), "worker 3").await.unwrap();
+        assert_eq!(claims.len(), 1);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let claims_3 = resource.claim_buffer(5, tag::SEND_UPLINK, Duration::seconds(60), "worker 4").await.unwrap();
+        assert_eq!(claims_3.len(), 1);
+        assert_eq!(claims_3[0].id, claims[0].id);
+        let (count, unreleased) = resource.ack_buffer(&[claims_1[0].id], "worker 2", tag::DELETE).await.unwrap();
+        assert_eq!(count, 0);
+        assert_eq!(unreleased, vec![claims_1[0].id]);
+        let (count, unreleased) = resource.ack_buffer(&[claims_1[0].id], "worker 1", tag::DELETE).await.unwrap();
+        assert_eq!(count, 1);
+        assert!(unreleased.is_empty());
+        let buffer = resource.read_buffer(claims_1[0].id).await.unwrap();
+        assert_eq!(buffer.tag, tag::DELETE);
+        let (count, unreleased) = resource.nack_buffer(&[claims_2[0].id, claims_1[0].id], "worker 2").await.unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(unreleased, vec![claims_1[0].id]);
+        let buffer = resource.read_buffer(claims_2[0].id).await.unwrap();
+        assert_eq!(buffer.tag, tag::ERROR);
+        let histories = resource.list_buffer_history(claims_2[0].id).await.unwrap();
+        assert_eq!(histories[0].changed_by, "worker 2");
+        resource.delete_buffer_transition(tag::HOLD, tag::DELETE).await.unwrap();
+        resource.delete_buffer_transition(tag::HOLD, tag::ERROR).await.unwrap();
+        let count = resource.delete_buffer_by_range(device_id1, model_buf_id, timestamp_1, timestamp_2, None, false).await.unwrap();
+        assert_eq!(count, 3);
+
         // delete buffer data
         resource.delete_buffer(buffers[0].id).await.unwrap();
         resource.delete_buffer(buffers[1].id).await.unwrap();
@@ -254,6 +657,68 @@ mod tests {
         let result = resource.read_slice(slice_id).await;
         assert!(result.is_err());
 
+        // create a slice rule then generate slices over a range and continuously from the rule checkpoint
+        let timestamp_6: DateTime<Utc> = DateTime::parse_from_str("2022-02-03 04:05:06.000000 +0000", "%Y-%m-%d %H:%M:%S.%6f %z").unwrap().into();
+        let speeds = [1.0, 5.0, 6.0, 2.0, 7.0, 8.0, 9.0, 1.0, 1.0];
+        let timestamps: Vec<DateTime<Utc>> = (0..speeds.len()).map(|i| timestamp_6 + Duration::seconds(i as i64 * 10)).collect();
+        let values: Vec<Vec<DataValue>> = speeds.iter().map(|&s| vec![F32(s), F32(direction1)]).collect();
+        let rows: Vec<&[DataValue]> = values.iter().map(|v| v.as_slice()).collect();
+        resource.create_data_multiple(&[device_id1; 9], &[model_id; 9], &timestamps, &rows, None).await.unwrap();
+        let mut rule = SliceRuleCreate {
+            device_id: device_id1,
+            model_id,
+            index: 2,
+            condition: SliceCondition::Greater,
+            threshold: 4.0,
+            min_duration: Duration::seconds(15),
+            max_gap: Duration::zero(),
+            name: "Speed high".to_owned()
+        };
+        let result = resource.create_slice_rule(&rule).await;
+        assert!(result.is_err());
+        rule.index = 0;
+        let rule_id = resource.create_slice_rule(&rule).await.unwrap();
+        let rules = resource.list_slice_rule_option(Some(device_id1), None).await.unwrap();
+        assert_eq!(rules[0].id, rule_id);
+        assert_eq!(rules[0].condition, SliceCondition::Greater);
+        assert_eq!(rules[0].checkpoint, None);
+        // a run shorter than minimum duration is dropped and dry run doesn't create slices
+        let slices = resource.generate_slice_by_range(rule_id, timestamps[0], timestamps[8], true).await.unwrap();
+        assert_eq!(slices.len(), 1);
+        assert_eq!((slices[0].timestamp_begin, slices[0].timestamp_end), (timestamps[4], timestamps[6]));
+        let slices = resource.list_slice_by_range(device_id1, model_id, timestamps[0], timestamps[8]).await.unwrap();
+        assert!(slices.is_empty());
+        // runs separated by unmatched data within maximum gap are merged and generated once
+        resource.update_slice_rule(rule_id, None, None, None, Some(Duration::seconds(25)), None).await.unwrap();
+        let slices = resource.generate_slice_by_range(rule_id, timestamps[0], timestamps[8], false).await.unwrap();
+        assert_eq!(slices.len(), 1);
+        assert_eq!((slices[0].timestamp_begin, slices[0].timestamp_end), (timestamps[1], timestamps[6]));
+        let slice = resource.read_slice(slices[0].id).await.unwrap();
+        assert!(slice.name.starts_with("Speed high"));
+        let slices = resource.generate_slice_by_range(rule_id, timestamps[0], timestamps[8], false).await.unwrap();
+        assert!(slices.is_empty());
+        resource.delete_slice(slice.id).await.unwrap();
+        // continuous generation waits until a run is closed by unmatched data beyond maximum gap
+        let results = resource.apply_slice_rule(false).await.unwrap();
+        assert_eq!(results, vec![(rule_id, 0)]);
+        let rule = resource.read_slice_rule(rule_id).await.unwrap();
+        assert_eq!(rule.checkpoint, Some(timestamps[1]));
+        let timestamp_7 = timestamps[8] + Duration::seconds(10);
+        resource.create_data(device_id1, model_id, timestamp_7, &[F32(1.0), F32(direction1)], None).await.unwrap();
+        let results = resource.apply_slice_rule(false).await.unwrap();
+        assert_eq!(results, vec![(rule_id, 1)]);
+        let slices = resource.list_slice_by_range(device_id1, model_id, timestamps[0], timestamp_7).await.unwrap();
+        assert_eq!(slices.len(), 1);
+        assert_eq!((slices[0].timestamp_begin, slices[0].timestamp_end), (timestamps[1], timestamps[6]));
+        let rule = resource.read_slice_rule(rule_id).await.unwrap();
+        assert_eq!(rule.checkpoint, Some(timestamp_7 + Duration::microseconds(1)));
+        resource.delete_slice(slices[0].id).await.unwrap();
+        resource.delete_data_by_range(device_id1, model_id, timestamps[0], timestamp_7, None, false).await.unwrap();
+        // delete slice rule
+        resource.delete_slice_rule(rule_id).await.unwrap();
+        let result = resource.read_slice_rule(rule_id).await;
+        assert!(result.is_err());
+
         // delete model config
         let config_id = model_configs.iter().next().map(|el| el.id).unwrap();
         resource.delete_model_config(config_id).await.unwrap();
@@ -32,7 +32,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ("../proto/bbthings_grpc/proto/resource/set.proto", "set_descriptor.bin"),
         ("../proto/bbthings_grpc/proto/resource/data.proto", "data_descriptor.bin"),
         ("../proto/bbthings_grpc/proto/resource/buffer.proto", "buffer_descriptor.bin"),
-        ("../proto/bbthings_grpc/proto/resource/slice.proto", "slice_descriptor.bin")
+        ("../proto/bbthings_grpc/proto/resource/slice.proto", "slice_descriptor.bin"),
+        ("../proto/bbthings_grpc/proto/resource/aggregate.proto", "aggregate_descriptor.bin"),
+        ("../proto/bbthings_grpc/proto/resource/retention.proto", "retention_descriptor.bin"),
+        ("../proto/bbthings_grpc/proto/resource/compression.proto", "compression_descriptor.bin")
     ];
 
     for tuple in proto_files {
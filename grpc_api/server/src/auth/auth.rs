@@ -145,7 +145,7 @@ impl AuthService for AuthServer {
                         let generate = iter_tokens.next().unwrap_or_default();
                         AccessTokenMap {
                             api_id: e.api_id.as_bytes().to_vec(),
-                            access_token: token::generate_token(generate.0, &e.role, e.access_duration, &e.access_key)
+                            access_token: token::generate_token(generate.0, &e.role, &user.id.to_string(), e.access_duration, &e.access_key)
                                 .unwrap_or(String::new()),
                             refresh_token: generate.1
                         }
@@ -208,7 +208,7 @@ impl AuthService for AuthServer {
                         .update_access_token(token_claims.jti, Some(token.expired), None).await
                         .map_err(|e| handle_error(e))?;
                     let duration = (token_claims.exp - token_claims.iat) as i32;
-                    let access_token = token::generate_token(token_claims.jti, &token_claims.sub, duration, &access_key)
+                    let access_token = token::generate_token(token_claims.jti, &token_claims.sub, &token.user_id.to_string(), duration, &access_key)
                         .ok_or_else(|| Status::internal(GENERATE_TOKEN_ERR))?;
                     (refresh_token, access_token)
                 } else {
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+use bbthings_database::{DataSchema, DataType, ArrayDataValue};
+
+// cached data expires so data changed outside of the resource server is read back from database
+const LATEST_CACHE_TTL: Duration = Duration::from_secs(60);
+// the earliest cached pairs are evicted when the number of cached pairs exceeds the capacity
+const LATEST_CACHE_CAPACITY: usize = 10_000;
+// requests of more device and model pairs are read from database without walking the cache
+const LATEST_CACHE_PAIR_MAX: usize = 1_000;
+
+// latest data of a device and model pair with its cached time, a pair without data is not cached
+type LatestMap = HashMap<(Uuid, Uuid), (DataSchema, Instant)>;
+
+#[derive(Debug, Clone, Default)]
+pub struct DataLatestCache {
+    latest: Arc<RwLock<LatestMap>>
+}
+
+impl DataLatestCache {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, device_ids: &[Uuid], model_ids: &[Uuid]) -> Option<Vec<DataSchema>>
+    {
+        // requested pairs can only be listed when both device and model ids are given
+        if device_ids.is_empty() || model_ids.is_empty() || device_ids.len() * model_ids.len() > LATEST_CACHE_PAIR_MAX {
+            return None;
+        }
+        // every requested pair must be cached and not expired otherwise all pairs are read from database
+        let latest = self.latest.read().unwrap();
+        let now = Instant::now();
+        let mut results = Vec::new();
+        for &device_id in device_ids {
+            for &model_id in model_ids {
+                match latest.get(&(device_id, model_id)) {
+                    Some((data, cached)) if now.duration_since(*cached) < LATEST_CACHE_TTL => results.push(data.clone()),
+                    _ => return None
+                }
+            }
+        }
+        results.sort_by_key(|d| (d.device_id, d.model_id));
+        results.dedup_by_key(|d| (d.device_id, d.model_id));
+        Some(results)
+    }
+
+    pub fn set(&self, data: &[DataSchema])
+    {
+        let mut latest = self.latest.write().unwrap();
+        let now = Instant::now();
+        for d in data {
+            latest.insert((d.device_id, d.model_id), (d.clone(), now));
+        }
+        Self::evict(&mut latest, now);
+    }
+
+    pub fn update(&self, data: &DataSchema)
+    {
+        let mut latest = self.latest.write().unwrap();
+        let key = (data.device_id, data.model_id);
+        let now = Instant::now();
+        // only cached pairs are updated because a pair which is not cached may have later data in database
+        let cached = match latest.get(&key) {
+            Some((cached, time)) if now.duration_since(*time) < LATEST_CACHE_TTL => cached,
+            Some(_) => {
+                latest.remove(&key);
+                return;
+            },
+            None => return
+        };
+        if cached.timestamp > data.timestamp {
+            return;
+        }
+        // convert inserted values to the types of stored values
+        let types: Vec<DataType> = cached.data.iter().map(|v| v.get_type()).collect();
+        match ArrayDataValue::from_vec(&data.data).validate(&types) {
+            Ok(values) => latest.insert(key, (DataSchema { data: values.to_vec(), ..data.clone() }, now)),
+            Err(_) => latest.remove(&key)
+        };
+    }
+
+    pub fn invalidate(&self, device_ids: &[Uuid], model_ids: &[Uuid])
+    {
+        let mut latest = self.latest.write().unwrap();
+        latest.retain(|(device_id, model_id), _| {
+            !((device_ids.is_empty() || device_ids.contains(device_id)) && (model_ids.is_empty() || model_ids.contains(model_id)))
+        });
+    }
+
+    pub fn clear(&self)
+    {
+        self.latest.write().unwrap().clear();
+    }
+
+    fn evict(latest: &mut LatestMap, now: Instant)
+    {
+        if latest.len() <= LATEST_CACHE_CAPACITY {
+            return;
+        }
+        latest.retain(|_, (_, cached)| now.duration_since(*cached) < LATEST_CACHE_TTL);
+        if latest.len() <= LATEST_CACHE_CAPACITY {
+            return;
+        }
+        // evict down to three quarters of the capacity so the eviction doesn't run on every insertion
+        let number = latest.len() - LATEST_CACHE_CAPACITY * 3 / 4;
+        let mut cached: Vec<((Uuid, Uuid), Instant)> = latest.iter().map(|(key, (_, time))| (*key, *time)).collect();
+        cached.sort_by_key(|(_, time)| *time);
+        for (key, _) in cached.into_iter().take(number) {
+            latest.remove(&key);
+        }
+    }
+
+}
@@ -6,17 +6,20 @@ use jsonwebtoken::{encode, decode, DecodingKey, EncodingKey, Header, Algorithm,
 pub struct TokenClaims {
     pub jti: i32,
     pub sub: String,
+    #[serde(default)]
+    pub uid: String,
     pub iat: i64,
     pub exp: i64,
 }
 
-pub(crate) fn generate_token(jti: i32, sub: &str, duration: i32, key: &[u8]) -> Option<String>
+pub(crate) fn generate_token(jti: i32, sub: &str, uid: &str, duration: i32, key: &[u8]) -> Option<String>
 {
     let iat = Utc::now().timestamp();
     let exp = iat + duration as i64;
     let claims = TokenClaims {
         jti,
         sub: sub.to_owned(),
+        uid: uid.to_owned(),
         iat,
         exp
     };
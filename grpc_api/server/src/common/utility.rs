@@ -3,10 +3,16 @@ use rsa::{RsaPrivateKey, RsaPublicKey, Oaep};
 use pkcs8::{DecodePublicKey, EncodePublicKey};
 use rand::thread_rng;
 use tonic::Status;
+use bbthings_database::{Cursor, Page};
+use tokio::sync::mpsc;
+use tokio_stream::{Stream, StreamExt};
+use tokio_stream::wrappers::ReceiverStream;
 pub use bbthings_database::utility::{generate_access_key, generate_token_string, hash_password, verify_password};
 
 const ENCRYPT_ERR: &str = "encrypt message error";
 const DECRYPT_ERR: &str = "decrypt password error";
+pub(crate) const STREAM_BATCH_SIZE: usize = 1000;
+const CURSOR_ERR: &str = "invalid page cursor";
 
 pub(crate) fn generate_transport_keys() -> Result<(RsaPrivateKey, RsaPublicKey), rsa::Error>
 {
@@ -71,6 +77,50 @@ pub(crate) fn handle_error(e: sqlx::Error) -> tonic::Status {
     }
 }
 
+pub(crate) fn decode_page(cursor: Option<Vec<u8>>, limit: u32) -> Result<Page, Status>
+{
+    let cursor = match cursor {
+        Some(bytes) => Some(Cursor::from_bytes(&bytes).ok_or(Status::invalid_argument(CURSOR_ERR))?),
+        None => None
+    };
+    Ok(Page::new(cursor, limit as usize))
+}
+
+pub(crate) fn stream_batch<S, T, U, R, F>(stream: S, response: F) -> ReceiverStream<Result<R, Status>>
+where
+    S: Stream<Item = Result<T, sqlx::Error>> + Send + 'static,
+    T: Into<U> + Send,
+    U: Send + 'static,
+    R: Send + 'static,
+    F: Fn(Vec<U>) -> R + Send + 'static
+{
+    let (tx, rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let mut stream = std::pin::pin!(stream);
+        let mut batch = Vec::with_capacity(STREAM_BATCH_SIZE);
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(value) => batch.push(value.into()),
+                Err(e) => {
+                    let _ = tx.send(Err(handle_error(e))).await;
+                    return;
+                }
+            }
+            // send a message every batch size rows and stop when the client is disconnected
+            if batch.len() >= STREAM_BATCH_SIZE {
+                let results = std::mem::replace(&mut batch, Vec::with_capacity(STREAM_BATCH_SIZE));
+                if tx.send(Ok(response(results))).await.is_err() {
+                    return;
+                }
+            }
+        }
+        if !batch.is_empty() {
+            let _ = tx.send(Ok(response(batch))).await;
+        }
+    });
+    ReceiverStream::new(rx)
+}
+
 pub fn hex_to_bytes(s: &str) -> Option<Vec<u8>> {
     if s.len() % 2 != 0 {
         return None;
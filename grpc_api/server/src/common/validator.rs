@@ -1,7 +1,7 @@
 use tonic::{Status, Extensions};
 use uuid::Uuid;
 use chrono::Utc;
-use super::token::decode_token;
+use super::token::{decode_token, TokenClaims};
 use super::config::{ROOT_ID, ROOT_NAME, ROOT_DATA};
 use bbthings_database::Auth;
 use crate::proto::auth::auth::ProcedureMap;
@@ -42,6 +42,26 @@ pub(crate) trait AccessValidator {
         .collect()
     }
 
+    fn decode_claims(&self, token: &str) -> Option<TokenClaims>
+    {
+        // decode token using api access key or root key
+        let decoded = decode_token(token, &self.token_key(), true);
+        if decoded.is_none() {
+            let root = ROOT_DATA.get().map(|x| x.to_owned()).unwrap_or_default();
+            return decode_token(token, &root.access_key, true);
+        }
+        decoded
+    }
+
+    fn token_user(&self, extension: &Extensions) -> String
+    {
+        // user id is signed into the token on login and refresh
+        extension.get::<String>()
+            .and_then(|token| self.decode_claims(token))
+            .map(|claims| claims.uid)
+            .unwrap_or_default()
+    }
+
     fn validate(&self, extension: &Extensions, procedure: &str) -> Result<(), Status>
     {
         // return ok if service doesn't configured to use validation
@@ -51,12 +71,7 @@ pub(crate) trait AccessValidator {
         // try to decode token from request extension using api accees key or root key and then get token claims
         let token = extension.get::<String>()
             .ok_or(Status::unauthenticated(EXT_NOT_FOUND))?;
-        let mut decoded = decode_token(token, &self.token_key(), true);
-        if decoded.is_none() {
-            let root = ROOT_DATA.get().map(|x| x.to_owned()).unwrap_or_default();
-            decoded = decode_token(token, &root.access_key, true);
-        }
-        let claims = match decoded {
+        let claims = match self.decode_claims(token) {
             Some(value) => value,
             None => return Err(Status::unauthenticated(TOKEN_EXPIRED))
         };
@@ -17,6 +17,9 @@ pub mod resource {
     pub mod data;
     pub mod buffer;
     pub mod slice;
+    pub mod aggregate;
+    pub mod retention;
+    pub mod compression;
 }
 pub mod common {
     pub mod utility;
@@ -24,6 +27,7 @@ pub mod common {
     pub mod token;
     pub mod validator;
     pub mod interceptor;
+    pub mod cache;
     pub mod test;
 }
 pub use common::utility;
@@ -46,6 +50,9 @@ pub mod proto {
         pub mod data;
         pub mod buffer;
         pub mod slice;
+        pub mod aggregate;
+        pub mod retention;
+        pub mod compression;
     }
     pub mod descriptor;
 }
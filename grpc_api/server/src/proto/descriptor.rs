@@ -68,3 +68,18 @@ pub mod slice {
     pub const DESCRIPTOR_SET: &[u8] =
         tonic::include_file_descriptor_set!("slice_descriptor");
 }
+
+pub mod aggregate {
+    pub const DESCRIPTOR_SET: &[u8] =
+        tonic::include_file_descriptor_set!("aggregate_descriptor");
+}
+
+pub mod retention {
+    pub const DESCRIPTOR_SET: &[u8] =
+        tonic::include_file_descriptor_set!("retention_descriptor");
+}
+
+pub mod compression {
+    pub const DESCRIPTOR_SET: &[u8] =
+        tonic::include_file_descriptor_set!("compression_descriptor");
+}
@@ -20,6 +20,8 @@ pub struct BufferSchema {
 pub struct BufferMultipleSchema {
     #[prost(message, repeated, tag = "1")]
     pub schemas: ::prost::alloc::vec::Vec<BufferSchema>,
+    #[prost(string, optional, tag = "2")]
+    pub conflict: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferId {
@@ -78,6 +80,21 @@ pub struct BufferRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferRangeDelete {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferNumber {
     #[prost(bytes = "vec", tag = "1")]
     pub device_id: ::prost::alloc::vec::Vec<u8>,
@@ -174,6 +191,92 @@ pub struct BufferGroupRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferGroupRangeDelete {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferTransfer {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, optional, tag = "4")]
+    pub begin: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub end: ::core::option::Option<i64>,
+    #[prost(int32, optional, tag = "6")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "7")]
+    pub target_tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferClaim {
+    #[prost(uint32, tag = "1")]
+    pub number: u32,
+    #[prost(int32, tag = "2")]
+    pub tag: i32,
+    #[prost(int64, tag = "3")]
+    pub lease: i64,
+    #[prost(string, tag = "4")]
+    pub worker_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferAck {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, tag = "2")]
+    pub worker_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub tag: i32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferNack {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, tag = "2")]
+    pub worker_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferTransitionSchema {
+    #[prost(int32, tag = "1")]
+    pub tag: i32,
+    #[prost(int32, tag = "2")]
+    pub new_tag: i32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferTransitionTag {
+    #[prost(int32, optional, tag = "1")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferHistorySchema {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(int32, tag = "2")]
+    pub buffer_id: i32,
+    #[prost(int32, tag = "3")]
+    pub tag: i32,
+    #[prost(int32, tag = "4")]
+    pub new_tag: i32,
+    #[prost(string, tag = "5")]
+    pub changed_by: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub changed_at: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferGroupNumber {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -187,6 +290,40 @@ pub struct BufferGroupNumber {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferRangePage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferGroupRangePage {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferGroupSelector {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -251,6 +388,17 @@ pub struct BufferSetRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferSetNumber {
+    #[prost(bytes = "vec", tag = "1")]
+    pub set_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+    #[prost(uint32, tag = "3")]
+    pub number: u32,
+    #[prost(int32, optional, tag = "4")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferUpdate {
     #[prost(int32, tag = "1")]
     pub id: i32,
@@ -286,6 +434,13 @@ pub struct BufferListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<BufferSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BufferPageResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BufferSchema>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferSetReadResponse {
     #[prost(message, optional, tag = "1")]
@@ -300,14 +455,63 @@ pub struct BufferSetListResponse {
 pub struct BufferCreateResponse {
     #[prost(int32, tag = "1")]
     pub id: i32,
+    #[prost(uint32, tag = "2")]
+    pub violated: u32,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferCreateMultipleResponse {
     #[prost(int32, repeated, tag = "1")]
     pub ids: ::prost::alloc::vec::Vec<i32>,
+    #[prost(uint32, tag = "2")]
+    pub inserted: u32,
+    #[prost(uint32, tag = "3")]
+    pub skipped: u32,
+    #[prost(uint32, tag = "4")]
+    pub overwritten: u32,
+    #[prost(uint32, tag = "5")]
+    pub violated: u32,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BufferChangeResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BufferTransitionListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BufferTransitionSchema>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BufferHistoryListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BufferHistorySchema>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferIngestBatch {
+    #[prost(uint32, tag = "1")]
+    pub batch: u32,
+    #[prost(uint32, tag = "2")]
+    pub count: u32,
+    #[prost(uint32, tag = "3")]
+    pub rejected: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferIngestReject {
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BufferIngestResponse {
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub batches: ::prost::alloc::vec::Vec<BufferIngestBatch>,
+    #[prost(message, repeated, tag = "3")]
+    pub rejects: ::prost::alloc::vec::Vec<BufferIngestReject>,
+    #[prost(string, optional, tag = "4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "5")]
+    pub violated: u64,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct TimestampReadResponse {
     #[prost(int64, tag = "1")]
@@ -323,6 +527,13 @@ pub struct BufferCountResponse {
     #[prost(uint32, tag = "1")]
     pub count: u32,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BufferReleaseResponse {
+    #[prost(uint32, tag = "1")]
+    pub count: u32,
+    #[prost(int32, repeated, tag = "2")]
+    pub unreleased_ids: ::prost::alloc::vec::Vec<i32>,
+}
 /// Generated client implementations.
 pub mod buffer_service_client {
     #![allow(
@@ -884,6 +1095,58 @@ pub mod buffer_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_buffer_by_range_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferPageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferByRangePage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferByRangePage"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_buffer_group_by_range_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferPageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferGroupByRangePage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferGroupByRangePage"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn list_buffer_group_by_number_before(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferGroupNumber>,
@@ -942,6 +1205,56 @@ pub mod buffer_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn stream_buffer_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferRange>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BufferListResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/StreamBufferByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "StreamBufferByRange"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn stream_buffer_group_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::BufferListResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/StreamBufferGroupByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "StreamBufferGroupByRange"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         pub async fn read_buffer_group_first(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferGroupSelector>,
@@ -1212,11 +1525,11 @@ pub mod buffer_service_client {
                 .insert(GrpcMethod::new("buffer.BufferService", "ListBufferSetByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn create_buffer(
+        pub async fn list_buffer_set_by_number_before(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferSchema>,
+            request: impl tonic::IntoRequest<super::BufferSetNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCreateResponse>,
+            tonic::Response<super::BufferSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1229,18 +1542,23 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CreateBuffer",
+                "/buffer.BufferService/ListBufferSetByNumberBefore",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CreateBuffer"));
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferSetByNumberBefore",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn create_buffer_multiple(
+        pub async fn list_buffer_set_by_number_after(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferMultipleSchema>,
+            request: impl tonic::IntoRequest<super::BufferSetNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCreateMultipleResponse>,
+            tonic::Response<super::BufferSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1253,18 +1571,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CreateBufferMultiple",
+                "/buffer.BufferService/ListBufferSetByNumberAfter",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CreateBufferMultiple"));
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferSetByNumberAfter"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_buffer(
+        pub async fn create_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferUpdate>,
+            request: impl tonic::IntoRequest<super::BufferSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferChangeResponse>,
+            tonic::Response<super::BufferCreateResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1277,18 +1597,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/UpdateBuffer",
+                "/buffer.BufferService/CreateBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "UpdateBuffer"));
+                .insert(GrpcMethod::new("buffer.BufferService", "CreateBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_buffer_by_time(
+        pub async fn create_buffer_multiple(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferUpdateTime>,
+            request: impl tonic::IntoRequest<super::BufferMultipleSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferChangeResponse>,
+            tonic::Response<super::BufferCreateMultipleResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1301,18 +1621,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/UpdateBufferByTime",
+                "/buffer.BufferService/CreateBufferMultiple",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "UpdateBufferByTime"));
+                .insert(GrpcMethod::new("buffer.BufferService", "CreateBufferMultiple"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_buffer(
+        pub async fn create_buffer_set(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferId>,
+            request: impl tonic::IntoRequest<super::BufferSetSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferChangeResponse>,
+            tonic::Response<super::BufferCreateMultipleResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1325,18 +1645,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/DeleteBuffer",
+                "/buffer.BufferService/CreateBufferSet",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "DeleteBuffer"));
+                .insert(GrpcMethod::new("buffer.BufferService", "CreateBufferSet"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_buffer_by_time(
+        pub async fn ingest_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferTime>,
+            request: impl tonic::IntoStreamingRequest<Message = super::BufferSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferChangeResponse>,
+            tonic::Response<super::BufferIngestResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1349,18 +1669,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/DeleteBufferByTime",
+                "/buffer.BufferService/IngestBuffer",
             );
-            let mut req = request.into_request();
+            let mut req = request.into_streaming_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "DeleteBufferByTime"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("buffer.BufferService", "IngestBuffer"));
+            self.inner.client_streaming(req, path, codec).await
         }
-        pub async fn read_buffer_timestamp(
+        pub async fn update_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferTime>,
+            request: impl tonic::IntoRequest<super::BufferUpdate>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1373,18 +1693,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ReadBufferTimestamp",
+                "/buffer.BufferService/UpdateBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "ReadBufferTimestamp"));
+                .insert(GrpcMethod::new("buffer.BufferService", "UpdateBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_timestamp_by_earlier(
+        pub async fn update_buffer_by_time(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferEarlier>,
+            request: impl tonic::IntoRequest<super::BufferUpdateTime>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1397,23 +1717,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferTimestampByEarlier",
+                "/buffer.BufferService/UpdateBufferByTime",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferTimestampByEarlier",
-                    ),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "UpdateBufferByTime"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_timestamp_by_later(
+        pub async fn delete_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferLater>,
+            request: impl tonic::IntoRequest<super::BufferId>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1426,20 +1741,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferTimestampByLater",
+                "/buffer.BufferService/DeleteBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampByLater"),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "DeleteBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_timestamp_by_range(
+        pub async fn delete_buffer_by_time(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferRange>,
+            request: impl tonic::IntoRequest<super::BufferTime>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1452,20 +1765,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferTimestampByRange",
+                "/buffer.BufferService/DeleteBufferByTime",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampByRange"),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "DeleteBufferByTime"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_timestamp_first(
+        pub async fn delete_buffer_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::BuffersSelector>,
+            request: impl tonic::IntoRequest<super::BufferRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1478,20 +1789,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferTimestampFirst",
+                "/buffer.BufferService/DeleteBufferByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampFirst"),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "DeleteBufferByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_timestamp_last(
+        pub async fn delete_buffer_group_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::BuffersSelector>,
+            request: impl tonic::IntoRequest<super::BufferGroupRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1504,20 +1813,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferTimestampLast",
+                "/buffer.BufferService/DeleteBufferGroupByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampLast"),
+                    GrpcMethod::new("buffer.BufferService", "DeleteBufferGroupByRange"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn read_buffer_group_timestamp(
+        pub async fn transfer_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupTime>,
+            request: impl tonic::IntoRequest<super::BufferTransfer>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1530,20 +1839,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ReadBufferGroupTimestamp",
+                "/buffer.BufferService/TransferBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("buffer.BufferService", "ReadBufferGroupTimestamp"),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "TransferBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_group_timestamp_by_earlier(
+        pub async fn claim_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupEarlier>,
+            request: impl tonic::IntoRequest<super::BufferClaim>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1556,23 +1863,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferGroupTimestampByEarlier",
+                "/buffer.BufferService/ClaimBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferGroupTimestampByEarlier",
-                    ),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "ClaimBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_group_timestamp_by_later(
+        pub async fn ack_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupLater>,
+            request: impl tonic::IntoRequest<super::BufferAck>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferReleaseResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1585,23 +1887,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferGroupTimestampByLater",
+                "/buffer.BufferService/AckBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferGroupTimestampByLater",
-                    ),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "AckBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_group_timestamp_by_range(
+        pub async fn nack_buffer(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupRange>,
+            request: impl tonic::IntoRequest<super::BufferNack>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferReleaseResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1614,23 +1911,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferGroupTimestampByRange",
+                "/buffer.BufferService/NackBuffer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferGroupTimestampByRange",
-                    ),
-                );
+                .insert(GrpcMethod::new("buffer.BufferService", "NackBuffer"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_group_timestamp_first(
+        pub async fn list_buffer_transition(
             &mut self,
-            request: impl tonic::IntoRequest<super::BuffersGroupSelector>,
+            request: impl tonic::IntoRequest<super::BufferTransitionTag>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferTransitionListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1643,23 +1935,44 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferGroupTimestampFirst",
+                "/buffer.BufferService/ListBufferTransition",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "ListBufferTransition"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_buffer_transition(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferTransitionSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferChangeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CreateBufferTransition",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferGroupTimestampFirst",
-                    ),
+                    GrpcMethod::new("buffer.BufferService", "CreateBufferTransition"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_buffer_group_timestamp_last(
+        pub async fn delete_buffer_transition(
             &mut self,
-            request: impl tonic::IntoRequest<super::BuffersGroupSelector>,
+            request: impl tonic::IntoRequest<super::BufferTransitionSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1672,23 +1985,44 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/ListBufferGroupTimestampLast",
+                "/buffer.BufferService/DeleteBufferTransition",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "buffer.BufferService",
-                        "ListBufferGroupTimestampLast",
-                    ),
+                    GrpcMethod::new("buffer.BufferService", "DeleteBufferTransition"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer(
+        pub async fn list_buffer_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferId>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferHistoryListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "ListBufferHistory"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn read_buffer_timestamp(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferTime>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampReadResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1701,18 +2035,18 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBuffer",
+                "/buffer.BufferService/ReadBufferTimestamp",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CountBuffer"));
+                .insert(GrpcMethod::new("buffer.BufferService", "ReadBufferTimestamp"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_by_earlier(
+        pub async fn list_buffer_timestamp_by_earlier(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1725,18 +2059,23 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferByEarlier",
+                "/buffer.BufferService/ListBufferTimestampByEarlier",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByEarlier"));
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferTimestampByEarlier",
+                    ),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_by_later(
+        pub async fn list_buffer_timestamp_by_later(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferLater>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1749,18 +2088,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferByLater",
+                "/buffer.BufferService/ListBufferTimestampByLater",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByLater"));
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampByLater"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_by_range(
+        pub async fn list_buffer_timestamp_by_range(
             &mut self,
             request: impl tonic::IntoRequest<super::BufferRange>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1773,18 +2114,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferByRange",
+                "/buffer.BufferService/ListBufferTimestampByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByRange"));
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampByRange"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_group(
+        pub async fn list_buffer_timestamp_first(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupTime>,
+            request: impl tonic::IntoRequest<super::BuffersSelector>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1797,18 +2140,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferGroup",
+                "/buffer.BufferService/ListBufferTimestampFirst",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferGroup"));
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampFirst"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_group_by_earlier(
+        pub async fn list_buffer_timestamp_last(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupEarlier>,
+            request: impl tonic::IntoRequest<super::BuffersSelector>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1821,20 +2166,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferGroupByEarlier",
+                "/buffer.BufferService/ListBufferTimestampLast",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByEarlier"),
+                    GrpcMethod::new("buffer.BufferService", "ListBufferTimestampLast"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_group_by_later(
+        pub async fn read_buffer_group_timestamp(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupLater>,
+            request: impl tonic::IntoRequest<super::BufferGroupTime>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampReadResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1847,20 +2192,20 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferGroupByLater",
+                "/buffer.BufferService/ReadBufferGroupTimestamp",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByLater"),
+                    GrpcMethod::new("buffer.BufferService", "ReadBufferGroupTimestamp"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_buffer_group_by_range(
+        pub async fn list_buffer_group_timestamp_by_earlier(
             &mut self,
-            request: impl tonic::IntoRequest<super::BufferGroupRange>,
+            request: impl tonic::IntoRequest<super::BufferGroupEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferCountResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1873,100 +2218,417 @@ pub mod buffer_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/buffer.BufferService/CountBufferGroupByRange",
+                "/buffer.BufferService/ListBufferGroupTimestampByEarlier",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByRange"),
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferGroupTimestampByEarlier",
+                    ),
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod buffer_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with BufferServiceServer.
-    #[async_trait]
-    pub trait BufferService: std::marker::Send + std::marker::Sync + 'static {
-        async fn read_buffer(
-            &self,
-            request: tonic::Request<super::BufferId>,
-        ) -> std::result::Result<
-            tonic::Response<super::BufferReadResponse>,
-            tonic::Status,
-        >;
-        async fn read_buffer_by_time(
-            &self,
-            request: tonic::Request<super::BufferTime>,
+        pub async fn list_buffer_group_timestamp_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupLater>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferReadResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_ids(
-            &self,
-            request: tonic::Request<super::BufferIds>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferGroupTimestampByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferGroupTimestampByLater",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_buffer_group_timestamp_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupRange>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_time(
-            &self,
-            request: tonic::Request<super::BufferTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferGroupTimestampByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferGroupTimestampByRange",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_buffer_group_timestamp_first(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BuffersGroupSelector>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_earlier(
-            &self,
-            request: tonic::Request<super::BufferEarlier>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferGroupTimestampFirst",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferGroupTimestampFirst",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_buffer_group_timestamp_last(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BuffersGroupSelector>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_later(
-            &self,
-            request: tonic::Request<super::BufferLater>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/ListBufferGroupTimestampLast",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "buffer.BufferService",
+                        "ListBufferGroupTimestampLast",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferTime>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_range(
-            &self,
-            request: tonic::Request<super::BufferRange>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBuffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "CountBuffer"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_number_before(
-            &self,
-            request: tonic::Request<super::BufferNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByEarlier"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferLater>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
-        >;
-        async fn list_buffer_by_number_after(
-            &self,
-            request: tonic::Request<super::BufferNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByLater"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferRange>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
-        >;
-        async fn read_buffer_first(
-            &self,
-            request: tonic::Request<super::BufferSelector>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupTime>,
         ) -> std::result::Result<
-            tonic::Response<super::BufferReadResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferGroup",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("buffer.BufferService", "CountBufferGroup"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_group_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferGroupByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByEarlier"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_group_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferGroupByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByLater"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_buffer_group_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BufferGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/buffer.BufferService/CountBufferGroupByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("buffer.BufferService", "CountBufferGroupByRange"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod buffer_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BufferServiceServer.
+    #[async_trait]
+    pub trait BufferService: std::marker::Send + std::marker::Sync + 'static {
+        async fn read_buffer(
+            &self,
+            request: tonic::Request<super::BufferId>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferReadResponse>,
+            tonic::Status,
+        >;
+        async fn read_buffer_by_time(
+            &self,
+            request: tonic::Request<super::BufferTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_ids(
+            &self,
+            request: tonic::Request<super::BufferIds>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_time(
+            &self,
+            request: tonic::Request<super::BufferTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_earlier(
+            &self,
+            request: tonic::Request<super::BufferEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_later(
+            &self,
+            request: tonic::Request<super::BufferLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_range(
+            &self,
+            request: tonic::Request<super::BufferRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_number_before(
+            &self,
+            request: tonic::Request<super::BufferNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_by_number_after(
+            &self,
+            request: tonic::Request<super::BufferNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferListResponse>,
+            tonic::Status,
+        >;
+        async fn read_buffer_first(
+            &self,
+            request: tonic::Request<super::BufferSelector>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferReadResponse>,
+            tonic::Status,
+        >;
         async fn read_buffer_last(
             &self,
             request: tonic::Request<super::BufferSelector>,
@@ -2030,6 +2692,20 @@ pub mod buffer_service_server {
             tonic::Response<super::BufferListResponse>,
             tonic::Status,
         >;
+        async fn list_buffer_by_range_page(
+            &self,
+            request: tonic::Request<super::BufferRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferPageResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_group_by_range_page(
+            &self,
+            request: tonic::Request<super::BufferGroupRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferPageResponse>,
+            tonic::Status,
+        >;
         async fn list_buffer_group_by_number_before(
             &self,
             request: tonic::Request<super::BufferGroupNumber>,
@@ -2044,6 +2720,32 @@ pub mod buffer_service_server {
             tonic::Response<super::BufferListResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the StreamBufferByRange method.
+        type StreamBufferByRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BufferListResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn stream_buffer_by_range(
+            &self,
+            request: tonic::Request<super::BufferRange>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamBufferByRangeStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamBufferGroupByRange method.
+        type StreamBufferGroupByRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::BufferListResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn stream_buffer_group_by_range(
+            &self,
+            request: tonic::Request<super::BufferGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamBufferGroupByRangeStream>,
+            tonic::Status,
+        >;
         async fn read_buffer_group_first(
             &self,
             request: tonic::Request<super::BufferGroupSelector>,
@@ -2121,6 +2823,20 @@ pub mod buffer_service_server {
             tonic::Response<super::BufferSetListResponse>,
             tonic::Status,
         >;
+        async fn list_buffer_set_by_number_before(
+            &self,
+            request: tonic::Request<super::BufferSetNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_set_by_number_after(
+            &self,
+            request: tonic::Request<super::BufferSetNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferSetListResponse>,
+            tonic::Status,
+        >;
         async fn create_buffer(
             &self,
             request: tonic::Request<super::BufferSchema>,
@@ -2135,6 +2851,20 @@ pub mod buffer_service_server {
             tonic::Response<super::BufferCreateMultipleResponse>,
             tonic::Status,
         >;
+        async fn create_buffer_set(
+            &self,
+            request: tonic::Request<super::BufferSetSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferCreateMultipleResponse>,
+            tonic::Status,
+        >;
+        async fn ingest_buffer(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::BufferSchema>>,
+        ) -> std::result::Result<
+            tonic::Response<super::BufferIngestResponse>,
+            tonic::Status,
+        >;
         async fn update_buffer(
             &self,
             request: tonic::Request<super::BufferUpdate>,
@@ -2163,77 +2893,147 @@ pub mod buffer_service_server {
             tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         >;
-        async fn read_buffer_timestamp(
+        async fn delete_buffer_by_range(
             &self,
-            request: tonic::Request<super::BufferTime>,
+            request: tonic::Request<super::BufferRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_timestamp_by_earlier(
+        async fn delete_buffer_group_by_range(
             &self,
-            request: tonic::Request<super::BufferEarlier>,
+            request: tonic::Request<super::BufferGroupRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_timestamp_by_later(
+        async fn transfer_buffer(
             &self,
-            request: tonic::Request<super::BufferLater>,
+            request: tonic::Request<super::BufferTransfer>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferCountResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_timestamp_by_range(
+        async fn claim_buffer(
             &self,
-            request: tonic::Request<super::BufferRange>,
+            request: tonic::Request<super::BufferClaim>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferListResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_timestamp_first(
+        async fn ack_buffer(
             &self,
-            request: tonic::Request<super::BuffersSelector>,
+            request: tonic::Request<super::BufferAck>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferReleaseResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_timestamp_last(
+        async fn nack_buffer(
             &self,
-            request: tonic::Request<super::BuffersSelector>,
+            request: tonic::Request<super::BufferNack>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferReleaseResponse>,
             tonic::Status,
         >;
-        async fn read_buffer_group_timestamp(
+        async fn list_buffer_transition(
             &self,
-            request: tonic::Request<super::BufferGroupTime>,
+            request: tonic::Request<super::BufferTransitionTag>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<super::BufferTransitionListResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_group_timestamp_by_earlier(
+        async fn create_buffer_transition(
             &self,
-            request: tonic::Request<super::BufferGroupEarlier>,
+            request: tonic::Request<super::BufferTransitionSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_group_timestamp_by_later(
+        async fn delete_buffer_transition(
             &self,
-            request: tonic::Request<super::BufferGroupLater>,
+            request: tonic::Request<super::BufferTransitionSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferChangeResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_group_timestamp_by_range(
+        async fn list_buffer_history(
             &self,
-            request: tonic::Request<super::BufferGroupRange>,
+            request: tonic::Request<super::BufferId>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::BufferHistoryListResponse>,
             tonic::Status,
         >;
-        async fn list_buffer_group_timestamp_first(
+        async fn read_buffer_timestamp(
+            &self,
+            request: tonic::Request<super::BufferTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_timestamp_by_earlier(
+            &self,
+            request: tonic::Request<super::BufferEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_timestamp_by_later(
+            &self,
+            request: tonic::Request<super::BufferLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_timestamp_by_range(
+            &self,
+            request: tonic::Request<super::BufferRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_timestamp_first(
+            &self,
+            request: tonic::Request<super::BuffersSelector>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_timestamp_last(
+            &self,
+            request: tonic::Request<super::BuffersSelector>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn read_buffer_group_timestamp(
+            &self,
+            request: tonic::Request<super::BufferGroupTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_group_timestamp_by_earlier(
+            &self,
+            request: tonic::Request<super::BufferGroupEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_group_timestamp_by_later(
+            &self,
+            request: tonic::Request<super::BufferGroupLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_group_timestamp_by_range(
+            &self,
+            request: tonic::Request<super::BufferGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_buffer_group_timestamp_first(
             &self,
             request: tonic::Request<super::BuffersGroupSelector>,
         ) -> std::result::Result<
@@ -2382,21 +3182,674 @@ pub mod buffer_service_server {
             match req.uri().path() {
                 "/buffer.BufferService/ReadBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferId>
-                    for ReadBufferSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    struct ReadBufferSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferId>
+                    for ReadBufferSvc<T> {
+                        type Response = super::BufferReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferId>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::read_buffer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadBufferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ReadBufferByTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadBufferByTimeSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
+                    for ReadBufferByTimeSvc<T> {
+                        type Response = super::BufferReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::read_buffer_by_time(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadBufferByTimeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByIds" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByIdsSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferIds>
+                    for ListBufferByIdsSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferIds>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_ids(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByIdsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByTimeSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
+                    for ListBufferByTimeSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_time(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByTimeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByEarlier" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByEarlierSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferEarlier>
+                    for ListBufferByEarlierSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferEarlier>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_earlier(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByEarlierSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByLater" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByLaterSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferLater>
+                    for ListBufferByLaterSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferLater>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_later(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByLaterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByRangeSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferRange>
+                    for ListBufferByRangeSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByNumberBefore" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByNumberBeforeSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferNumber>
+                    for ListBufferByNumberBeforeSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_number_before(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByNumberBeforeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByNumberAfter" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByNumberAfterSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferNumber>
+                    for ListBufferByNumberAfterSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_number_after(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByNumberAfterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ReadBufferFirst" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadBufferFirstSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferSelector>
+                    for ReadBufferFirstSvc<T> {
+                        type Response = super::BufferReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferSelector>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::read_buffer_first(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadBufferFirstSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ReadBufferLast" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadBufferLastSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferSelector>
+                    for ReadBufferLastSvc<T> {
+                        type Response = super::BufferReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferSelector>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::read_buffer_last(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadBufferLastSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferFirst" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferFirstSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BuffersSelector>
+                    for ListBufferFirstSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BuffersSelector>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_first(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferFirstSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferFirstOffset" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferFirstOffsetSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BuffersSelector>
+                    for ListBufferFirstOffsetSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BuffersSelector>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_first_offset(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferFirstOffsetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferLast" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferLastSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BuffersSelector>
+                    for ListBufferLastSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BuffersSelector>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_last(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferLastSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferLastOffset" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferLastOffsetSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BuffersSelector>
+                    for ListBufferLastOffsetSvc<T> {
+                        type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferId>,
+                            request: tonic::Request<super::BuffersSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer(&inner, request).await
+                                <T as BufferService>::list_buffer_last_offset(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2407,7 +3860,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferSvc(inner);
+                        let method = ListBufferLastOffsetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2423,23 +3876,28 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferByTime" => {
+                "/buffer.BufferService/ListBufferGroupByTime" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferByTimeSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
-                    for ReadBufferByTimeSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    struct ListBufferGroupByTimeSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupTime>
+                    for ListBufferGroupByTimeSvc<T> {
+                        type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferTime>,
+                            request: tonic::Request<super::BufferGroupTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_by_time(&inner, request)
+                                <T as BufferService>::list_buffer_group_by_time(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2451,7 +3909,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferByTimeSvc(inner);
+                        let method = ListBufferGroupByTimeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2467,11 +3925,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByIds" => {
+                "/buffer.BufferService/ListBufferGroupByEarlier" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByIdsSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferIds>
-                    for ListBufferByIdsSvc<T> {
+                    struct ListBufferGroupByEarlierSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupEarlier>
+                    for ListBufferGroupByEarlierSvc<T> {
                         type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2479,11 +3939,14 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferIds>,
+                            request: tonic::Request<super::BufferGroupEarlier>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_ids(&inner, request)
+                                <T as BufferService>::list_buffer_group_by_earlier(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2495,7 +3958,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByIdsSvc(inner);
+                        let method = ListBufferGroupByEarlierSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2511,11 +3974,211 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByTime" => {
+                "/buffer.BufferService/ListBufferGroupByLater" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByTimeSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
-                    for ListBufferByTimeSvc<T> {
+                    struct ListBufferGroupByLaterSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupLater>
+                    for ListBufferGroupByLaterSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferGroupLater>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_group_by_later(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferGroupByLaterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferGroupByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferGroupByRangeSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupRange>
+                    for ListBufferGroupByRangeSvc<T> {
+                        type Response = super::BufferListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferGroupRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferGroupByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferByRangePage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferByRangePageSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferRangePage>
+                    for ListBufferByRangePageSvc<T> {
+                        type Response = super::BufferPageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferRangePage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_by_range_page(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferByRangePageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferGroupByRangePage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferGroupByRangePageSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupRangePage>
+                    for ListBufferGroupByRangePageSvc<T> {
+                        type Response = super::BufferPageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BufferGroupRangePage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BufferService>::list_buffer_group_by_range_page(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBufferGroupByRangePageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/buffer.BufferService/ListBufferGroupByNumberBefore" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBufferGroupByNumberBeforeSvc<T: BufferService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferGroupNumber>
+                    for ListBufferGroupByNumberBeforeSvc<T> {
                         type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2523,11 +4186,14 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferTime>,
+                            request: tonic::Request<super::BufferGroupNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_time(&inner, request)
+                                <T as BufferService>::list_buffer_group_by_number_before(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2539,7 +4205,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByTimeSvc(inner);
+                        let method = ListBufferGroupByNumberBeforeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2555,13 +4221,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByEarlier" => {
+                "/buffer.BufferService/ListBufferGroupByNumberAfter" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByEarlierSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferGroupByNumberAfterSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferEarlier>
-                    for ListBufferByEarlierSvc<T> {
+                    > tonic::server::UnaryService<super::BufferGroupNumber>
+                    for ListBufferGroupByNumberAfterSvc<T> {
                         type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2569,11 +4235,11 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferEarlier>,
+                            request: tonic::Request<super::BufferGroupNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_earlier(
+                                <T as BufferService>::list_buffer_group_by_number_after(
                                         &inner,
                                         request,
                                     )
@@ -2588,7 +4254,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByEarlierSvc(inner);
+                        let method = ListBufferGroupByNumberAfterSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2604,25 +4270,29 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByLater" => {
+                "/buffer.BufferService/StreamBufferByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByLaterSvc<T: BufferService>(pub Arc<T>);
+                    struct StreamBufferByRangeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferLater>
-                    for ListBufferByLaterSvc<T> {
+                    > tonic::server::ServerStreamingService<super::BufferRange>
+                    for StreamBufferByRangeSvc<T> {
                         type Response = super::BufferListResponse;
+                        type ResponseStream = T::StreamBufferByRangeStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferLater>,
+                            request: tonic::Request<super::BufferRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_later(&inner, request)
+                                <T as BufferService>::stream_buffer_by_range(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2634,7 +4304,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByLaterSvc(inner);
+                        let method = StreamBufferByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2645,30 +4315,34 @@ pub mod buffer_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByRange" => {
+                "/buffer.BufferService/StreamBufferGroupByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByRangeSvc<T: BufferService>(pub Arc<T>);
+                    struct StreamBufferGroupByRangeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferRange>
-                    for ListBufferByRangeSvc<T> {
+                    > tonic::server::ServerStreamingService<super::BufferGroupRange>
+                    for StreamBufferGroupByRangeSvc<T> {
                         type Response = super::BufferListResponse;
+                        type ResponseStream = T::StreamBufferGroupByRangeStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferRange>,
+                            request: tonic::Request<super::BufferGroupRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_range(&inner, request)
+                                <T as BufferService>::stream_buffer_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2680,7 +4354,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByRangeSvc(inner);
+                        let method = StreamBufferGroupByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2691,30 +4365,30 @@ pub mod buffer_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByNumberBefore" => {
+                "/buffer.BufferService/ReadBufferGroupFirst" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByNumberBeforeSvc<T: BufferService>(pub Arc<T>);
+                    struct ReadBufferGroupFirstSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferNumber>
-                    for ListBufferByNumberBeforeSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferGroupSelector>
+                    for ReadBufferGroupFirstSvc<T> {
+                        type Response = super::BufferReadResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferNumber>,
+                            request: tonic::Request<super::BufferGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_number_before(
+                                <T as BufferService>::read_buffer_group_first(
                                         &inner,
                                         request,
                                     )
@@ -2729,7 +4403,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByNumberBeforeSvc(inner);
+                        let method = ReadBufferGroupFirstSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2745,25 +4419,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferByNumberAfter" => {
+                "/buffer.BufferService/ReadBufferGroupLast" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferByNumberAfterSvc<T: BufferService>(pub Arc<T>);
+                    struct ReadBufferGroupLastSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferNumber>
-                    for ListBufferByNumberAfterSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferGroupSelector>
+                    for ReadBufferGroupLastSvc<T> {
+                        type Response = super::BufferReadResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferNumber>,
+                            request: tonic::Request<super::BufferGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_by_number_after(
+                                <T as BufferService>::read_buffer_group_last(
                                         &inner,
                                         request,
                                     )
@@ -2778,7 +4452,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferByNumberAfterSvc(inner);
+                        let method = ReadBufferGroupLastSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2794,25 +4468,28 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferFirst" => {
+                "/buffer.BufferService/ListBufferGroupFirst" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferFirstSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferGroupFirstSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSelector>
-                    for ReadBufferFirstSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    > tonic::server::UnaryService<super::BuffersGroupSelector>
+                    for ListBufferGroupFirstSvc<T> {
+                        type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSelector>,
+                            request: tonic::Request<super::BuffersGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_first(&inner, request)
+                                <T as BufferService>::list_buffer_group_first(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2824,7 +4501,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferFirstSvc(inner);
+                        let method = ListBufferGroupFirstSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2840,25 +4517,28 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferLast" => {
+                "/buffer.BufferService/ListBufferGroupFirstOffset" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferLastSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferGroupFirstOffsetSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSelector>
-                    for ReadBufferLastSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    > tonic::server::UnaryService<super::BuffersGroupSelector>
+                    for ListBufferGroupFirstOffsetSvc<T> {
+                        type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSelector>,
+                            request: tonic::Request<super::BuffersGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_last(&inner, request)
+                                <T as BufferService>::list_buffer_group_first_offset(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2870,7 +4550,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferLastSvc(inner);
+                        let method = ListBufferGroupFirstOffsetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2886,13 +4566,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferFirst" => {
+                "/buffer.BufferService/ListBufferGroupLast" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferFirstSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferGroupLastSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersSelector>
-                    for ListBufferFirstSvc<T> {
+                    > tonic::server::UnaryService<super::BuffersGroupSelector>
+                    for ListBufferGroupLastSvc<T> {
                         type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2900,11 +4580,14 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersSelector>,
+                            request: tonic::Request<super::BuffersGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_first(&inner, request)
+                                <T as BufferService>::list_buffer_group_last(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2916,7 +4599,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferFirstSvc(inner);
+                        let method = ListBufferGroupLastSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2932,13 +4615,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferFirstOffset" => {
+                "/buffer.BufferService/ListBufferGroupLastOffset" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferFirstOffsetSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferGroupLastOffsetSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersSelector>
-                    for ListBufferFirstOffsetSvc<T> {
+                    > tonic::server::UnaryService<super::BuffersGroupSelector>
+                    for ListBufferGroupLastOffsetSvc<T> {
                         type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2946,11 +4629,11 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersSelector>,
+                            request: tonic::Request<super::BuffersGroupSelector>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_first_offset(
+                                <T as BufferService>::list_buffer_group_last_offset(
                                         &inner,
                                         request,
                                     )
@@ -2965,7 +4648,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferFirstOffsetSvc(inner);
+                        let method = ListBufferGroupLastOffsetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2981,26 +4664,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferLast" => {
+                "/buffer.BufferService/ReadBufferSet" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferLastSvc<T: BufferService>(pub Arc<T>);
+                    struct ReadBufferSetSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersSelector>
-                    for ListBufferLastSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetTime>
+                    for ReadBufferSetSvc<T> {
+                        type Response = super::BufferSetReadResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersSelector>,
+                            request: tonic::Request<super::BufferSetTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_last(&inner, request)
-                                    .await
+                                <T as BufferService>::read_buffer_set(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3011,7 +4693,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferLastSvc(inner);
+                        let method = ReadBufferSetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3027,25 +4709,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferLastOffset" => {
+                "/buffer.BufferService/ListBufferSetByTime" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferLastOffsetSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferSetByTimeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersSelector>
-                    for ListBufferLastOffsetSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetTime>
+                    for ListBufferSetByTimeSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersSelector>,
+                            request: tonic::Request<super::BufferSetTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_last_offset(
+                                <T as BufferService>::list_buffer_set_by_time(
                                         &inner,
                                         request,
                                     )
@@ -3060,7 +4742,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferLastOffsetSvc(inner);
+                        let method = ListBufferSetByTimeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3076,25 +4758,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByTime" => {
+                "/buffer.BufferService/ListBufferSetByEarlier" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByTimeSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferSetByEarlierSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupTime>
-                    for ListBufferGroupByTimeSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetEarlier>
+                    for ListBufferSetByEarlierSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupTime>,
+                            request: tonic::Request<super::BufferSetEarlier>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_time(
+                                <T as BufferService>::list_buffer_set_by_earlier(
                                         &inner,
                                         request,
                                     )
@@ -3109,7 +4791,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByTimeSvc(inner);
+                        let method = ListBufferSetByEarlierSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3125,25 +4807,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByEarlier" => {
+                "/buffer.BufferService/ListBufferSetByLater" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByEarlierSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferSetByLaterSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupEarlier>
-                    for ListBufferGroupByEarlierSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetLater>
+                    for ListBufferSetByLaterSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupEarlier>,
+                            request: tonic::Request<super::BufferSetLater>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_earlier(
+                                <T as BufferService>::list_buffer_set_by_later(
                                         &inner,
                                         request,
                                     )
@@ -3158,7 +4840,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByEarlierSvc(inner);
+                        let method = ListBufferSetByLaterSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3174,25 +4856,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByLater" => {
+                "/buffer.BufferService/ListBufferSetByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByLaterSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferSetByRangeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupLater>
-                    for ListBufferGroupByLaterSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetRange>
+                    for ListBufferSetByRangeSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupLater>,
+                            request: tonic::Request<super::BufferSetRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_later(
+                                <T as BufferService>::list_buffer_set_by_range(
                                         &inner,
                                         request,
                                     )
@@ -3207,7 +4889,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByLaterSvc(inner);
+                        let method = ListBufferSetByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3223,25 +4905,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByRange" => {
+                "/buffer.BufferService/ListBufferSetByNumberBefore" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByRangeSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferSetByNumberBeforeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupRange>
-                    for ListBufferGroupByRangeSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetNumber>
+                    for ListBufferSetByNumberBeforeSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupRange>,
+                            request: tonic::Request<super::BufferSetNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_range(
+                                <T as BufferService>::list_buffer_set_by_number_before(
                                         &inner,
                                         request,
                                     )
@@ -3256,7 +4938,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByRangeSvc(inner);
+                        let method = ListBufferSetByNumberBeforeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3272,27 +4954,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByNumberBefore" => {
+                "/buffer.BufferService/ListBufferSetByNumberAfter" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByNumberBeforeSvc<T: BufferService>(
-                        pub Arc<T>,
-                    );
+                    struct ListBufferSetByNumberAfterSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupNumber>
-                    for ListBufferGroupByNumberBeforeSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSetNumber>
+                    for ListBufferSetByNumberAfterSvc<T> {
+                        type Response = super::BufferSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupNumber>,
+                            request: tonic::Request<super::BufferSetNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_number_before(
+                                <T as BufferService>::list_buffer_set_by_number_after(
                                         &inner,
                                         request,
                                     )
@@ -3307,7 +4987,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByNumberBeforeSvc(inner);
+                        let method = ListBufferSetByNumberAfterSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3323,29 +5003,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupByNumberAfter" => {
+                "/buffer.BufferService/CreateBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupByNumberAfterSvc<T: BufferService>(pub Arc<T>);
+                    struct CreateBufferSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupNumber>
-                    for ListBufferGroupByNumberAfterSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferSchema>
+                    for CreateBufferSvc<T> {
+                        type Response = super::BufferCreateResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupNumber>,
+                            request: tonic::Request<super::BufferSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_by_number_after(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::create_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3356,7 +5032,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupByNumberAfterSvc(inner);
+                        let method = CreateBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3372,25 +5048,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferGroupFirst" => {
+                "/buffer.BufferService/CreateBufferMultiple" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferGroupFirstSvc<T: BufferService>(pub Arc<T>);
+                    struct CreateBufferMultipleSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupSelector>
-                    for ReadBufferGroupFirstSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    > tonic::server::UnaryService<super::BufferMultipleSchema>
+                    for CreateBufferMultipleSvc<T> {
+                        type Response = super::BufferCreateMultipleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupSelector>,
+                            request: tonic::Request<super::BufferMultipleSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_group_first(
+                                <T as BufferService>::create_buffer_multiple(
                                         &inner,
                                         request,
                                     )
@@ -3405,7 +5081,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferGroupFirstSvc(inner);
+                        let method = CreateBufferMultipleSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3421,28 +5097,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferGroupLast" => {
+                "/buffer.BufferService/CreateBufferSet" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferGroupLastSvc<T: BufferService>(pub Arc<T>);
+                    struct CreateBufferSetSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferGroupSelector>
-                    for ReadBufferGroupLastSvc<T> {
-                        type Response = super::BufferReadResponse;
+                    > tonic::server::UnaryService<super::BufferSetSchema>
+                    for CreateBufferSetSvc<T> {
+                        type Response = super::BufferCreateMultipleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferGroupSelector>,
+                            request: tonic::Request<super::BufferSetSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_group_last(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as BufferService>::create_buffer_set(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -3454,7 +5127,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferGroupLastSvc(inner);
+                        let method = CreateBufferSetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3470,29 +5143,27 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupFirst" => {
+                "/buffer.BufferService/IngestBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupFirstSvc<T: BufferService>(pub Arc<T>);
+                    struct IngestBufferSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersGroupSelector>
-                    for ListBufferGroupFirstSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::ClientStreamingService<super::BufferSchema>
+                    for IngestBufferSvc<T> {
+                        type Response = super::BufferIngestResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersGroupSelector>,
+                            request: tonic::Request<
+                                tonic::Streaming<super::BufferSchema>,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_first(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::ingest_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3503,7 +5174,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupFirstSvc(inner);
+                        let method = IngestBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3514,34 +5185,30 @@ pub mod buffer_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupFirstOffset" => {
+                "/buffer.BufferService/UpdateBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupFirstOffsetSvc<T: BufferService>(pub Arc<T>);
+                    struct UpdateBufferSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersGroupSelector>
-                    for ListBufferGroupFirstOffsetSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferUpdate>
+                    for UpdateBufferSvc<T> {
+                        type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersGroupSelector>,
+                            request: tonic::Request<super::BufferUpdate>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_first_offset(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::update_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3552,7 +5219,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupFirstOffsetSvc(inner);
+                        let method = UpdateBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3568,28 +5235,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupLast" => {
+                "/buffer.BufferService/UpdateBufferByTime" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupLastSvc<T: BufferService>(pub Arc<T>);
+                    struct UpdateBufferByTimeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersGroupSelector>
-                    for ListBufferGroupLastSvc<T> {
-                        type Response = super::BufferListResponse;
+                    > tonic::server::UnaryService<super::BufferUpdateTime>
+                    for UpdateBufferByTimeSvc<T> {
+                        type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersGroupSelector>,
+                            request: tonic::Request<super::BufferUpdateTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_last(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as BufferService>::update_buffer_by_time(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -3601,7 +5265,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupLastSvc(inner);
+                        let method = UpdateBufferByTimeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3617,29 +5281,23 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferGroupLastOffset" => {
+                "/buffer.BufferService/DeleteBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferGroupLastOffsetSvc<T: BufferService>(pub Arc<T>);
-                    impl<
-                        T: BufferService,
-                    > tonic::server::UnaryService<super::BuffersGroupSelector>
-                    for ListBufferGroupLastOffsetSvc<T> {
-                        type Response = super::BufferListResponse;
+                    struct DeleteBufferSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferId>
+                    for DeleteBufferSvc<T> {
+                        type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BuffersGroupSelector>,
+                            request: tonic::Request<super::BufferId>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_group_last_offset(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::delete_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3650,7 +5308,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferGroupLastOffsetSvc(inner);
+                        let method = DeleteBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3666,25 +5324,24 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ReadBufferSet" => {
+                "/buffer.BufferService/DeleteBufferByTime" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadBufferSetSvc<T: BufferService>(pub Arc<T>);
-                    impl<
-                        T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSetTime>
-                    for ReadBufferSetSvc<T> {
-                        type Response = super::BufferSetReadResponse;
+                    struct DeleteBufferByTimeSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
+                    for DeleteBufferByTimeSvc<T> {
+                        type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSetTime>,
+                            request: tonic::Request<super::BufferTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::read_buffer_set(&inner, request).await
+                                <T as BufferService>::delete_buffer_by_time(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3695,7 +5352,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadBufferSetSvc(inner);
+                        let method = DeleteBufferByTimeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3711,25 +5368,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferSetByTime" => {
+                "/buffer.BufferService/DeleteBufferByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferSetByTimeSvc<T: BufferService>(pub Arc<T>);
+                    struct DeleteBufferByRangeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSetTime>
-                    for ListBufferSetByTimeSvc<T> {
-                        type Response = super::BufferSetListResponse;
+                    > tonic::server::UnaryService<super::BufferRangeDelete>
+                    for DeleteBufferByRangeSvc<T> {
+                        type Response = super::BufferCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSetTime>,
+                            request: tonic::Request<super::BufferRangeDelete>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_set_by_time(
+                                <T as BufferService>::delete_buffer_by_range(
                                         &inner,
                                         request,
                                     )
@@ -3744,7 +5401,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferSetByTimeSvc(inner);
+                        let method = DeleteBufferByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3760,25 +5417,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferSetByEarlier" => {
+                "/buffer.BufferService/DeleteBufferGroupByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferSetByEarlierSvc<T: BufferService>(pub Arc<T>);
+                    struct DeleteBufferGroupByRangeSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSetEarlier>
-                    for ListBufferSetByEarlierSvc<T> {
-                        type Response = super::BufferSetListResponse;
+                    > tonic::server::UnaryService<super::BufferGroupRangeDelete>
+                    for DeleteBufferGroupByRangeSvc<T> {
+                        type Response = super::BufferCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSetEarlier>,
+                            request: tonic::Request<super::BufferGroupRangeDelete>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_set_by_earlier(
+                                <T as BufferService>::delete_buffer_group_by_range(
                                         &inner,
                                         request,
                                     )
@@ -3793,7 +5450,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferSetByEarlierSvc(inner);
+                        let method = DeleteBufferGroupByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3809,29 +5466,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferSetByLater" => {
+                "/buffer.BufferService/TransferBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferSetByLaterSvc<T: BufferService>(pub Arc<T>);
+                    struct TransferBufferSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSetLater>
-                    for ListBufferSetByLaterSvc<T> {
-                        type Response = super::BufferSetListResponse;
+                    > tonic::server::UnaryService<super::BufferTransfer>
+                    for TransferBufferSvc<T> {
+                        type Response = super::BufferCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSetLater>,
+                            request: tonic::Request<super::BufferTransfer>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_set_by_later(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::transfer_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3842,7 +5495,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferSetByLaterSvc(inner);
+                        let method = TransferBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3858,29 +5511,25 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/ListBufferSetByRange" => {
+                "/buffer.BufferService/ClaimBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListBufferSetByRangeSvc<T: BufferService>(pub Arc<T>);
+                    struct ClaimBufferSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSetRange>
-                    for ListBufferSetByRangeSvc<T> {
-                        type Response = super::BufferSetListResponse;
+                    > tonic::server::UnaryService<super::BufferClaim>
+                    for ClaimBufferSvc<T> {
+                        type Response = super::BufferListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSetRange>,
+                            request: tonic::Request<super::BufferClaim>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::list_buffer_set_by_range(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::claim_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3891,7 +5540,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListBufferSetByRangeSvc(inner);
+                        let method = ClaimBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3907,25 +5556,23 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/CreateBuffer" => {
+                "/buffer.BufferService/AckBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateBufferSvc<T: BufferService>(pub Arc<T>);
-                    impl<
-                        T: BufferService,
-                    > tonic::server::UnaryService<super::BufferSchema>
-                    for CreateBufferSvc<T> {
-                        type Response = super::BufferCreateResponse;
+                    struct AckBufferSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferAck>
+                    for AckBufferSvc<T> {
+                        type Response = super::BufferReleaseResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferSchema>,
+                            request: tonic::Request<super::BufferAck>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::create_buffer(&inner, request).await
+                                <T as BufferService>::ack_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3936,7 +5583,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateBufferSvc(inner);
+                        let method = AckBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3952,29 +5599,23 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/CreateBufferMultiple" => {
+                "/buffer.BufferService/NackBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateBufferMultipleSvc<T: BufferService>(pub Arc<T>);
-                    impl<
-                        T: BufferService,
-                    > tonic::server::UnaryService<super::BufferMultipleSchema>
-                    for CreateBufferMultipleSvc<T> {
-                        type Response = super::BufferCreateMultipleResponse;
+                    struct NackBufferSvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferNack>
+                    for NackBufferSvc<T> {
+                        type Response = super::BufferReleaseResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferMultipleSchema>,
+                            request: tonic::Request<super::BufferNack>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::create_buffer_multiple(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as BufferService>::nack_buffer(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3985,7 +5626,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateBufferMultipleSvc(inner);
+                        let method = NackBufferSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4001,25 +5642,29 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/UpdateBuffer" => {
+                "/buffer.BufferService/ListBufferTransition" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateBufferSvc<T: BufferService>(pub Arc<T>);
+                    struct ListBufferTransitionSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferUpdate>
-                    for UpdateBufferSvc<T> {
-                        type Response = super::BufferChangeResponse;
+                    > tonic::server::UnaryService<super::BufferTransitionTag>
+                    for ListBufferTransitionSvc<T> {
+                        type Response = super::BufferTransitionListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferUpdate>,
+                            request: tonic::Request<super::BufferTransitionTag>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::update_buffer(&inner, request).await
+                                <T as BufferService>::list_buffer_transition(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4030,7 +5675,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateBufferSvc(inner);
+                        let method = ListBufferTransitionSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4046,13 +5691,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/UpdateBufferByTime" => {
+                "/buffer.BufferService/CreateBufferTransition" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateBufferByTimeSvc<T: BufferService>(pub Arc<T>);
+                    struct CreateBufferTransitionSvc<T: BufferService>(pub Arc<T>);
                     impl<
                         T: BufferService,
-                    > tonic::server::UnaryService<super::BufferUpdateTime>
-                    for UpdateBufferByTimeSvc<T> {
+                    > tonic::server::UnaryService<super::BufferTransitionSchema>
+                    for CreateBufferTransitionSvc<T> {
                         type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -4060,11 +5705,14 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferUpdateTime>,
+                            request: tonic::Request<super::BufferTransitionSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::update_buffer_by_time(&inner, request)
+                                <T as BufferService>::create_buffer_transition(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -4076,7 +5724,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateBufferByTimeSvc(inner);
+                        let method = CreateBufferTransitionSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4092,11 +5740,13 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/DeleteBuffer" => {
+                "/buffer.BufferService/DeleteBufferTransition" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteBufferSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferId>
-                    for DeleteBufferSvc<T> {
+                    struct DeleteBufferTransitionSvc<T: BufferService>(pub Arc<T>);
+                    impl<
+                        T: BufferService,
+                    > tonic::server::UnaryService<super::BufferTransitionSchema>
+                    for DeleteBufferTransitionSvc<T> {
                         type Response = super::BufferChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -4104,11 +5754,15 @@ pub mod buffer_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferId>,
+                            request: tonic::Request<super::BufferTransitionSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::delete_buffer(&inner, request).await
+                                <T as BufferService>::delete_buffer_transition(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4119,7 +5773,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteBufferSvc(inner);
+                        let method = DeleteBufferTransitionSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4135,23 +5789,23 @@ pub mod buffer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/buffer.BufferService/DeleteBufferByTime" => {
+                "/buffer.BufferService/ListBufferHistory" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteBufferByTimeSvc<T: BufferService>(pub Arc<T>);
-                    impl<T: BufferService> tonic::server::UnaryService<super::BufferTime>
-                    for DeleteBufferByTimeSvc<T> {
-                        type Response = super::BufferChangeResponse;
+                    struct ListBufferHistorySvc<T: BufferService>(pub Arc<T>);
+                    impl<T: BufferService> tonic::server::UnaryService<super::BufferId>
+                    for ListBufferHistorySvc<T> {
+                        type Response = super::BufferHistoryListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BufferTime>,
+                            request: tonic::Request<super::BufferId>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BufferService>::delete_buffer_by_time(&inner, request)
+                                <T as BufferService>::list_buffer_history(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -4163,7 +5817,7 @@ pub mod buffer_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteBufferByTimeSvc(inner);
+                        let method = ListBufferHistorySvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -18,6 +18,8 @@ pub struct DataSchema {
 pub struct DataMultipleSchema {
     #[prost(message, repeated, tag = "1")]
     pub schemas: ::prost::alloc::vec::Vec<DataSchema>,
+    #[prost(string, optional, tag = "2")]
+    pub conflict: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataTime {
@@ -66,6 +68,109 @@ pub struct DataRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataUpdate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub timestamp: i64,
+    #[prost(int32, optional, tag = "4")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bytes = "vec", optional, tag = "5")]
+    pub data_bytes: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, repeated, tag = "6")]
+    pub data_type: ::prost::alloc::vec::Vec<u32>,
+    #[prost(int32, optional, tag = "7")]
+    pub new_tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataUpdateRange {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(uint32, tag = "6")]
+    pub index: u32,
+    #[prost(bytes = "vec", tag = "7")]
+    pub data_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, repeated, tag = "8")]
+    pub data_type: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataAuditSchema {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "4")]
+    pub timestamp: i64,
+    #[prost(int32, tag = "5")]
+    pub tag: i32,
+    #[prost(bytes = "vec", tag = "6")]
+    pub data_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, repeated, tag = "7")]
+    pub data_type: ::prost::alloc::vec::Vec<u32>,
+    #[prost(int32, tag = "8")]
+    pub new_tag: i32,
+    #[prost(bytes = "vec", tag = "9")]
+    pub new_data_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, repeated, tag = "10")]
+    pub new_data_type: ::prost::alloc::vec::Vec<u32>,
+    #[prost(int64, tag = "11")]
+    pub changed_at: i64,
+    #[prost(string, tag = "12")]
+    pub changed_by: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataStatsSchema {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "3")]
+    pub index: u32,
+    #[prost(uint64, tag = "4")]
+    pub count: u64,
+    #[prost(uint32, tag = "5")]
+    pub data_type: u32,
+    #[prost(bytes = "vec", tag = "6")]
+    pub min_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub max_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(double, tag = "8")]
+    pub mean: f64,
+    #[prost(double, tag = "9")]
+    pub stddev: f64,
+    #[prost(bytes = "vec", tag = "10")]
+    pub first_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "11")]
+    pub last_bytes: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataRangeDelete {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataNumber {
     #[prost(bytes = "vec", tag = "1")]
     pub device_id: ::prost::alloc::vec::Vec<u8>,
@@ -79,6 +184,63 @@ pub struct DataNumber {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataBucketed {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int64, tag = "5")]
+    pub interval: i64,
+    #[prost(string, tag = "6")]
+    pub aggregate: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "7")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataSlice {
+    #[prost(int32, tag = "1")]
+    pub slice_id: i32,
+    #[prost(int32, optional, tag = "2")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataSubscribe {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int32, optional, tag = "3")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataExport {
+    #[prost(string, tag = "1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub set_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "5")]
+    pub begin: i64,
+    #[prost(int64, tag = "6")]
+    pub end: i64,
+    #[prost(string, tag = "7")]
+    pub format: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "8")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataExportChunk {
+    #[prost(bytes = "vec", tag = "1")]
+    pub chunk: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataSetSchema {
     #[prost(bytes = "vec", tag = "1")]
     pub set_id: ::prost::alloc::vec::Vec<u8>,
@@ -92,6 +254,19 @@ pub struct DataSetSchema {
     pub tag: i32,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataSetAlignedSchema {
+    #[prost(bytes = "vec", tag = "1")]
+    pub set_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, repeated, tag = "4")]
+    pub data_type: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bool, repeated, tag = "5")]
+    pub present: ::prost::alloc::vec::Vec<bool>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataGroupTime {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -138,6 +313,21 @@ pub struct DataGroupRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataGroupRangeDelete {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bool, tag = "6")]
+    pub dry_run: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataGroupNumber {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -151,6 +341,49 @@ pub struct DataGroupNumber {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataLatest {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int32, optional, tag = "3")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataRangePage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataGroupRangePage {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub model_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(int64, tag = "3")]
+    pub begin: i64,
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    #[prost(int32, optional, tag = "5")]
+    pub tag: ::core::option::Option<i32>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataSetTime {
     #[prost(bytes = "vec", tag = "1")]
     pub set_id: ::prost::alloc::vec::Vec<u8>,
@@ -189,6 +422,32 @@ pub struct DataSetRange {
     pub tag: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataSetAligned {
+    #[prost(bytes = "vec", tag = "1")]
+    pub set_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "2")]
+    pub begin: i64,
+    #[prost(int64, tag = "3")]
+    pub end: i64,
+    #[prost(int64, tag = "4")]
+    pub interval: i64,
+    #[prost(string, tag = "5")]
+    pub align: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "6")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataSetNumber {
+    #[prost(bytes = "vec", tag = "1")]
+    pub set_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+    #[prost(uint32, tag = "3")]
+    pub number: u32,
+    #[prost(int32, optional, tag = "4")]
+    pub tag: ::core::option::Option<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataReadResponse {
     #[prost(message, optional, tag = "1")]
     pub result: ::core::option::Option<DataSchema>,
@@ -198,6 +457,23 @@ pub struct DataListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<DataSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataStatsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<DataStatsSchema>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataAuditListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<DataAuditSchema>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataPageResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<DataSchema>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DataSetReadResponse {
     #[prost(message, optional, tag = "1")]
@@ -208,8 +484,87 @@ pub struct DataSetListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<DataSetSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataSetAlignedListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<DataSetAlignedSchema>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataChangeResponse {
+    #[prost(uint32, tag = "1")]
+    pub violated: u32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataCreateMultipleResponse {
+    #[prost(uint32, tag = "1")]
+    pub inserted: u32,
+    #[prost(uint32, tag = "2")]
+    pub skipped: u32,
+    #[prost(uint32, tag = "3")]
+    pub overwritten: u32,
+    #[prost(uint32, tag = "4")]
+    pub violated: u32,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct DataChangeResponse {}
+pub struct DataIngestBatch {
+    #[prost(uint32, tag = "1")]
+    pub batch: u32,
+    #[prost(uint32, tag = "2")]
+    pub count: u32,
+    #[prost(uint32, tag = "3")]
+    pub rejected: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataIngestReject {
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataIngestResponse {
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub batches: ::prost::alloc::vec::Vec<DataIngestBatch>,
+    #[prost(message, repeated, tag = "3")]
+    pub rejects: ::prost::alloc::vec::Vec<DataIngestReject>,
+    #[prost(string, optional, tag = "4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "5")]
+    pub violated: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataImport {
+    #[prost(uint64, repeated, tag = "1")]
+    pub lines: ::prost::alloc::vec::Vec<u64>,
+    #[prost(message, repeated, tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<DataSchema>,
+    #[prost(string, tag = "3")]
+    pub conflict: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DataImportReject {
+    #[prost(uint64, tag = "1")]
+    pub line: u64,
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataImportResponse {
+    #[prost(uint64, tag = "1")]
+    pub inserted: u64,
+    #[prost(uint64, tag = "2")]
+    pub skipped: u64,
+    #[prost(uint64, tag = "3")]
+    pub overwritten: u64,
+    #[prost(message, repeated, tag = "4")]
+    pub rejects: ::prost::alloc::vec::Vec<DataImportReject>,
+    #[prost(string, optional, tag = "5")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "6")]
+    pub violated: u64,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct TimestampReadResponse {
     #[prost(int64, tag = "1")]
@@ -579,11 +934,11 @@ pub mod data_service_client {
                 .insert(GrpcMethod::new("data.DataService", "ListDataGroupByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_group_by_number_before(
+        pub async fn list_data_by_range_page(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupNumber>,
+            request: impl tonic::IntoRequest<super::DataRangePage>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataPageResponse>,
             tonic::Status,
         > {
             self.inner
@@ -596,20 +951,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataGroupByNumberBefore",
+                "/data.DataService/ListDataByRangePage",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("data.DataService", "ListDataGroupByNumberBefore"),
-                );
+                .insert(GrpcMethod::new("data.DataService", "ListDataByRangePage"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_group_by_number_after(
+        pub async fn list_data_group_by_range_page(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupNumber>,
+            request: impl tonic::IntoRequest<super::DataGroupRangePage>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataPageResponse>,
             tonic::Status,
         > {
             self.inner
@@ -622,20 +975,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataGroupByNumberAfter",
+                "/data.DataService/ListDataGroupByRangePage",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("data.DataService", "ListDataGroupByNumberAfter"),
-                );
+                .insert(GrpcMethod::new("data.DataService", "ListDataGroupByRangePage"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn read_data_set(
+        pub async fn list_data_group_by_number_before(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSetTime>,
+            request: impl tonic::IntoRequest<super::DataGroupNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetReadResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -648,18 +999,20 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ReadDataSet",
+                "/data.DataService/ListDataGroupByNumberBefore",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ReadDataSet"));
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataGroupByNumberBefore"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_set_by_time(
+        pub async fn list_data_group_by_number_after(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSetTime>,
+            request: impl tonic::IntoRequest<super::DataGroupNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -672,18 +1025,20 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataSetByTime",
+                "/data.DataService/ListDataGroupByNumberAfter",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataSetByTime"));
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataGroupByNumberAfter"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_set_by_earlier(
+        pub async fn list_data_latest(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSetEarlier>,
+            request: impl tonic::IntoRequest<super::DataLatest>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -696,18 +1051,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataSetByEarlier",
+                "/data.DataService/ListDataLatest",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataSetByEarlier"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataLatest"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_set_by_later(
+        pub async fn list_data_bucketed(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSetLater>,
+            request: impl tonic::IntoRequest<super::DataBucketed>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -720,18 +1075,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataSetByLater",
+                "/data.DataService/ListDataBucketed",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataSetByLater"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataBucketed"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_set_by_range(
+        pub async fn stats_data_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSetRange>,
+            request: impl tonic::IntoRequest<super::DataRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
+            tonic::Response<super::DataStatsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -744,18 +1099,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataSetByRange",
+                "/data.DataService/StatsDataByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataSetByRange"));
+                .insert(GrpcMethod::new("data.DataService", "StatsDataByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn create_data(
+        pub async fn stats_data_group_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataSchema>,
+            request: impl tonic::IntoRequest<super::DataGroupRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
+            tonic::Response<super::DataStatsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -768,18 +1123,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CreateData",
+                "/data.DataService/StatsDataGroupByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CreateData"));
+                .insert(GrpcMethod::new("data.DataService", "StatsDataGroupByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn create_data_multiple(
+        pub async fn list_data_by_slice(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataMultipleSchema>,
+            request: impl tonic::IntoRequest<super::DataSlice>,
         ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -792,18 +1147,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CreateDataMultiple",
+                "/data.DataService/ListDataBySlice",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CreateDataMultiple"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataBySlice"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_data(
+        pub async fn stream_data_by_slice(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataTime>,
+            request: impl tonic::IntoRequest<super::DataSlice>,
         ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
+            tonic::Response<tonic::codec::Streaming<super::DataListResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -816,18 +1171,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/DeleteData",
+                "/data.DataService/StreamDataBySlice",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "DeleteData"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("data.DataService", "StreamDataBySlice"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn read_data_timestamp(
+        pub async fn stats_data_by_slice(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataTime>,
+            request: impl tonic::IntoRequest<super::DataSlice>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<super::DataStatsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -840,18 +1195,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ReadDataTimestamp",
+                "/data.DataService/StatsDataBySlice",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ReadDataTimestamp"));
+                .insert(GrpcMethod::new("data.DataService", "StatsDataBySlice"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_timestamp_by_earlier(
+        pub async fn stream_data_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataEarlier>,
+            request: impl tonic::IntoRequest<super::DataRange>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<tonic::codec::Streaming<super::DataListResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -864,20 +1219,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataTimestampByEarlier",
+                "/data.DataService/StreamDataByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("data.DataService", "ListDataTimestampByEarlier"),
-                );
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("data.DataService", "StreamDataByRange"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn list_data_timestamp_by_later(
+        pub async fn stream_data_group_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataLater>,
+            request: impl tonic::IntoRequest<super::DataGroupRange>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<tonic::codec::Streaming<super::DataListResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -890,18 +1243,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataTimestampByLater",
+                "/data.DataService/StreamDataGroupByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataTimestampByLater"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("data.DataService", "StreamDataGroupByRange"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn list_data_timestamp_by_range(
+        pub async fn subscribe_data(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataRange>,
+            request: impl tonic::IntoRequest<super::DataSubscribe>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<tonic::codec::Streaming<super::DataSchema>>,
             tonic::Status,
         > {
             self.inner
@@ -914,18 +1267,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataTimestampByRange",
+                "/data.DataService/SubscribeData",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ListDataTimestampByRange"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("data.DataService", "SubscribeData"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn read_data_group_timestamp(
+        pub async fn export_data(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupTime>,
+            request: impl tonic::IntoRequest<super::DataExport>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
+            tonic::Response<tonic::codec::Streaming<super::DataExportChunk>>,
             tonic::Status,
         > {
             self.inner
@@ -938,18 +1291,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ReadDataGroupTimestamp",
+                "/data.DataService/ExportData",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "ReadDataGroupTimestamp"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("data.DataService", "ExportData"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn list_data_group_timestamp_by_earlier(
+        pub async fn read_data_set(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupEarlier>,
+            request: impl tonic::IntoRequest<super::DataSetTime>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::DataSetReadResponse>,
             tonic::Status,
         > {
             self.inner
@@ -962,23 +1315,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataGroupTimestampByEarlier",
+                "/data.DataService/ReadDataSet",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "data.DataService",
-                        "ListDataGroupTimestampByEarlier",
-                    ),
-                );
+                .insert(GrpcMethod::new("data.DataService", "ReadDataSet"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_group_timestamp_by_later(
+        pub async fn list_data_set_by_time(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupLater>,
+            request: impl tonic::IntoRequest<super::DataSetTime>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -991,20 +1339,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataGroupTimestampByLater",
+                "/data.DataService/ListDataSetByTime",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("data.DataService", "ListDataGroupTimestampByLater"),
-                );
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetByTime"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn list_data_group_timestamp_by_range(
+        pub async fn list_data_set_by_earlier(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupRange>,
+            request: impl tonic::IntoRequest<super::DataSetEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1017,20 +1363,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/ListDataGroupTimestampByRange",
+                "/data.DataService/ListDataSetByEarlier",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("data.DataService", "ListDataGroupTimestampByRange"),
-                );
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetByEarlier"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data(
+        pub async fn list_data_set_by_later(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataTime>,
+            request: impl tonic::IntoRequest<super::DataSetLater>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1043,18 +1387,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountData",
+                "/data.DataService/ListDataSetByLater",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountData"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetByLater"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_by_earlier(
+        pub async fn list_data_set_by_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataEarlier>,
+            request: impl tonic::IntoRequest<super::DataSetRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1067,18 +1411,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataByEarlier",
+                "/data.DataService/ListDataSetByRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataByEarlier"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetByRange"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_by_later(
+        pub async fn list_data_by_slice_set(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataLater>,
+            request: impl tonic::IntoRequest<super::DataSlice>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1091,18 +1435,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataByLater",
+                "/data.DataService/ListDataBySliceSet",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataByLater"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataBySliceSet"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_by_range(
+        pub async fn stats_data_by_slice_set(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataRange>,
+            request: impl tonic::IntoRequest<super::DataSlice>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataStatsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1115,18 +1459,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataByRange",
+                "/data.DataService/StatsDataBySliceSet",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataByRange"));
+                .insert(GrpcMethod::new("data.DataService", "StatsDataBySliceSet"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_group(
+        pub async fn list_data_set_aligned(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupTime>,
+            request: impl tonic::IntoRequest<super::DataSetAligned>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetAlignedListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1139,18 +1483,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataGroup",
+                "/data.DataService/ListDataSetAligned",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataGroup"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetAligned"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_group_by_earlier(
+        pub async fn list_data_set_by_number_before(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupEarlier>,
+            request: impl tonic::IntoRequest<super::DataSetNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1163,18 +1507,20 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataGroupByEarlier",
+                "/data.DataService/ListDataSetByNumberBefore",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByEarlier"));
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataSetByNumberBefore"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_group_by_later(
+        pub async fn list_data_set_by_number_after(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupLater>,
+            request: impl tonic::IntoRequest<super::DataSetNumber>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataSetListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1187,18 +1533,18 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataGroupByLater",
+                "/data.DataService/ListDataSetByNumberAfter",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByLater"));
+                .insert(GrpcMethod::new("data.DataService", "ListDataSetByNumberAfter"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn count_data_group_by_range(
+        pub async fn create_data(
             &mut self,
-            request: impl tonic::IntoRequest<super::DataGroupRange>,
+            request: impl tonic::IntoRequest<super::DataSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataChangeResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1211,352 +1557,1196 @@ pub mod data_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/data.DataService/CountDataGroupByRange",
+                "/data.DataService/CreateData",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByRange"));
+                .insert(GrpcMethod::new("data.DataService", "CreateData"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod data_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with DataServiceServer.
-    #[async_trait]
-    pub trait DataService: std::marker::Send + std::marker::Sync + 'static {
-        async fn read_data(
-            &self,
-            request: tonic::Request<super::DataTime>,
+        pub async fn create_data_multiple(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataMultipleSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::DataReadResponse>,
+            tonic::Response<super::DataCreateMultipleResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_time(
-            &self,
-            request: tonic::Request<super::DataTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CreateDataMultiple",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CreateDataMultiple"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_data_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataSetSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataChangeResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_earlier(
-            &self,
-            request: tonic::Request<super::DataEarlier>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CreateDataSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CreateDataSet"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn ingest_data(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::DataSchema>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataIngestResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_later(
-            &self,
-            request: tonic::Request<super::DataLater>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/IngestData",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "IngestData"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn import_data(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::DataImport>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataImportResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_range(
-            &self,
-            request: tonic::Request<super::DataRange>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ImportData",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ImportData"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn update_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataUpdate>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataChangeResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_number_before(
-            &self,
-            request: tonic::Request<super::DataNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/UpdateData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "UpdateData"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_data_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataUpdateRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn list_data_by_number_after(
-            &self,
-            request: tonic::Request<super::DataNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/UpdateDataByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "UpdateDataByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_audit_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataAuditListResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_time(
-            &self,
-            request: tonic::Request<super::DataGroupTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataAuditByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ListDataAuditByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataTime>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataChangeResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_earlier(
-            &self,
-            request: tonic::Request<super::DataGroupEarlier>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/DeleteData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "DeleteData"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_data_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_later(
-            &self,
-            request: tonic::Request<super::DataGroupLater>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/DeleteDataByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "DeleteDataByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_data_group_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupRangeDelete>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_range(
-            &self,
-            request: tonic::Request<super::DataGroupRange>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/DeleteDataGroupByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "DeleteDataGroupByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn read_data_timestamp(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataTime>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::TimestampReadResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_number_before(
-            &self,
-            request: tonic::Request<super::DataGroupNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ReadDataTimestamp",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ReadDataTimestamp"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_timestamp_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_by_number_after(
-            &self,
-            request: tonic::Request<super::DataGroupNumber>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataTimestampByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataTimestampByEarlier"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_timestamp_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataLater>,
         ) -> std::result::Result<
-            tonic::Response<super::DataListResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn read_data_set(
-            &self,
-            request: tonic::Request<super::DataSetTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataTimestampByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ListDataTimestampByLater"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_timestamp_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetReadResponse>,
+            tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_data_set_by_time(
-            &self,
-            request: tonic::Request<super::DataSetTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataTimestampByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ListDataTimestampByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn read_data_group_timestamp(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupTime>,
         ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
+            tonic::Response<super::TimestampReadResponse>,
             tonic::Status,
-        >;
-        async fn list_data_set_by_earlier(
-            &self,
-            request: tonic::Request<super::DataSetEarlier>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_set_by_later(
-            &self,
-            request: tonic::Request<super::DataSetLater>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_set_by_range(
-            &self,
-            request: tonic::Request<super::DataSetRange>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataSetListResponse>,
-            tonic::Status,
-        >;
-        async fn create_data(
-            &self,
-            request: tonic::Request<super::DataSchema>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
-            tonic::Status,
-        >;
-        async fn create_data_multiple(
-            &self,
-            request: tonic::Request<super::DataMultipleSchema>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
-            tonic::Status,
-        >;
-        async fn delete_data(
-            &self,
-            request: tonic::Request<super::DataTime>,
-        ) -> std::result::Result<
-            tonic::Response<super::DataChangeResponse>,
-            tonic::Status,
-        >;
-        async fn read_data_timestamp(
-            &self,
-            request: tonic::Request<super::DataTime>,
-        ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_timestamp_by_earlier(
-            &self,
-            request: tonic::Request<super::DataEarlier>,
-        ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_timestamp_by_later(
-            &self,
-            request: tonic::Request<super::DataLater>,
-        ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_timestamp_by_range(
-            &self,
-            request: tonic::Request<super::DataRange>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ReadDataGroupTimestamp",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "ReadDataGroupTimestamp"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_group_timestamp_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupEarlier>,
         ) -> std::result::Result<
             tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn read_data_group_timestamp(
-            &self,
-            request: tonic::Request<super::DataGroupTime>,
-        ) -> std::result::Result<
-            tonic::Response<super::TimestampReadResponse>,
-            tonic::Status,
-        >;
-        async fn list_data_group_timestamp_by_earlier(
-            &self,
-            request: tonic::Request<super::DataGroupEarlier>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataGroupTimestampByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "data.DataService",
+                        "ListDataGroupTimestampByEarlier",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_group_timestamp_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupLater>,
         ) -> std::result::Result<
             tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_timestamp_by_later(
-            &self,
-            request: tonic::Request<super::DataGroupLater>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataGroupTimestampByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataGroupTimestampByLater"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_data_group_timestamp_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupRange>,
         ) -> std::result::Result<
             tonic::Response<super::TimestampListResponse>,
             tonic::Status,
-        >;
-        async fn list_data_group_timestamp_by_range(
-            &self,
-            request: tonic::Request<super::DataGroupRange>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/ListDataGroupTimestampByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("data.DataService", "ListDataGroupTimestampByRange"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataTime>,
         ) -> std::result::Result<
-            tonic::Response<super::TimestampListResponse>,
+            tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn count_data(
-            &self,
-            request: tonic::Request<super::DataTime>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountData"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataEarlier>,
         ) -> std::result::Result<
             tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn count_data_by_earlier(
-            &self,
-            request: tonic::Request<super::DataEarlier>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataByEarlier"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataLater>,
         ) -> std::result::Result<
             tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn count_data_by_later(
-            &self,
-            request: tonic::Request<super::DataLater>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataByLater"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataRange>,
         ) -> std::result::Result<
             tonic::Response<super::DataCountResponse>,
             tonic::Status,
-        >;
-        async fn count_data_by_range(
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataGroup",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataGroup"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_group_by_earlier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataGroupByEarlier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByEarlier"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_group_by_later(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataGroupByLater",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByLater"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn count_data_group_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/data.DataService/CountDataGroupByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("data.DataService", "CountDataGroupByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod data_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DataServiceServer.
+    #[async_trait]
+    pub trait DataService: std::marker::Send + std::marker::Sync + 'static {
+        async fn read_data(
+            &self,
+            request: tonic::Request<super::DataTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_time(
+            &self,
+            request: tonic::Request<super::DataTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_earlier(
+            &self,
+            request: tonic::Request<super::DataEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_later(
+            &self,
+            request: tonic::Request<super::DataLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_range(
             &self,
             request: tonic::Request<super::DataRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         >;
-        async fn count_data_group(
+        async fn list_data_by_number_before(
+            &self,
+            request: tonic::Request<super::DataNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_number_after(
+            &self,
+            request: tonic::Request<super::DataNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_by_time(
             &self,
             request: tonic::Request<super::DataGroupTime>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         >;
-        async fn count_data_group_by_earlier(
+        async fn list_data_group_by_earlier(
             &self,
             request: tonic::Request<super::DataGroupEarlier>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         >;
-        async fn count_data_group_by_later(
+        async fn list_data_group_by_later(
             &self,
             request: tonic::Request<super::DataGroupLater>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         >;
-        async fn count_data_group_by_range(
+        async fn list_data_group_by_range(
             &self,
             request: tonic::Request<super::DataGroupRange>,
         ) -> std::result::Result<
-            tonic::Response<super::DataCountResponse>,
+            tonic::Response<super::DataListResponse>,
             tonic::Status,
         >;
-    }
-    #[derive(Debug)]
-    pub struct DataServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> DataServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
-        }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for DataServiceServer<T>
-    where
-        T: DataService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
+        async fn list_data_by_range_page(
+            &self,
+            request: tonic::Request<super::DataRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataPageResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_by_range_page(
+            &self,
+            request: tonic::Request<super::DataGroupRangePage>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataPageResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_by_number_before(
+            &self,
+            request: tonic::Request<super::DataGroupNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_by_number_after(
+            &self,
+            request: tonic::Request<super::DataGroupNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_latest(
+            &self,
+            request: tonic::Request<super::DataLatest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_bucketed(
+            &self,
+            request: tonic::Request<super::DataBucketed>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        async fn stats_data_by_range(
+            &self,
+            request: tonic::Request<super::DataRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataStatsResponse>,
+            tonic::Status,
+        >;
+        async fn stats_data_group_by_range(
+            &self,
+            request: tonic::Request<super::DataGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataStatsResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_slice(
+            &self,
+            request: tonic::Request<super::DataSlice>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataListResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamDataBySlice method.
+        type StreamDataBySliceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DataListResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn stream_data_by_slice(
+            &self,
+            request: tonic::Request<super::DataSlice>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamDataBySliceStream>,
+            tonic::Status,
+        >;
+        async fn stats_data_by_slice(
+            &self,
+            request: tonic::Request<super::DataSlice>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataStatsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamDataByRange method.
+        type StreamDataByRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DataListResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn stream_data_by_range(
+            &self,
+            request: tonic::Request<super::DataRange>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamDataByRangeStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamDataGroupByRange method.
+        type StreamDataGroupByRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DataListResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn stream_data_group_by_range(
+            &self,
+            request: tonic::Request<super::DataGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamDataGroupByRangeStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeData method.
+        type SubscribeDataStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DataSchema, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn subscribe_data(
+            &self,
+            request: tonic::Request<super::DataSubscribe>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeDataStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportData method.
+        type ExportDataStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DataExportChunk, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn export_data(
+            &self,
+            request: tonic::Request<super::DataExport>,
+        ) -> std::result::Result<tonic::Response<Self::ExportDataStream>, tonic::Status>;
+        async fn read_data_set(
+            &self,
+            request: tonic::Request<super::DataSetTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_time(
+            &self,
+            request: tonic::Request<super::DataSetTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_earlier(
+            &self,
+            request: tonic::Request<super::DataSetEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_later(
+            &self,
+            request: tonic::Request<super::DataSetLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_range(
+            &self,
+            request: tonic::Request<super::DataSetRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_by_slice_set(
+            &self,
+            request: tonic::Request<super::DataSlice>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn stats_data_by_slice_set(
+            &self,
+            request: tonic::Request<super::DataSlice>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataStatsResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_aligned(
+            &self,
+            request: tonic::Request<super::DataSetAligned>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetAlignedListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_number_before(
+            &self,
+            request: tonic::Request<super::DataSetNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_set_by_number_after(
+            &self,
+            request: tonic::Request<super::DataSetNumber>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataSetListResponse>,
+            tonic::Status,
+        >;
+        async fn create_data(
+            &self,
+            request: tonic::Request<super::DataSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataChangeResponse>,
+            tonic::Status,
+        >;
+        async fn create_data_multiple(
+            &self,
+            request: tonic::Request<super::DataMultipleSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCreateMultipleResponse>,
+            tonic::Status,
+        >;
+        async fn create_data_set(
+            &self,
+            request: tonic::Request<super::DataSetSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataChangeResponse>,
+            tonic::Status,
+        >;
+        async fn ingest_data(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::DataSchema>>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataIngestResponse>,
+            tonic::Status,
+        >;
+        async fn import_data(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::DataImport>>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataImportResponse>,
+            tonic::Status,
+        >;
+        async fn update_data(
+            &self,
+            request: tonic::Request<super::DataUpdate>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataChangeResponse>,
+            tonic::Status,
+        >;
+        async fn update_data_by_range(
+            &self,
+            request: tonic::Request<super::DataUpdateRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_audit_by_range(
+            &self,
+            request: tonic::Request<super::DataRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataAuditListResponse>,
+            tonic::Status,
+        >;
+        async fn delete_data(
+            &self,
+            request: tonic::Request<super::DataTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataChangeResponse>,
+            tonic::Status,
+        >;
+        async fn delete_data_by_range(
+            &self,
+            request: tonic::Request<super::DataRangeDelete>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn delete_data_group_by_range(
+            &self,
+            request: tonic::Request<super::DataGroupRangeDelete>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn read_data_timestamp(
+            &self,
+            request: tonic::Request<super::DataTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_timestamp_by_earlier(
+            &self,
+            request: tonic::Request<super::DataEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_timestamp_by_later(
+            &self,
+            request: tonic::Request<super::DataLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_timestamp_by_range(
+            &self,
+            request: tonic::Request<super::DataRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn read_data_group_timestamp(
+            &self,
+            request: tonic::Request<super::DataGroupTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_timestamp_by_earlier(
+            &self,
+            request: tonic::Request<super::DataGroupEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_timestamp_by_later(
+            &self,
+            request: tonic::Request<super::DataGroupLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn list_data_group_timestamp_by_range(
+            &self,
+            request: tonic::Request<super::DataGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimestampListResponse>,
+            tonic::Status,
+        >;
+        async fn count_data(
+            &self,
+            request: tonic::Request<super::DataTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_by_earlier(
+            &self,
+            request: tonic::Request<super::DataEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_by_later(
+            &self,
+            request: tonic::Request<super::DataLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_by_range(
+            &self,
+            request: tonic::Request<super::DataRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_group(
+            &self,
+            request: tonic::Request<super::DataGroupTime>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_group_by_earlier(
+            &self,
+            request: tonic::Request<super::DataGroupEarlier>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_group_by_later(
+            &self,
+            request: tonic::Request<super::DataGroupLater>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+        async fn count_data_group_by_range(
+            &self,
+            request: tonic::Request<super::DataGroupRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::DataCountResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct DataServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> DataServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DataServiceServer<T>
+    where
+        T: DataService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
     {
         type Response = http::Response<tonic::body::Body>;
         type Error = std::convert::Infallible;
@@ -1571,21 +2761,991 @@ pub mod data_service_server {
             match req.uri().path() {
                 "/data.DataService/ReadData" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadDataSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
-                    for ReadDataSvc<T> {
-                        type Response = super::DataReadResponse;
+                    struct ReadDataSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
+                    for ReadDataSvc<T> {
+                        type Response = super::DataReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::read_data(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByTimeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
+                    for ListDataByTimeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_time(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByTimeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByEarlier" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByEarlierSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataEarlier>
+                    for ListDataByEarlierSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataEarlier>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_earlier(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByEarlierSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByLater" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByLaterSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataLater>
+                    for ListDataByLaterSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataLater>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_later(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByLaterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataRange>
+                    for ListDataByRangeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByNumberBefore" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByNumberBeforeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataNumber>
+                    for ListDataByNumberBeforeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_number_before(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByNumberBeforeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByNumberAfter" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByNumberAfterSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataNumber>
+                    for ListDataByNumberAfterSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_number_after(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByNumberAfterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByTimeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupTime>
+                    for ListDataGroupByTimeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_time(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByTimeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByEarlier" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByEarlierSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupEarlier>
+                    for ListDataGroupByEarlierSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupEarlier>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_earlier(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByEarlierSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByLater" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByLaterSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupLater>
+                    for ListDataGroupByLaterSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupLater>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_later(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByLaterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupRange>
+                    for ListDataGroupByRangeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataByRangePage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataByRangePageSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataRangePage>
+                    for ListDataByRangePageSvc<T> {
+                        type Response = super::DataPageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataRangePage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_range_page(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataByRangePageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByRangePage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByRangePageSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupRangePage>
+                    for ListDataGroupByRangePageSvc<T> {
+                        type Response = super::DataPageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupRangePage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_range_page(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByRangePageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByNumberBefore" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByNumberBeforeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupNumber>
+                    for ListDataGroupByNumberBeforeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_number_before(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByNumberBeforeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataGroupByNumberAfter" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataGroupByNumberAfterSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupNumber>
+                    for ListDataGroupByNumberAfterSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupNumber>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_group_by_number_after(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataGroupByNumberAfterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataLatest" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataLatestSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataLatest>
+                    for ListDataLatestSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataLatest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_latest(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataLatestSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataBucketed" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataBucketedSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataBucketed>
+                    for ListDataBucketedSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataBucketed>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_bucketed(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataBucketedSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/StatsDataByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatsDataByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataRange>
+                    for StatsDataByRangeSvc<T> {
+                        type Response = super::DataStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::stats_data_by_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StatsDataByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/StatsDataGroupByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatsDataGroupByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupRange>
+                    for StatsDataGroupByRangeSvc<T> {
+                        type Response = super::DataStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::stats_data_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StatsDataGroupByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataBySlice" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataBySliceSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSlice>
+                    for ListDataBySliceSvc<T> {
+                        type Response = super::DataListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataSlice>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_by_slice(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataBySliceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/StreamDataBySlice" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamDataBySliceSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ServerStreamingService<super::DataSlice>
+                    for StreamDataBySliceSvc<T> {
+                        type Response = super::DataListResponse;
+                        type ResponseStream = T::StreamDataBySliceStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataSlice>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::stream_data_by_slice(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StreamDataBySliceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/StatsDataBySlice" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatsDataBySliceSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSlice>
+                    for StatsDataBySliceSvc<T> {
+                        type Response = super::DataStatsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataTime>,
+                            request: tonic::Request<super::DataSlice>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::read_data(&inner, request).await
+                                <T as DataService>::stats_data_by_slice(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1596,7 +3756,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadDataSvc(inner);
+                        let method = StatsDataBySliceSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1612,23 +3772,212 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByTime" => {
+                "/data.DataService/StreamDataByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByTimeSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
-                    for ListDataByTimeSvc<T> {
+                    struct StreamDataByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ServerStreamingService<super::DataRange>
+                    for StreamDataByRangeSvc<T> {
+                        type Response = super::DataListResponse;
+                        type ResponseStream = T::StreamDataByRangeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::stream_data_by_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StreamDataByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/StreamDataGroupByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamDataGroupByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ServerStreamingService<super::DataGroupRange>
+                    for StreamDataGroupByRangeSvc<T> {
                         type Response = super::DataListResponse;
+                        type ResponseStream = T::StreamDataGroupByRangeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataGroupRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::stream_data_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StreamDataGroupByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/SubscribeData" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeDataSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ServerStreamingService<super::DataSubscribe>
+                    for SubscribeDataSvc<T> {
+                        type Response = super::DataSchema;
+                        type ResponseStream = T::SubscribeDataStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataSubscribe>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::subscribe_data(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ExportData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportDataSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ServerStreamingService<super::DataExport>
+                    for ExportDataSvc<T> {
+                        type Response = super::DataExportChunk;
+                        type ResponseStream = T::ExportDataStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataExport>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::export_data(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ReadDataSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadDataSetSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSetTime>
+                    for ReadDataSetSvc<T> {
+                        type Response = super::DataSetReadResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataTime>,
+                            request: tonic::Request<super::DataSetTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_time(&inner, request).await
+                                <T as DataService>::read_data_set(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1639,7 +3988,51 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByTimeSvc(inner);
+                        let method = ReadDataSetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/data.DataService/ListDataSetByTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDataSetByTimeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSetTime>
+                    for ListDataSetByTimeSvc<T> {
+                        type Response = super::DataSetListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataSetTime>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DataService>::list_data_set_by_time(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDataSetByTimeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1655,23 +4048,28 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByEarlier" => {
+                "/data.DataService/ListDataSetByEarlier" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByEarlierSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataEarlier>
-                    for ListDataByEarlierSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct ListDataSetByEarlierSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataSetEarlier>
+                    for ListDataSetByEarlierSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataEarlier>,
+                            request: tonic::Request<super::DataSetEarlier>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_earlier(&inner, request)
+                                <T as DataService>::list_data_set_by_earlier(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1683,7 +4081,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByEarlierSvc(inner);
+                        let method = ListDataSetByEarlierSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1699,23 +4097,23 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByLater" => {
+                "/data.DataService/ListDataSetByLater" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByLaterSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataLater>
-                    for ListDataByLaterSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct ListDataSetByLaterSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSetLater>
+                    for ListDataSetByLaterSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataLater>,
+                            request: tonic::Request<super::DataSetLater>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_later(&inner, request)
+                                <T as DataService>::list_data_set_by_later(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1727,7 +4125,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByLaterSvc(inner);
+                        let method = ListDataSetByLaterSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1743,23 +4141,23 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByRange" => {
+                "/data.DataService/ListDataSetByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByRangeSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataRange>
-                    for ListDataByRangeSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct ListDataSetByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSetRange>
+                    for ListDataSetByRangeSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataRange>,
+                            request: tonic::Request<super::DataSetRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_range(&inner, request)
+                                <T as DataService>::list_data_set_by_range(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1771,7 +4169,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByRangeSvc(inner);
+                        let method = ListDataSetByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1787,26 +4185,23 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByNumberBefore" => {
+                "/data.DataService/ListDataBySliceSet" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByNumberBeforeSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataNumber>
-                    for ListDataByNumberBeforeSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct ListDataBySliceSetSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSlice>
+                    for ListDataBySliceSetSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataNumber>,
+                            request: tonic::Request<super::DataSlice>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_number_before(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as DataService>::list_data_by_slice_set(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1818,7 +4213,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByNumberBeforeSvc(inner);
+                        let method = ListDataBySliceSetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1834,26 +4229,23 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataByNumberAfter" => {
+                "/data.DataService/StatsDataBySliceSet" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataByNumberAfterSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataNumber>
-                    for ListDataByNumberAfterSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct StatsDataBySliceSetSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSlice>
+                    for StatsDataBySliceSetSvc<T> {
+                        type Response = super::DataStatsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataNumber>,
+                            request: tonic::Request<super::DataSlice>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_by_number_after(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as DataService>::stats_data_by_slice_set(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1865,7 +4257,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataByNumberAfterSvc(inner);
+                        let method = StatsDataBySliceSetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1881,25 +4273,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByTime" => {
+                "/data.DataService/ListDataSetAligned" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByTimeSvc<T: DataService>(pub Arc<T>);
+                    struct ListDataSetAlignedSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupTime>
-                    for ListDataGroupByTimeSvc<T> {
-                        type Response = super::DataListResponse;
+                    > tonic::server::UnaryService<super::DataSetAligned>
+                    for ListDataSetAlignedSvc<T> {
+                        type Response = super::DataSetAlignedListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupTime>,
+                            request: tonic::Request<super::DataSetAligned>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_time(&inner, request)
+                                <T as DataService>::list_data_set_aligned(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1911,7 +4303,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByTimeSvc(inner);
+                        let method = ListDataSetAlignedSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1927,25 +4319,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByEarlier" => {
+                "/data.DataService/ListDataSetByNumberBefore" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByEarlierSvc<T: DataService>(pub Arc<T>);
+                    struct ListDataSetByNumberBeforeSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupEarlier>
-                    for ListDataGroupByEarlierSvc<T> {
-                        type Response = super::DataListResponse;
+                    > tonic::server::UnaryService<super::DataSetNumber>
+                    for ListDataSetByNumberBeforeSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupEarlier>,
+                            request: tonic::Request<super::DataSetNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_earlier(
+                                <T as DataService>::list_data_set_by_number_before(
                                         &inner,
                                         request,
                                     )
@@ -1960,7 +4352,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByEarlierSvc(inner);
+                        let method = ListDataSetByNumberBeforeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1976,25 +4368,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByLater" => {
+                "/data.DataService/ListDataSetByNumberAfter" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByLaterSvc<T: DataService>(pub Arc<T>);
+                    struct ListDataSetByNumberAfterSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupLater>
-                    for ListDataGroupByLaterSvc<T> {
-                        type Response = super::DataListResponse;
+                    > tonic::server::UnaryService<super::DataSetNumber>
+                    for ListDataSetByNumberAfterSvc<T> {
+                        type Response = super::DataSetListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupLater>,
+                            request: tonic::Request<super::DataSetNumber>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_later(
+                                <T as DataService>::list_data_set_by_number_after(
                                         &inner,
                                         request,
                                     )
@@ -2009,7 +4401,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByLaterSvc(inner);
+                        let method = ListDataSetByNumberAfterSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2025,29 +4417,23 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByRange" => {
+                "/data.DataService/CreateData" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByRangeSvc<T: DataService>(pub Arc<T>);
-                    impl<
-                        T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupRange>
-                    for ListDataGroupByRangeSvc<T> {
-                        type Response = super::DataListResponse;
+                    struct CreateDataSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataSchema>
+                    for CreateDataSvc<T> {
+                        type Response = super::DataChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupRange>,
+                            request: tonic::Request<super::DataSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_range(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as DataService>::create_data(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2058,7 +4444,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByRangeSvc(inner);
+                        let method = CreateDataSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2074,28 +4460,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByNumberBefore" => {
+                "/data.DataService/CreateDataMultiple" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByNumberBeforeSvc<T: DataService>(pub Arc<T>);
+                    struct CreateDataMultipleSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupNumber>
-                    for ListDataGroupByNumberBeforeSvc<T> {
-                        type Response = super::DataListResponse;
+                    > tonic::server::UnaryService<super::DataMultipleSchema>
+                    for CreateDataMultipleSvc<T> {
+                        type Response = super::DataCreateMultipleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupNumber>,
+                            request: tonic::Request<super::DataMultipleSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_number_before(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as DataService>::create_data_multiple(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2107,7 +4490,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByNumberBeforeSvc(inner);
+                        let method = CreateDataMultipleSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2123,29 +4506,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataGroupByNumberAfter" => {
+                "/data.DataService/CreateDataSet" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataGroupByNumberAfterSvc<T: DataService>(pub Arc<T>);
+                    struct CreateDataSetSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataGroupNumber>
-                    for ListDataGroupByNumberAfterSvc<T> {
-                        type Response = super::DataListResponse;
+                    > tonic::server::UnaryService<super::DataSetSchema>
+                    for CreateDataSetSvc<T> {
+                        type Response = super::DataChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataGroupNumber>,
+                            request: tonic::Request<super::DataSetSchema>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_group_by_number_after(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as DataService>::create_data_set(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2156,7 +4535,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataGroupByNumberAfterSvc(inner);
+                        let method = CreateDataSetSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2172,23 +4551,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ReadDataSet" => {
+                "/data.DataService/IngestData" => {
                     #[allow(non_camel_case_types)]
-                    struct ReadDataSetSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataSetTime>
-                    for ReadDataSetSvc<T> {
-                        type Response = super::DataSetReadResponse;
+                    struct IngestDataSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ClientStreamingService<super::DataSchema>
+                    for IngestDataSvc<T> {
+                        type Response = super::DataIngestResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSetTime>,
+                            request: tonic::Request<tonic::Streaming<super::DataSchema>>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::read_data_set(&inner, request).await
+                                <T as DataService>::ingest_data(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2199,7 +4580,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ReadDataSetSvc(inner);
+                        let method = IngestDataSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2210,29 +4591,30 @@ pub mod data_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataSetByTime" => {
+                "/data.DataService/ImportData" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataSetByTimeSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataSetTime>
-                    for ListDataSetByTimeSvc<T> {
-                        type Response = super::DataSetListResponse;
+                    struct ImportDataSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::ClientStreamingService<super::DataImport>
+                    for ImportDataSvc<T> {
+                        type Response = super::DataImportResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSetTime>,
+                            request: tonic::Request<tonic::Streaming<super::DataImport>>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_set_by_time(&inner, request)
-                                    .await
+                                <T as DataService>::import_data(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2243,7 +4625,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataSetByTimeSvc(inner);
+                        let method = ImportDataSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2254,34 +4636,28 @@ pub mod data_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataSetByEarlier" => {
+                "/data.DataService/UpdateData" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataSetByEarlierSvc<T: DataService>(pub Arc<T>);
-                    impl<
-                        T: DataService,
-                    > tonic::server::UnaryService<super::DataSetEarlier>
-                    for ListDataSetByEarlierSvc<T> {
-                        type Response = super::DataSetListResponse;
+                    struct UpdateDataSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataUpdate>
+                    for UpdateDataSvc<T> {
+                        type Response = super::DataChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSetEarlier>,
+                            request: tonic::Request<super::DataUpdate>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_set_by_earlier(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as DataService>::update_data(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2292,7 +4668,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataSetByEarlierSvc(inner);
+                        let method = UpdateDataSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2308,23 +4684,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataSetByLater" => {
+                "/data.DataService/UpdateDataByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataSetByLaterSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataSetLater>
-                    for ListDataSetByLaterSvc<T> {
-                        type Response = super::DataSetListResponse;
+                    struct UpdateDataByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataUpdateRange>
+                    for UpdateDataByRangeSvc<T> {
+                        type Response = super::DataCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSetLater>,
+                            request: tonic::Request<super::DataUpdateRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_set_by_later(&inner, request)
+                                <T as DataService>::update_data_by_range(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2336,7 +4714,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataSetByLaterSvc(inner);
+                        let method = UpdateDataByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2352,23 +4730,26 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/ListDataSetByRange" => {
+                "/data.DataService/ListDataAuditByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct ListDataSetByRangeSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataSetRange>
-                    for ListDataSetByRangeSvc<T> {
-                        type Response = super::DataSetListResponse;
+                    struct ListDataAuditByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataRange>
+                    for ListDataAuditByRangeSvc<T> {
+                        type Response = super::DataAuditListResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSetRange>,
+                            request: tonic::Request<super::DataRange>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::list_data_set_by_range(&inner, request)
+                                <T as DataService>::list_data_audit_by_range(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2380,7 +4761,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListDataSetByRangeSvc(inner);
+                        let method = ListDataAuditByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2396,11 +4777,11 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/CreateData" => {
+                "/data.DataService/DeleteData" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateDataSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataSchema>
-                    for CreateDataSvc<T> {
+                    struct DeleteDataSvc<T: DataService>(pub Arc<T>);
+                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
+                    for DeleteDataSvc<T> {
                         type Response = super::DataChangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2408,11 +4789,11 @@ pub mod data_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataSchema>,
+                            request: tonic::Request<super::DataTime>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::create_data(&inner, request).await
+                                <T as DataService>::delete_data(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2423,7 +4804,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateDataSvc(inner);
+                        let method = DeleteDataSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2439,25 +4820,25 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/CreateDataMultiple" => {
+                "/data.DataService/DeleteDataByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateDataMultipleSvc<T: DataService>(pub Arc<T>);
+                    struct DeleteDataByRangeSvc<T: DataService>(pub Arc<T>);
                     impl<
                         T: DataService,
-                    > tonic::server::UnaryService<super::DataMultipleSchema>
-                    for CreateDataMultipleSvc<T> {
-                        type Response = super::DataChangeResponse;
+                    > tonic::server::UnaryService<super::DataRangeDelete>
+                    for DeleteDataByRangeSvc<T> {
+                        type Response = super::DataCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataMultipleSchema>,
+                            request: tonic::Request<super::DataRangeDelete>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::create_data_multiple(&inner, request)
+                                <T as DataService>::delete_data_by_range(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2469,7 +4850,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateDataMultipleSvc(inner);
+                        let method = DeleteDataByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2485,23 +4866,29 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/data.DataService/DeleteData" => {
+                "/data.DataService/DeleteDataGroupByRange" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteDataSvc<T: DataService>(pub Arc<T>);
-                    impl<T: DataService> tonic::server::UnaryService<super::DataTime>
-                    for DeleteDataSvc<T> {
-                        type Response = super::DataChangeResponse;
+                    struct DeleteDataGroupByRangeSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::DataGroupRangeDelete>
+                    for DeleteDataGroupByRangeSvc<T> {
+                        type Response = super::DataCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DataTime>,
+                            request: tonic::Request<super::DataGroupRangeDelete>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as DataService>::delete_data(&inner, request).await
+                                <T as DataService>::delete_data_group_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -2512,7 +4899,7 @@ pub mod data_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteDataSvc(inner);
+                        let method = DeleteDataGroupByRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
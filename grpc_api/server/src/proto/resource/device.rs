@@ -84,6 +84,19 @@ pub struct DeviceOption {
     pub name: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeviceOptionPage {
+    #[prost(bytes = "vec", optional, tag = "1")]
+    pub gateway_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub type_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, optional, tag = "3")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "5")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GatewayOption {
     #[prost(bytes = "vec", optional, tag = "1")]
     pub type_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
@@ -233,6 +246,22 @@ pub struct TypeConfigUpdate {
     #[prost(string, optional, tag = "5")]
     pub category: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IntegrityOption {
+    #[prost(string, optional, tag = "1")]
+    pub table: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IntegrityViolationSchema {
+    #[prost(string, tag = "1")]
+    pub table: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag = "4")]
+    pub count: i64,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeviceReadResponse {
     #[prost(message, optional, tag = "1")]
@@ -243,6 +272,13 @@ pub struct DeviceListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<DeviceSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DevicePageResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<DeviceSchema>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeviceCreateResponse {
     #[prost(bytes = "vec", tag = "1")]
@@ -311,6 +347,11 @@ pub struct TypeConfigListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<TypeConfigSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IntegrityViolationListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<IntegrityViolationSchema>,
+}
 /// Generated client implementations.
 pub mod device_service_client {
     #![allow(
@@ -570,6 +611,30 @@ pub mod device_service_client {
                 .insert(GrpcMethod::new("device.DeviceService", "ListDeviceOption"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_device_option_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeviceOptionPage>,
+        ) -> std::result::Result<
+            tonic::Response<super::DevicePageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/device.DeviceService/ListDeviceOptionPage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("device.DeviceService", "ListDeviceOptionPage"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn create_device(
             &mut self,
             request: impl tonic::IntoRequest<super::DeviceSchema>,
@@ -1434,6 +1499,32 @@ pub mod device_service_client {
                 .insert(GrpcMethod::new("device.DeviceService", "DeleteTypeConfig"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_integrity_violation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IntegrityOption>,
+        ) -> std::result::Result<
+            tonic::Response<super::IntegrityViolationListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/device.DeviceService/ListIntegrityViolation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("device.DeviceService", "ListIntegrityViolation"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1498,6 +1589,13 @@ pub mod device_service_server {
             tonic::Response<super::DeviceListResponse>,
             tonic::Status,
         >;
+        async fn list_device_option_page(
+            &self,
+            request: tonic::Request<super::DeviceOptionPage>,
+        ) -> std::result::Result<
+            tonic::Response<super::DevicePageResponse>,
+            tonic::Status,
+        >;
         async fn create_device(
             &self,
             request: tonic::Request<super::DeviceSchema>,
@@ -1750,6 +1848,13 @@ pub mod device_service_server {
             tonic::Response<super::ConfigChangeResponse>,
             tonic::Status,
         >;
+        async fn list_integrity_violation(
+            &self,
+            request: tonic::Request<super::IntegrityOption>,
+        ) -> std::result::Result<
+            tonic::Response<super::IntegrityViolationListResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct DeviceServiceServer<T> {
@@ -2141,6 +2246,55 @@ pub mod device_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/device.DeviceService/ListDeviceOptionPage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDeviceOptionPageSvc<T: DeviceService>(pub Arc<T>);
+                    impl<
+                        T: DeviceService,
+                    > tonic::server::UnaryService<super::DeviceOptionPage>
+                    for ListDeviceOptionPageSvc<T> {
+                        type Response = super::DevicePageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeviceOptionPage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeviceService>::list_device_option_page(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListDeviceOptionPageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/device.DeviceService/CreateDevice" => {
                     #[allow(non_camel_case_types)]
                     struct CreateDeviceSvc<T: DeviceService>(pub Arc<T>);
@@ -3743,6 +3897,55 @@ pub mod device_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/device.DeviceService/ListIntegrityViolation" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListIntegrityViolationSvc<T: DeviceService>(pub Arc<T>);
+                    impl<
+                        T: DeviceService,
+                    > tonic::server::UnaryService<super::IntegrityOption>
+                    for ListIntegrityViolationSvc<T> {
+                        type Response = super::IntegrityViolationListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IntegrityOption>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeviceService>::list_integrity_violation(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListIntegrityViolationSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
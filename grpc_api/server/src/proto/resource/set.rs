@@ -165,7 +165,10 @@ pub struct SetCreateResponse {
     pub id: ::prost::alloc::vec::Vec<u8>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct SetChangeResponse {}
+pub struct SetChangeResponse {
+    #[prost(uint32, tag = "1")]
+    pub violated: u32,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TemplateReadResponse {
     #[prost(message, optional, tag = "1")]
@@ -76,6 +76,23 @@ pub struct SliceOption {
     pub end: ::core::option::Option<i64>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SliceOptionPage {
+    #[prost(bytes = "vec", optional, tag = "1")]
+    pub device_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub model_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, optional, tag = "3")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "4")]
+    pub begin: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub end: ::core::option::Option<i64>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SliceGroupTime {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub device_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -165,6 +182,74 @@ pub struct SliceSetOption {
     #[prost(int64, optional, tag = "4")]
     pub end: ::core::option::Option<i64>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SliceRuleSchema {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub device_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub model_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub index: u32,
+    #[prost(string, tag = "5")]
+    pub condition: ::prost::alloc::string::String,
+    #[prost(double, tag = "6")]
+    pub threshold: f64,
+    #[prost(int64, tag = "7")]
+    pub min_duration: i64,
+    #[prost(int64, tag = "8")]
+    pub max_gap: i64,
+    #[prost(string, tag = "9")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag = "10")]
+    pub checkpoint: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SliceRuleOption {
+    #[prost(bytes = "vec", optional, tag = "1")]
+    pub device_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub model_id: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SliceRuleUpdate {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, optional, tag = "2")]
+    pub condition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(double, optional, tag = "3")]
+    pub threshold: ::core::option::Option<f64>,
+    #[prost(int64, optional, tag = "4")]
+    pub min_duration: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub max_gap: ::core::option::Option<i64>,
+    #[prost(string, optional, tag = "6")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SliceRuleRange {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(int64, tag = "2")]
+    pub begin: i64,
+    #[prost(int64, tag = "3")]
+    pub end: i64,
+    #[prost(bool, tag = "4")]
+    pub dry_run: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SliceRuleApply {
+    #[prost(bool, tag = "1")]
+    pub dry_run: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SliceRuleCount {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SliceReadResponse {
     #[prost(message, optional, tag = "1")]
@@ -175,6 +260,13 @@ pub struct SliceListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<SliceSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SlicePageResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<SliceSchema>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub cursor: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SliceCreateResponse {
     #[prost(int32, tag = "1")]
@@ -192,6 +284,21 @@ pub struct SliceSetListResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<SliceSetSchema>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SliceRuleReadResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: ::core::option::Option<SliceRuleSchema>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SliceRuleListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<SliceRuleSchema>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SliceRuleApplyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<SliceRuleCount>,
+}
 /// Generated client implementations.
 pub mod slice_service_client {
     #![allow(
@@ -451,6 +558,30 @@ pub mod slice_service_client {
                 .insert(GrpcMethod::new("slice.SliceService", "ListSliceOption"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_slice_option_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceOptionPage>,
+        ) -> std::result::Result<
+            tonic::Response<super::SlicePageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/ListSliceOptionPage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "ListSliceOptionPage"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn list_slice_group_by_time(
             &mut self,
             request: impl tonic::IntoRequest<super::SliceGroupTime>,
@@ -837,6 +968,174 @@ pub mod slice_service_client {
                 .insert(GrpcMethod::new("slice.SliceService", "DeleteSliceSet"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn read_slice_rule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceId>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleReadResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/ReadSliceRule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "ReadSliceRule"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_slice_rule_option(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceRuleOption>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/ListSliceRuleOption",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "ListSliceRuleOption"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_slice_rule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceRuleSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/CreateSliceRule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "CreateSliceRule"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_slice_rule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceRuleUpdate>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceChangeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/UpdateSliceRule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "UpdateSliceRule"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_slice_rule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceId>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceChangeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/DeleteSliceRule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "DeleteSliceRule"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn generate_slice_by_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceRuleRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/GenerateSliceByRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "GenerateSliceByRange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn apply_slice_rule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SliceRuleApply>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleApplyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/slice.SliceService/ApplySliceRule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("slice.SliceService", "ApplySliceRule"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -901,6 +1200,13 @@ pub mod slice_service_server {
             tonic::Response<super::SliceListResponse>,
             tonic::Status,
         >;
+        async fn list_slice_option_page(
+            &self,
+            request: tonic::Request<super::SliceOptionPage>,
+        ) -> std::result::Result<
+            tonic::Response<super::SlicePageResponse>,
+            tonic::Status,
+        >;
         async fn list_slice_group_by_time(
             &self,
             request: tonic::Request<super::SliceGroupTime>,
@@ -1013,36 +1319,85 @@ pub mod slice_service_server {
             tonic::Response<super::SliceChangeResponse>,
             tonic::Status,
         >;
-    }
-    #[derive(Debug)]
-    pub struct SliceServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> SliceServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        async fn read_slice_rule(
+            &self,
+            request: tonic::Request<super::SliceId>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleReadResponse>,
+            tonic::Status,
+        >;
+        async fn list_slice_rule_option(
+            &self,
+            request: tonic::Request<super::SliceRuleOption>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleListResponse>,
+            tonic::Status,
+        >;
+        async fn create_slice_rule(
+            &self,
+            request: tonic::Request<super::SliceRuleSchema>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceCreateResponse>,
+            tonic::Status,
+        >;
+        async fn update_slice_rule(
+            &self,
+            request: tonic::Request<super::SliceRuleUpdate>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceChangeResponse>,
+            tonic::Status,
+        >;
+        async fn delete_slice_rule(
+            &self,
+            request: tonic::Request<super::SliceId>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceChangeResponse>,
+            tonic::Status,
+        >;
+        async fn generate_slice_by_range(
+            &self,
+            request: tonic::Request<super::SliceRuleRange>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceListResponse>,
+            tonic::Status,
+        >;
+        async fn apply_slice_rule(
+            &self,
+            request: tonic::Request<super::SliceRuleApply>,
+        ) -> std::result::Result<
+            tonic::Response<super::SliceRuleApplyResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct SliceServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> SliceServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
         }
         /// Enable decompressing requests with the given encoding.
         #[must_use]
@@ -1407,6 +1762,52 @@ pub mod slice_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/slice.SliceService/ListSliceOptionPage" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSliceOptionPageSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceOptionPage>
+                    for ListSliceOptionPageSvc<T> {
+                        type Response = super::SlicePageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceOptionPage>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::list_slice_option_page(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListSliceOptionPageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/slice.SliceService/ListSliceGroupByTime" => {
                     #[allow(non_camel_case_types)]
                     struct ListSliceGroupByTimeSvc<T: SliceService>(pub Arc<T>);
@@ -2140,6 +2541,325 @@ pub mod slice_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/slice.SliceService/ReadSliceRule" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadSliceRuleSvc<T: SliceService>(pub Arc<T>);
+                    impl<T: SliceService> tonic::server::UnaryService<super::SliceId>
+                    for ReadSliceRuleSvc<T> {
+                        type Response = super::SliceRuleReadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceId>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::read_slice_rule(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadSliceRuleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/ListSliceRuleOption" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSliceRuleOptionSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceRuleOption>
+                    for ListSliceRuleOptionSvc<T> {
+                        type Response = super::SliceRuleListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceRuleOption>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::list_slice_rule_option(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListSliceRuleOptionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/CreateSliceRule" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateSliceRuleSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceRuleSchema>
+                    for CreateSliceRuleSvc<T> {
+                        type Response = super::SliceCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceRuleSchema>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::create_slice_rule(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateSliceRuleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/UpdateSliceRule" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateSliceRuleSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceRuleUpdate>
+                    for UpdateSliceRuleSvc<T> {
+                        type Response = super::SliceChangeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceRuleUpdate>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::update_slice_rule(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateSliceRuleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/DeleteSliceRule" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteSliceRuleSvc<T: SliceService>(pub Arc<T>);
+                    impl<T: SliceService> tonic::server::UnaryService<super::SliceId>
+                    for DeleteSliceRuleSvc<T> {
+                        type Response = super::SliceChangeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceId>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::delete_slice_rule(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteSliceRuleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/GenerateSliceByRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct GenerateSliceByRangeSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceRuleRange>
+                    for GenerateSliceByRangeSvc<T> {
+                        type Response = super::SliceListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceRuleRange>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::generate_slice_by_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GenerateSliceByRangeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/slice.SliceService/ApplySliceRule" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApplySliceRuleSvc<T: SliceService>(pub Arc<T>);
+                    impl<
+                        T: SliceService,
+                    > tonic::server::UnaryService<super::SliceRuleApply>
+                    for ApplySliceRuleSvc<T> {
+                        type Response = super::SliceRuleApplyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SliceRuleApply>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SliceService>::apply_slice_rule(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ApplySliceRuleSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
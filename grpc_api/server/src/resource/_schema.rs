@@ -1,4 +1,4 @@
-use chrono::{Utc, TimeZone};
+use chrono::{Utc, TimeZone, Duration};
 use uuid::Uuid;
 use bbthings_database::{DataType, DataValue, ArrayDataValue};
 use bbthings_database::{
@@ -6,11 +6,12 @@ use bbthings_database::{
     TypeSchema, TypeConfigSchema, DeviceConfigSchema, GatewayConfigSchema,
     GroupModelSchema, GroupDeviceSchema, GroupGatewaySchema,
     SetSchema, SetMember, SetTemplateSchema, SetTemplateMember,
-    DataSchema, DataSetSchema, BufferSchema, BufferSetSchema,
-    SliceSchema, SliceSetSchema
+    DataSchema, DataSetSchema, DataSetAlignedSchema, DataAuditSchema, DataStatsSchema, BufferSchema, BufferSetSchema, BufferTransitionSchema, BufferHistorySchema,
+    SliceSchema, SliceSetSchema, SliceRuleSchema, RetentionSchema, CompressionSchema, IntegrityViolationSchema
 };
+use bbthings_database::resource::slice::SliceCondition;
 use crate::proto::resource::{
-    model, device, group, set, data, buffer, slice
+    model, device, group, set, data, buffer, slice, retention, compression
 };
 
 impl From<ModelSchema> for model::ModelSchema {
@@ -453,6 +454,88 @@ impl From<data::DataSchema> for DataSchema {
     }
 }
 
+impl From<DataStatsSchema> for data::DataStatsSchema {
+    fn from(value: DataStatsSchema) -> Self {
+        Self {
+            device_id: value.device_id.as_bytes().to_vec(),
+            model_id: value.model_id.as_bytes().to_vec(),
+            index: value.index as u32,
+            count: value.count as u64,
+            data_type: value.first.get_type().into(),
+            min_bytes: value.min.to_bytes(),
+            max_bytes: value.max.to_bytes(),
+            mean: value.mean,
+            stddev: value.stddev,
+            first_bytes: value.first.to_bytes(),
+            last_bytes: value.last.to_bytes()
+        }
+    }
+}
+
+impl From<data::DataStatsSchema> for DataStatsSchema {
+    fn from(value: data::DataStatsSchema) -> Self {
+        let data_type = DataType::from(value.data_type);
+        Self {
+            device_id: Uuid::from_slice(&value.device_id).unwrap_or_default(),
+            model_id: Uuid::from_slice(&value.model_id).unwrap_or_default(),
+            index: value.index as usize,
+            count: value.count as usize,
+            min: DataValue::from_bytes(&value.min_bytes, data_type.clone()),
+            max: DataValue::from_bytes(&value.max_bytes, data_type.clone()),
+            mean: value.mean,
+            stddev: value.stddev,
+            first: DataValue::from_bytes(&value.first_bytes, data_type.clone()),
+            last: DataValue::from_bytes(&value.last_bytes, data_type)
+        }
+    }
+}
+
+impl From<DataAuditSchema> for data::DataAuditSchema {
+    fn from(value: DataAuditSchema) -> Self {
+        Self {
+            id: value.id,
+            device_id: value.device_id.as_bytes().to_vec(),
+            model_id: value.model_id.as_bytes().to_vec(),
+            timestamp: value.timestamp.timestamp_micros(),
+            tag: value.tag as i32,
+            data_bytes: ArrayDataValue::from_vec(&value.data).to_bytes(),
+            data_type: value.data.into_iter().map(|e| e.get_type().into()).collect(),
+            new_tag: value.new_tag as i32,
+            new_data_bytes: ArrayDataValue::from_vec(&value.new_data).to_bytes(),
+            new_data_type: value.new_data.into_iter().map(|e| e.get_type().into()).collect(),
+            changed_at: value.changed_at.timestamp_micros(),
+            changed_by: value.changed_by
+        }
+    }
+}
+
+impl From<data::DataAuditSchema> for DataAuditSchema {
+    fn from(value: data::DataAuditSchema) -> Self {
+        Self {
+            id: value.id,
+            device_id: Uuid::from_slice(&value.device_id).unwrap_or_default(),
+            model_id: Uuid::from_slice(&value.model_id).unwrap_or_default(),
+            timestamp: Utc.timestamp_nanos(value.timestamp * 1000),
+            tag: value.tag as i16,
+            data: ArrayDataValue::from_bytes(
+                    &value.data_bytes,
+                    value.data_type.into_iter().map(|e| DataType::from(e))
+                    .collect::<Vec<DataType>>()
+                    .as_slice()
+                ).to_vec(),
+            new_tag: value.new_tag as i16,
+            new_data: ArrayDataValue::from_bytes(
+                    &value.new_data_bytes,
+                    value.new_data_type.into_iter().map(|e| DataType::from(e))
+                    .collect::<Vec<DataType>>()
+                    .as_slice()
+                ).to_vec(),
+            changed_at: Utc.timestamp_nanos(value.changed_at * 1000),
+            changed_by: value.changed_by
+        }
+    }
+}
+
 impl From<DataSetSchema> for data::DataSetSchema {
     fn from(value: DataSetSchema) -> Self {
         Self {
@@ -481,6 +564,34 @@ impl From<data::DataSetSchema> for DataSetSchema {
     }
 }
 
+impl From<DataSetAlignedSchema> for data::DataSetAlignedSchema {
+    fn from(value: DataSetAlignedSchema) -> Self {
+        Self {
+            set_id: value.set_id.as_bytes().to_vec(),
+            timestamp: value.timestamp.timestamp_micros(),
+            data_bytes: ArrayDataValue::from_vec(&value.data).to_bytes(),
+            data_type: value.data.into_iter().map(|e| e.get_type().into()).collect(),
+            present: value.present
+        }
+    }
+}
+
+impl From<data::DataSetAlignedSchema> for DataSetAlignedSchema {
+    fn from(value: data::DataSetAlignedSchema) -> Self {
+        Self {
+            set_id: Uuid::from_slice(&value.set_id).unwrap_or_default(),
+            timestamp: Utc.timestamp_nanos(value.timestamp * 1000),
+            data: ArrayDataValue::from_bytes(
+                    &value.data_bytes,
+                    value.data_type.into_iter().map(|e| DataType::from(e))
+                    .collect::<Vec<DataType>>()
+                    .as_slice()
+                ).to_vec(),
+            present: value.present
+        }
+    }
+}
+
 impl From<BufferSchema> for buffer::BufferSchema {
     fn from(value: BufferSchema) -> Self {
         Self {
@@ -543,6 +654,50 @@ impl From<buffer::BufferSetSchema> for BufferSetSchema {
     }
 }
 
+impl From<BufferTransitionSchema> for buffer::BufferTransitionSchema {
+    fn from(value: BufferTransitionSchema) -> Self {
+        Self {
+            tag: value.tag as i32,
+            new_tag: value.new_tag as i32
+        }
+    }
+}
+
+impl From<buffer::BufferTransitionSchema> for BufferTransitionSchema {
+    fn from(value: buffer::BufferTransitionSchema) -> Self {
+        Self {
+            tag: value.tag as i16,
+            new_tag: value.new_tag as i16
+        }
+    }
+}
+
+impl From<BufferHistorySchema> for buffer::BufferHistorySchema {
+    fn from(value: BufferHistorySchema) -> Self {
+        Self {
+            id: value.id,
+            buffer_id: value.buffer_id,
+            tag: value.tag as i32,
+            new_tag: value.new_tag as i32,
+            changed_by: value.changed_by,
+            changed_at: value.changed_at.timestamp_micros()
+        }
+    }
+}
+
+impl From<buffer::BufferHistorySchema> for BufferHistorySchema {
+    fn from(value: buffer::BufferHistorySchema) -> Self {
+        Self {
+            id: value.id,
+            buffer_id: value.buffer_id,
+            tag: value.tag as i16,
+            new_tag: value.new_tag as i16,
+            changed_by: value.changed_by,
+            changed_at: Utc.timestamp_nanos(value.changed_at * 1000)
+        }
+    }
+}
+
 impl From<SliceSchema> for slice::SliceSchema {
     fn from(value: SliceSchema) -> Self {
         Self {
@@ -596,3 +751,107 @@ impl From<slice::SliceSetSchema> for SliceSetSchema {
         }
     }
 }
+
+impl From<SliceRuleSchema> for slice::SliceRuleSchema {
+    fn from(value: SliceRuleSchema) -> Self {
+        Self {
+            id: value.id,
+            device_id: value.device_id.as_bytes().to_vec(),
+            model_id: value.model_id.as_bytes().to_vec(),
+            index: value.index as u32,
+            condition: value.condition.into(),
+            threshold: value.threshold,
+            min_duration: value.min_duration.num_microseconds().unwrap_or(i64::MAX),
+            max_gap: value.max_gap.num_microseconds().unwrap_or(i64::MAX),
+            name: value.name,
+            checkpoint: value.checkpoint.map(|t| t.timestamp_micros())
+        }
+    }
+}
+
+impl From<slice::SliceRuleSchema> for SliceRuleSchema {
+    fn from(value: slice::SliceRuleSchema) -> Self {
+        Self {
+            id: value.id,
+            device_id: Uuid::from_slice(&value.device_id).unwrap_or_default(),
+            model_id: Uuid::from_slice(&value.model_id).unwrap_or_default(),
+            index: value.index as i16,
+            condition: SliceCondition::try_from(value.condition.as_str()).unwrap_or_default(),
+            threshold: value.threshold,
+            min_duration: Duration::microseconds(value.min_duration),
+            max_gap: Duration::microseconds(value.max_gap),
+            name: value.name,
+            checkpoint: value.checkpoint.map(|t| Utc.timestamp_nanos(t * 1000))
+        }
+    }
+}
+
+impl From<RetentionSchema> for retention::RetentionSchema {
+    fn from(value: RetentionSchema) -> Self {
+        Self {
+            id: value.id,
+            model_id: value.model_id.map(|id| id.as_bytes().to_vec()),
+            type_id: value.type_id.map(|id| id.as_bytes().to_vec()),
+            tag: value.tag.map(|t| t as i32),
+            retention: value.retention.num_microseconds().unwrap_or(i64::MAX)
+        }
+    }
+}
+
+impl From<retention::RetentionSchema> for RetentionSchema {
+    fn from(value: retention::RetentionSchema) -> Self {
+        Self {
+            id: value.id,
+            model_id: value.model_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            type_id: value.type_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            tag: value.tag.map(|t| t as i16),
+            retention: Duration::microseconds(value.retention)
+        }
+    }
+}
+
+impl From<CompressionSchema> for compression::CompressionStats {
+    fn from(value: CompressionSchema) -> Self {
+        Self {
+            table: value.table,
+            total_chunks: value.total_chunks,
+            compressed_chunks: value.compressed_chunks,
+            before_bytes: value.before_bytes,
+            after_bytes: value.after_bytes
+        }
+    }
+}
+
+impl From<compression::CompressionStats> for CompressionSchema {
+    fn from(value: compression::CompressionStats) -> Self {
+        Self {
+            table: value.table,
+            total_chunks: value.total_chunks,
+            compressed_chunks: value.compressed_chunks,
+            before_bytes: value.before_bytes,
+            after_bytes: value.after_bytes
+        }
+    }
+}
+
+impl From<IntegrityViolationSchema> for device::IntegrityViolationSchema {
+    fn from(value: IntegrityViolationSchema) -> Self {
+        Self {
+            table: value.table,
+            device_id: value.device_id.as_bytes().to_vec(),
+            model_id: value.model_id.as_bytes().to_vec(),
+            count: value.count
+        }
+    }
+}
+
+impl From<device::IntegrityViolationSchema> for IntegrityViolationSchema {
+    fn from(value: device::IntegrityViolationSchema) -> Self {
+        Self {
+            table: value.table,
+            device_id: Uuid::from_slice(&value.device_id).unwrap_or_default(),
+            model_id: Uuid::from_slice(&value.model_id).unwrap_or_default(),
+            count: value.count
+        }
+    }
+}
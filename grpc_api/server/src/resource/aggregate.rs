@@ -0,0 +1,113 @@
+use tonic::{Request, Response, Status};
+use chrono::{Utc, TimeZone};
+use uuid::Uuid;
+use bbthings_database::Resource;
+use crate::proto::resource::aggregate::aggregate_service_server::AggregateService;
+use crate::proto::resource::aggregate::{
+    AggregateRange, AggregateGroupRange, AggregateCountResponse
+};
+use crate::common::validator::{AccessValidator, AccessSchema};
+use crate::common::cache::DataLatestCache;
+use crate::common::utility::handle_error;
+
+const CREATE_DATA: &str = "create_data";
+
+#[derive(Debug)]
+pub struct AggregateServer {
+    resource_db: Resource,
+    latest: DataLatestCache,
+    token_key: Vec<u8>,
+    accesses: Vec<AccessSchema>
+}
+
+impl AggregateServer {
+    pub fn new(resource_db: Resource) -> Self {
+        Self {
+            resource_db,
+            latest: DataLatestCache::new(),
+            token_key: Vec::new(),
+            accesses: Vec::new()
+        }
+    }
+    pub fn new_with_validator(resource_db: Resource, token_key: &[u8], accesses: &[AccessSchema]) -> Self {
+        const PROCEDURES: &[&str] = &[
+            CREATE_DATA
+        ];
+        Self {
+            resource_db,
+            latest: DataLatestCache::new(),
+            token_key: token_key.to_vec(),
+            accesses: Self::construct_accesses(accesses, PROCEDURES)
+        }
+    }
+    pub fn with_latest_cache(mut self, latest: DataLatestCache) -> Self {
+        // aggregated data may become the latest data so the cache of data server is shared
+        self.latest = latest;
+        self
+    }
+}
+
+#[tonic::async_trait]
+impl AggregateService for AggregateServer {
+
+    async fn aggregate_data(&self, request: Request<AggregateRange>)
+        -> Result<Response<AggregateCountResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let request = request.into_inner();
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
+        let result = self.resource_db.aggregate_data(
+            device_id,
+            model_id,
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag as i16
+        ).await;
+        let count = match result {
+            Ok(value) => {
+                self.latest.invalidate(&[device_id], &[model_id]);
+                value as u32
+            },
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(AggregateCountResponse { count }))
+    }
+
+    async fn aggregate_data_group(&self, request: Request<AggregateGroupRange>)
+        -> Result<Response<AggregateCountResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let request = request.into_inner();
+        let device_ids: Vec<Uuid> = request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let model_ids: Vec<Uuid> = request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let result = self.resource_db.aggregate_data_group(
+            &device_ids,
+            &model_ids,
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag as i16
+        ).await;
+        let count = match result {
+            Ok(value) => {
+                self.latest.invalidate(&device_ids, &model_ids);
+                value as u32
+            },
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(AggregateCountResponse { count }))
+    }
+
+}
+
+impl AccessValidator for AggregateServer {
+
+    fn token_key(&self) -> Vec<u8> {
+        self.token_key.clone()
+    }
+
+    fn accesses(&self) -> Vec<AccessSchema> {
+        self.accesses.clone()
+    }
+
+}
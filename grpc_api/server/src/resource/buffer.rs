@@ -1,27 +1,37 @@
-use tonic::{Request, Response, Status};
-use chrono::{DateTime, Utc, TimeZone};
+use tonic::{Request, Response, Status, Streaming};
+use chrono::{DateTime, Utc, TimeZone, Duration};
 use uuid::Uuid;
+use tokio_stream::wrappers::ReceiverStream;
 use bbthings_database::{Resource, DataType, DataValue, ArrayDataValue};
+use bbthings_database::resource::data::DataConflict;
 use crate::proto::resource::buffer::buffer_service_server::BufferService;
 use crate::proto::resource::buffer::{
-    BufferSchema, BufferMultipleSchema, BufferId, BufferIds, BufferTime, BufferEarlier, BufferLater, BufferRange, BufferNumber, 
+    BufferSchema, BufferMultipleSchema, BufferId, BufferIds, BufferTime, BufferEarlier, BufferLater, BufferRange, BufferRangeDelete, BufferNumber, 
     BufferSelector, BuffersSelector, BufferUpdate, BufferUpdateTime,
-    BufferGroupTime, BufferGroupEarlier, BufferGroupLater, BufferGroupRange, BufferGroupNumber, BufferGroupSelector, BuffersGroupSelector,
-    BufferSetTime, BufferSetEarlier, BufferSetLater, BufferSetRange,
-    BufferReadResponse, BufferListResponse, BufferCreateResponse, BufferCreateMultipleResponse, BufferChangeResponse,
-    BufferSetReadResponse, BufferSetListResponse, TimestampReadResponse, TimestampListResponse, BufferCountResponse
+    BufferGroupTime, BufferGroupEarlier, BufferGroupLater, BufferGroupRange, BufferGroupRangeDelete, BufferTransfer, BufferClaim, BufferAck, BufferNack, BufferTransitionSchema, BufferTransitionTag, BufferGroupNumber, BufferRangePage, BufferGroupRangePage, BufferGroupSelector, BuffersGroupSelector,
+    BufferSetSchema, BufferSetTime, BufferSetEarlier, BufferSetLater, BufferSetRange, BufferSetNumber,
+    BufferReadResponse, BufferListResponse, BufferPageResponse, BufferCreateResponse, BufferCreateMultipleResponse, BufferChangeResponse,
+    BufferIngestBatch, BufferIngestReject, BufferIngestResponse,
+    BufferSetReadResponse, BufferSetListResponse, TimestampReadResponse, TimestampListResponse, BufferCountResponse, BufferReleaseResponse,
+    BufferTransitionListResponse, BufferHistoryListResponse
 };
 use crate::common::validator::{AccessValidator, AccessSchema};
-use crate::common::utility::handle_error;
+use crate::common::cache::DataLatestCache;
+use crate::common::utility::{handle_error, decode_page, stream_batch, STREAM_BATCH_SIZE};
 
 const READ_BUFFER: &str = "read_buffer";
 const CREATE_BUFFER: &str = "create_buffer";
 const UPDATE_BUFFER: &str = "update_buffer";
 const DELETE_BUFFER: &str = "delete_buffer";
+const CHANGE_BUFFER_TRANSITION: &str = "change_buffer_transition";
+const CREATE_DATA: &str = "create_data";
+
+const TRANSFER_SELECTION_EMPTY: &str = "Buffer transfer requires buffer ids or a time range";
 
 #[derive(Debug)]
 pub struct BufferServer {
     resource_db: Resource,
+    latest: DataLatestCache,
     token_key: Vec<u8>,
     accesses: Vec<AccessSchema>
 }
@@ -30,25 +40,35 @@ impl BufferServer {
     pub fn new(resource_db: Resource) -> Self {
         Self {
             resource_db,
+            latest: DataLatestCache::new(),
             token_key: Vec::new(),
             accesses: Vec::new()
         }
     }
     pub fn new_with_validator(resource_db: Resource, token_key: &[u8], accesses: &[AccessSchema]) -> Self {
         const PROCEDURES: &[&str] = &[
-            READ_BUFFER, CREATE_BUFFER, UPDATE_BUFFER, DELETE_BUFFER
+            READ_BUFFER, CREATE_BUFFER, UPDATE_BUFFER, DELETE_BUFFER, CHANGE_BUFFER_TRANSITION, CREATE_DATA
         ];
         Self {
             resource_db,
+            latest: DataLatestCache::new(),
             token_key: token_key.to_vec(),
             accesses: Self::construct_accesses(accesses, PROCEDURES)
         }
     }
+    pub fn with_latest_cache(mut self, latest: DataLatestCache) -> Self {
+        // transferred buffers change the latest data so the cache of data server is shared
+        self.latest = latest;
+        self
+    }
 }
 
 #[tonic::async_trait]
 impl BufferService for BufferServer {
 
+    type StreamBufferByRangeStream = ReceiverStream<Result<BufferListResponse, Status>>;
+    type StreamBufferGroupByRangeStream = ReceiverStream<Result<BufferListResponse, Status>>;
+
     async fn read_buffer(&self, request: Request<BufferId>)
         -> Result<Response<BufferReadResponse>, Status>
     {
@@ -385,6 +405,48 @@ impl BufferService for BufferServer {
         Ok(Response::new(BufferListResponse { results }))
     }
 
+    async fn list_buffer_by_range_page(&self, request: Request<BufferRangePage>)
+        -> Result<Response<BufferPageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_buffer_by_range_page(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferPageResponse { results, cursor }))
+    }
+
+    async fn list_buffer_group_by_range_page(&self, request: Request<BufferGroupRangePage>)
+        -> Result<Response<BufferPageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_buffer_group_by_range_page(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferPageResponse { results, cursor }))
+    }
+
     async fn list_buffer_group_by_number_before(&self, request: Request<BufferGroupNumber>)
         -> Result<Response<BufferListResponse>, Status>
     {
@@ -423,6 +485,36 @@ impl BufferService for BufferServer {
         Ok(Response::new(BufferListResponse { results }))
     }
 
+    async fn stream_buffer_by_range(&self, request: Request<BufferRange>)
+        -> Result<Response<Self::StreamBufferByRangeStream>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let stream = self.resource_db.stream_buffer_by_range(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        );
+        Ok(Response::new(stream_batch(stream, |results| BufferListResponse { results })))
+    }
+
+    async fn stream_buffer_group_by_range(&self, request: Request<BufferGroupRange>)
+        -> Result<Response<Self::StreamBufferGroupByRangeStream>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let stream = self.resource_db.stream_buffer_group_by_range(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        );
+        Ok(Response::new(stream_batch(stream, |results| BufferListResponse { results })))
+    }
+
     async fn read_buffer_group_first(&self, request: Request<BufferGroupSelector>)
         -> Result<Response<BufferReadResponse>, Status>
     {
@@ -653,6 +745,42 @@ impl BufferService for BufferServer {
         Ok(Response::new(BufferSetListResponse { results }))
     }
 
+    async fn list_buffer_set_by_number_before(&self, request: Request<BufferSetNumber>)
+        -> Result<Response<BufferSetListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_buffer_set_by_number_before(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            request.number as usize,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferSetListResponse { results }))
+    }
+
+    async fn list_buffer_set_by_number_after(&self, request: Request<BufferSetNumber>)
+        -> Result<Response<BufferSetListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_buffer_set_by_number_after(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            request.number as usize,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferSetListResponse { results }))
+    }
+
     async fn create_buffer(&self, request: Request<BufferSchema>)
         -> Result<Response<BufferCreateResponse>, Status>
     {
@@ -668,11 +796,11 @@ impl BufferService for BufferServer {
             ).to_vec(),
             Some(request.tag as i16)
         ).await;
-        let id = match result {
+        let (id, violated) = match result {
             Ok(value) => value,
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(BufferCreateResponse { id }))
+        Ok(Response::new(BufferCreateResponse { id, violated: violated as u32 }))
     }
 
     async fn create_buffer_multiple(&self, request: Request<BufferMultipleSchema>)
@@ -680,6 +808,10 @@ impl BufferService for BufferServer {
     {
         self.validate(request.extensions(), CREATE_BUFFER)?;
         let request = request.into_inner();
+        let conflict = match request.conflict {
+            Some(value) => DataConflict::try_from(value.as_str()).map_err(Status::invalid_argument)?,
+            None => DataConflict::Error
+        };
         let (device_ids, model_ids, timestamps, data_vec, tags): (Vec<Uuid>, Vec<Uuid>, Vec<DateTime<Utc>>, Vec<Vec<DataValue>>, Vec<i16>) 
             = request.schemas.into_iter().map(|r| {(
                 Uuid::from_slice(&r.device_id).unwrap_or_default(),
@@ -692,26 +824,109 @@ impl BufferService for BufferServer {
                 r.tag as i16
             )}).collect();
         let data_multiple: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
-        let result = self.resource_db.create_buffer_multiple(
+        let result = self.resource_db.create_buffer_multiple_conflict(
             &device_ids,
             &model_ids,
             &timestamps,
             &data_multiple,
-            Some(&tags)
+            Some(&tags),
+            conflict
         ).await;
-        let ids = match result {
+        let (ids, count) = match result {
             Ok(value) => value,
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(BufferCreateMultipleResponse { ids }))
+        Ok(Response::new(BufferCreateMultipleResponse {
+            ids,
+            inserted: count.inserted as u32,
+            skipped: count.skipped as u32,
+            overwritten: count.overwritten as u32,
+            violated: count.violated as u32
+        }))
+    }
+
+    async fn create_buffer_set(&self, request: Request<BufferSetSchema>)
+        -> Result<Response<BufferCreateMultipleResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.create_buffer_set(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            &ArrayDataValue::from_bytes(
+                &request.data_bytes,
+                &request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>()
+            ).to_vec(),
+            Some(request.tag as i16)
+        ).await;
+        let (ids, violated) = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        let inserted = ids.len() as u32;
+        Ok(Response::new(BufferCreateMultipleResponse { ids, inserted, skipped: 0, overwritten: 0, violated: violated as u32 }))
+    }
+
+    async fn ingest_buffer(&self, request: Request<Streaming<BufferSchema>>)
+        -> Result<Response<BufferIngestResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_BUFFER)?;
+        let mut stream = request.into_inner();
+        let mut response = BufferIngestResponse::default();
+        let mut schemas: Vec<bbthings_database::BufferSchema> = Vec::new();
+        let mut offset = 0;
+        loop {
+            // a failure stops the ingestion and returns the acknowledgement of stored batches with the error
+            let message = match stream.message().await {
+                Ok(value) => value,
+                Err(e) => {
+                    response.error = Some(e.message().to_owned());
+                    break;
+                }
+            };
+            let end = message.is_none();
+            if let Some(schema) = message {
+                schemas.push(schema.into());
+            }
+            // insert every batch size messages and the remaining messages when the stream is ended
+            if schemas.len() >= STREAM_BATCH_SIZE || (end && !schemas.is_empty()) {
+                let result = self.resource_db.ingest_buffer(&schemas).await;
+                let (violated, rejects) = match result {
+                    Ok(value) => value,
+                    Err(e) => {
+                        response.error = Some(handle_error(e).message().to_owned());
+                        break;
+                    }
+                };
+                let count = schemas.len() - rejects.len();
+                response.batches.push(BufferIngestBatch {
+                    batch: response.batches.len() as u32,
+                    count: count as u32,
+                    rejected: rejects.len() as u32
+                });
+                response.count += count as u64;
+                response.violated += violated as u64;
+                response.rejects.extend(rejects.into_iter().map(|(index, reason)| BufferIngestReject {
+                    index: (offset + index) as u64,
+                    reason
+                }));
+                offset += schemas.len();
+                schemas.clear();
+            }
+            if end {
+                break;
+            }
+        }
+        Ok(Response::new(response))
     }
 
     async fn update_buffer(&self, request: Request<BufferUpdate>)
         -> Result<Response<BufferChangeResponse>, Status>
     {
         self.validate(request.extensions(), UPDATE_BUFFER)?;
+        let changed_by = self.token_user(request.extensions());
         let request = request.into_inner();
-        let result = self.resource_db.update_buffer(
+        let result = self.resource_db.update_buffer_changed_by(
             request.id,
             request.data_bytes.map(|s| {
                 ArrayDataValue::from_bytes(
@@ -719,7 +934,8 @@ impl BufferService for BufferServer {
                     request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>().as_slice()
                 ).to_vec()
             }).as_deref(),
-            request.tag.map(|t| t as i16)
+            request.tag.map(|t| t as i16),
+            &changed_by
         ).await;
         match result {
             Ok(_) => (),
@@ -732,8 +948,9 @@ impl BufferService for BufferServer {
         -> Result<Response<BufferChangeResponse>, Status>
     {
         self.validate(request.extensions(), UPDATE_BUFFER)?;
+        let changed_by = self.token_user(request.extensions());
         let request = request.into_inner();
-        let result = self.resource_db.update_buffer_by_time(
+        let result = self.resource_db.update_buffer_by_time_changed_by(
             Uuid::from_slice(&request.device_id).unwrap_or_default(),
             Uuid::from_slice(&request.model_id).unwrap_or_default(),
             Utc.timestamp_nanos(request.timestamp * 1000),
@@ -743,7 +960,8 @@ impl BufferService for BufferServer {
                     request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>().as_slice()
                 ).to_vec()
             }).as_deref(),
-            request.tag.map(|t| t as i16)
+            request.tag.map(|t| t as i16),
+            &changed_by
         ).await;
         match result {
             Ok(_) => (),
@@ -783,6 +1001,181 @@ impl BufferService for BufferServer {
         Ok(Response::new(BufferChangeResponse { }))
     }
 
+    async fn delete_buffer_by_range(&self, request: Request<BufferRangeDelete>)
+        -> Result<Response<BufferCountResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_BUFFER } else { DELETE_BUFFER };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let result = self.resource_db.delete_buffer_by_range(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            request.dry_run
+        ).await;
+        let count = match result {
+            Ok(value) => value as u32,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferCountResponse { count }))
+    }
+
+    async fn delete_buffer_group_by_range(&self, request: Request<BufferGroupRangeDelete>)
+        -> Result<Response<BufferCountResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_BUFFER } else { DELETE_BUFFER };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let result = self.resource_db.delete_buffer_group_by_range(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            request.dry_run
+        ).await;
+        let count = match result {
+            Ok(value) => value as u32,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferCountResponse { count }))
+    }
+
+    async fn transfer_buffer(&self, request: Request<BufferTransfer>)
+        -> Result<Response<BufferCountResponse>, Status>
+    {
+        // transfer deletes buffers and creates data so both procedures are required
+        self.validate(request.extensions(), DELETE_BUFFER)?;
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let request = request.into_inner();
+        let tag = request.tag.map(|t| t as i16);
+        let target_tag = request.target_tag.map(|t| t as i16);
+        // buffer ids take precedence over the device, model, and time range selection
+        let count = if !request.ids.is_empty() {
+            let result = self.resource_db.transfer_buffer_by_ids(&request.ids, target_tag).await;
+            let count = match result {
+                Ok(value) => value as u32,
+                Err(e) => return Err(handle_error(e))
+            };
+            // device and model of buffers selected by ids are unknown so the whole cache is cleared
+            self.latest.clear();
+            count
+        } else {
+            let (begin, end) = match (request.begin, request.end) {
+                (Some(begin), Some(end)) => (Utc.timestamp_nanos(begin * 1000), Utc.timestamp_nanos(end * 1000)),
+                _ => return Err(Status::invalid_argument(TRANSFER_SELECTION_EMPTY))
+            };
+            let device_ids: Vec<Uuid> = request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+            let model_ids: Vec<Uuid> = request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+            let result = self.resource_db.transfer_buffer_group_by_range(&device_ids, &model_ids, begin, end, tag, target_tag).await;
+            let count = match result {
+                Ok(value) => value as u32,
+                Err(e) => return Err(handle_error(e))
+            };
+            self.latest.invalidate(&device_ids, &model_ids);
+            count
+        };
+        Ok(Response::new(BufferCountResponse { count }))
+    }
+
+    async fn claim_buffer(&self, request: Request<BufferClaim>)
+        -> Result<Response<BufferListResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.claim_buffer(
+            request.number as usize,
+            request.tag as i16,
+            Duration::microseconds(request.lease),
+            &request.worker_id
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferListResponse { results }))
+    }
+
+    async fn ack_buffer(&self, request: Request<BufferAck>)
+        -> Result<Response<BufferReleaseResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.ack_buffer(&request.ids, &request.worker_id, request.tag as i16).await;
+        let (count, unreleased_ids) = match result {
+            Ok((count, ids)) => (count as u32, ids),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferReleaseResponse { count, unreleased_ids }))
+    }
+
+    async fn nack_buffer(&self, request: Request<BufferNack>)
+        -> Result<Response<BufferReleaseResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.nack_buffer(&request.ids, &request.worker_id).await;
+        let (count, unreleased_ids) = match result {
+            Ok((count, ids)) => (count as u32, ids),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferReleaseResponse { count, unreleased_ids }))
+    }
+
+    async fn list_buffer_transition(&self, request: Request<BufferTransitionTag>)
+        -> Result<Response<BufferTransitionListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_buffer_transition(request.tag.map(|t| t as i16)).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferTransitionListResponse { results }))
+    }
+
+    async fn create_buffer_transition(&self, request: Request<BufferTransitionSchema>)
+        -> Result<Response<BufferChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), CHANGE_BUFFER_TRANSITION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.create_buffer_transition(request.tag as i16, request.new_tag as i16).await;
+        match result {
+            Ok(_) => (),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferChangeResponse { }))
+    }
+
+    async fn delete_buffer_transition(&self, request: Request<BufferTransitionSchema>)
+        -> Result<Response<BufferChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), CHANGE_BUFFER_TRANSITION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.delete_buffer_transition(request.tag as i16, request.new_tag as i16).await;
+        match result {
+            Ok(_) => (),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferChangeResponse { }))
+    }
+
+    async fn list_buffer_history(&self, request: Request<BufferId>)
+        -> Result<Response<BufferHistoryListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_BUFFER)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_buffer_history(request.id).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(BufferHistoryListResponse { results }))
+    }
+
     async fn read_buffer_timestamp(&self, request: Request<BufferTime>)
         -> Result<Response<TimestampReadResponse>, Status>
     {
@@ -0,0 +1,120 @@
+use tonic::{Request, Response, Status};
+use chrono::Duration;
+use bbthings_database::Resource;
+use bbthings_database::resource::compression::CompressionTable;
+use crate::proto::resource::compression::compression_service_server::CompressionService;
+use crate::proto::resource::compression::{
+    CompressionTarget, CompressionPolicy, CompressionChangeResponse, CompressionStatsResponse
+};
+use crate::common::validator::{AccessValidator, AccessSchema};
+use crate::common::utility::handle_error;
+
+const READ_COMPRESSION: &str = "read_compression";
+const UPDATE_COMPRESSION: &str = "update_compression";
+
+#[derive(Debug)]
+pub struct CompressionServer {
+    resource_db: Resource,
+    token_key: Vec<u8>,
+    accesses: Vec<AccessSchema>
+}
+
+impl CompressionServer {
+    pub fn new(resource_db: Resource) -> Self {
+        Self {
+            resource_db,
+            token_key: Vec::new(),
+            accesses: Vec::new()
+        }
+    }
+    pub fn new_with_validator(resource_db: Resource, token_key: &[u8], accesses: &[AccessSchema]) -> Self {
+        const PROCEDURES: &[&str] = &[
+            READ_COMPRESSION, UPDATE_COMPRESSION
+        ];
+        Self {
+            resource_db,
+            token_key: token_key.to_vec(),
+            accesses: Self::construct_accesses(accesses, PROCEDURES)
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl CompressionService for CompressionServer {
+
+    async fn enable_compression(&self, request: Request<CompressionTarget>)
+        -> Result<Response<CompressionChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_COMPRESSION)?;
+        let request = request.into_inner();
+        let table = CompressionTable::try_from(request.table.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.enable_compression(table).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(CompressionChangeResponse { }))
+    }
+
+    async fn set_compression_policy(&self, request: Request<CompressionPolicy>)
+        -> Result<Response<CompressionChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_COMPRESSION)?;
+        let request = request.into_inner();
+        let table = CompressionTable::try_from(request.table.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.set_compression_policy(
+            table,
+            Duration::microseconds(request.compress_after)
+        ).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(CompressionChangeResponse { }))
+    }
+
+    async fn remove_compression_policy(&self, request: Request<CompressionTarget>)
+        -> Result<Response<CompressionChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_COMPRESSION)?;
+        let request = request.into_inner();
+        let table = CompressionTable::try_from(request.table.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.remove_compression_policy(table).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(CompressionChangeResponse { }))
+    }
+
+    async fn read_compression_stats(&self, request: Request<CompressionTarget>)
+        -> Result<Response<CompressionStatsResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_COMPRESSION)?;
+        let request = request.into_inner();
+        let table = CompressionTable::try_from(request.table.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.read_compression_stats(table).await;
+        let result = match result {
+            Ok(value) => Some(value.into()),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(CompressionStatsResponse { result }))
+    }
+
+}
+
+impl AccessValidator for CompressionServer {
+
+    fn token_key(&self) -> Vec<u8> {
+        self.token_key.clone()
+    }
+
+    fn accesses(&self) -> Vec<AccessSchema> {
+        self.accesses.clone()
+    }
+
+}
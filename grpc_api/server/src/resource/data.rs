@@ -1,25 +1,35 @@
-use tonic::{Request, Response, Status};
-use chrono::{DateTime, Utc, TimeZone};
+use tonic::{Request, Response, Status, Streaming};
+use chrono::{DateTime, Utc, TimeZone, Duration};
 use uuid::Uuid;
+use std::pin::Pin;
+use tokio_stream::{Stream, StreamExt};
+use tokio_stream::wrappers::ReceiverStream;
 use bbthings_database::{Resource, DataValue, DataType, ArrayDataValue};
+use bbthings_database::resource::aggregate::{DataAggregate, DataAlign};
+use bbthings_database::resource::data::{DataConflict, DataRangeUpdate};
+use bbthings_database::resource::export::{ExportFormat, ExportSource};
 use crate::proto::resource::data::data_service_server::DataService;
 use crate::proto::resource::data::{
-    DataSchema, DataMultipleSchema, DataTime, DataEarlier, DataLater, DataRange, DataNumber,
-    DataGroupTime, DataGroupEarlier, DataGroupLater, DataGroupRange, DataGroupNumber,
-    DataSetTime, DataSetEarlier, DataSetLater, DataSetRange,
-    DataReadResponse, DataListResponse, DataChangeResponse, DataSetReadResponse, DataSetListResponse,
+    DataSchema, DataMultipleSchema, DataTime, DataEarlier, DataLater, DataRange, DataRangeDelete, DataUpdate, DataUpdateRange, DataNumber, DataLatest, DataBucketed, DataSubscribe, DataSlice, DataExport, DataExportChunk,
+    DataGroupTime, DataGroupEarlier, DataGroupLater, DataGroupRange, DataGroupRangeDelete, DataGroupNumber, DataRangePage, DataGroupRangePage,
+    DataSetSchema, DataSetTime, DataSetEarlier, DataSetLater, DataSetRange, DataSetAligned, DataSetNumber,
+    DataReadResponse, DataListResponse, DataStatsResponse, DataAuditListResponse, DataPageResponse, DataChangeResponse, DataCreateMultipleResponse, DataSetReadResponse, DataSetListResponse, DataSetAlignedListResponse,
+    DataIngestBatch, DataIngestReject, DataIngestResponse, DataImport, DataImportReject, DataImportResponse,
     TimestampReadResponse, TimestampListResponse, DataCountResponse
 };
 use crate::common::validator::{AccessValidator, AccessSchema};
-use crate::common::utility::handle_error;
+use crate::common::utility::{handle_error, decode_page, stream_batch, STREAM_BATCH_SIZE};
+use crate::common::cache::DataLatestCache;
 
 const READ_DATA: &str = "read_data";
 const CREATE_DATA: &str = "create_data";
+const UPDATE_DATA: &str = "update_data";
 const DELETE_DATA: &str = "delete_data";
 
 #[derive(Debug)]
 pub struct DataServer {
     resource_db: Resource,
+    latest: DataLatestCache,
     token_key: Vec<u8>,
     accesses: Vec<AccessSchema>
 }
@@ -28,25 +38,38 @@ impl DataServer {
     pub fn new(resource_db: Resource) -> Self {
         Self {
             resource_db,
+            latest: DataLatestCache::new(),
             token_key: Vec::new(),
             accesses: Vec::new()
         }
     }
     pub fn new_with_validator(resource_db: Resource, token_key: &[u8], accesses: &[AccessSchema]) -> Self {
         const PROCEDURES: &[&str] = &[
-            READ_DATA, CREATE_DATA, DELETE_DATA
+            READ_DATA, CREATE_DATA, UPDATE_DATA, DELETE_DATA
         ];
         Self {
             resource_db,
+            latest: DataLatestCache::new(),
             token_key: token_key.to_vec(),
             accesses: Self::construct_accesses(accesses, PROCEDURES)
         }
     }
+    pub fn with_latest_cache(mut self, latest: DataLatestCache) -> Self {
+        // the cache is shared with the other servers and tasks which change data
+        self.latest = latest;
+        self
+    }
 }
 
 #[tonic::async_trait]
 impl DataService for DataServer {
 
+    type StreamDataByRangeStream = ReceiverStream<Result<DataListResponse, Status>>;
+    type StreamDataGroupByRangeStream = ReceiverStream<Result<DataListResponse, Status>>;
+    type StreamDataBySliceStream = ReceiverStream<Result<DataListResponse, Status>>;
+    type SubscribeDataStream = Pin<Box<dyn Stream<Item = Result<DataSchema, Status>> + Send>>;
+    type ExportDataStream = Pin<Box<dyn Stream<Item = Result<DataExportChunk, Status>> + Send>>;
+
     async fn read_data(&self, request: Request<DataTime>)
         -> Result<Response<DataReadResponse>, Status>
     {
@@ -249,6 +272,48 @@ impl DataService for DataServer {
         Ok(Response::new(DataListResponse { results }))
     }
 
+    async fn list_data_by_range_page(&self, request: Request<DataRangePage>)
+        -> Result<Response<DataPageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_data_by_range_page(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataPageResponse { results, cursor }))
+    }
+
+    async fn list_data_group_by_range_page(&self, request: Request<DataGroupRangePage>)
+        -> Result<Response<DataPageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_data_group_by_range_page(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataPageResponse { results, cursor }))
+    }
+
     async fn list_data_group_by_number_before(&self, request: Request<DataGroupNumber>)
         -> Result<Response<DataListResponse>, Status>
     {
@@ -287,6 +352,226 @@ impl DataService for DataServer {
         Ok(Response::new(DataListResponse { results }))
     }
 
+    async fn list_data_latest(&self, request: Request<DataLatest>)
+        -> Result<Response<DataListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let device_ids: Vec<Uuid> = request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let model_ids: Vec<Uuid> = request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let tag = request.tag.map(|t| t as i16);
+        // the cache only keeps the latest data of all tags
+        let cached = match tag {
+            Some(_) => None,
+            None => self.latest.get(&device_ids, &model_ids)
+        };
+        if let Some(value) = cached {
+            let results = value.into_iter().map(|e| e.into()).collect();
+            return Ok(Response::new(DataListResponse { results }));
+        }
+        let result = self.resource_db.list_data_latest(&device_ids, &model_ids, tag).await;
+        let value = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        if tag.is_none() {
+            self.latest.set(&value);
+        }
+        let results = value.into_iter().map(|e| e.into()).collect();
+        Ok(Response::new(DataListResponse { results }))
+    }
+
+    async fn list_data_bucketed(&self, request: Request<DataBucketed>)
+        -> Result<Response<DataListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let aggregate = DataAggregate::try_from(request.aggregate.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.list_data_bucketed(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            Duration::microseconds(request.interval),
+            aggregate,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataListResponse { results }))
+    }
+
+    async fn stats_data_by_range(&self, request: Request<DataRange>)
+        -> Result<Response<DataStatsResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.stats_data_by_range(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataStatsResponse { results }))
+    }
+
+    async fn stats_data_group_by_range(&self, request: Request<DataGroupRange>)
+        -> Result<Response<DataStatsResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.stats_data_group_by_range(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataStatsResponse { results }))
+    }
+
+    async fn list_data_by_slice(&self, request: Request<DataSlice>)
+        -> Result<Response<DataListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_data_by_slice(
+            request.slice_id,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataListResponse { results }))
+    }
+
+    async fn stream_data_by_slice(&self, request: Request<DataSlice>)
+        -> Result<Response<Self::StreamDataBySliceStream>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.stream_data_by_slice(
+            request.slice_id,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let stream = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(stream_batch(stream, |results| DataListResponse { results })))
+    }
+
+    async fn stats_data_by_slice(&self, request: Request<DataSlice>)
+        -> Result<Response<DataStatsResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.stats_data_by_slice(
+            request.slice_id,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataStatsResponse { results }))
+    }
+
+    async fn stream_data_by_range(&self, request: Request<DataRange>)
+        -> Result<Response<Self::StreamDataByRangeStream>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let stream = self.resource_db.stream_data_by_range(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        );
+        Ok(Response::new(stream_batch(stream, |results| DataListResponse { results })))
+    }
+
+    async fn stream_data_group_by_range(&self, request: Request<DataGroupRange>)
+        -> Result<Response<Self::StreamDataGroupByRangeStream>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let stream = self.resource_db.stream_data_group_by_range(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16)
+        );
+        Ok(Response::new(stream_batch(stream, |results| DataListResponse { results })))
+    }
+
+    async fn subscribe_data(&self, request: Request<DataSubscribe>)
+        -> Result<Response<Self::SubscribeDataStream>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.subscribe_data(
+            &request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            &request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect::<Vec<Uuid>>(),
+            request.tag.map(|t| t as i16)
+        ).await;
+        let stream = match result {
+            Ok(value) => value.map(|r| r.map(|d| d.into()).map_err(handle_error)),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn export_data(&self, request: Request<DataExport>)
+        -> Result<Response<Self::ExportDataStream>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let source = ExportSource::try_from(request.source.as_str())
+            .map_err(Status::invalid_argument)?;
+        let format = ExportFormat::try_from(request.format.as_str())
+            .map_err(Status::invalid_argument)?;
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
+        let begin = Utc.timestamp_nanos(request.begin * 1000);
+        let end = Utc.timestamp_nanos(request.end * 1000);
+        let tag = request.tag.map(|t| t as i16);
+        let chunk = |r: Result<Vec<u8>, sqlx::Error>| r.map(|chunk| DataExportChunk { chunk }).map_err(handle_error);
+        let stream: Self::ExportDataStream = match source {
+            ExportSource::Data => match self.resource_db.export_data_by_range(device_id, model_id, begin, end, format, tag).await {
+                Ok(value) => Box::pin(value.map(chunk)),
+                Err(e) => return Err(handle_error(e))
+            },
+            ExportSource::Buffer => match self.resource_db.export_buffer_by_range(device_id, model_id, begin, end, format, tag).await {
+                Ok(value) => Box::pin(value.map(chunk)),
+                Err(e) => return Err(handle_error(e))
+            },
+            ExportSource::Set => {
+                let set_id = Uuid::from_slice(&request.set_id).unwrap_or_default();
+                match self.resource_db.export_data_set_by_range(set_id, begin, end, format, tag).await {
+                    Ok(value) => Box::pin(value.map(chunk)),
+                    Err(e) => return Err(handle_error(e))
+                }
+            }
+        };
+        Ok(Response::new(stream))
+    }
+
     async fn read_data_set(&self, request: Request<DataSetTime>)
         -> Result<Response<DataSetReadResponse>, Status>
     {
@@ -373,33 +658,127 @@ impl DataService for DataServer {
         Ok(Response::new(DataSetListResponse { results }))
     }
 
+    async fn list_data_by_slice_set(&self, request: Request<DataSlice>)
+        -> Result<Response<DataSetListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_data_by_slice_set(
+            request.slice_id,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataSetListResponse { results }))
+    }
+
+    async fn stats_data_by_slice_set(&self, request: Request<DataSlice>)
+        -> Result<Response<DataStatsResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.stats_data_by_slice_set(
+            request.slice_id,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataStatsResponse { results }))
+    }
+
+    async fn list_data_set_aligned(&self, request: Request<DataSetAligned>)
+        -> Result<Response<DataSetAlignedListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let align = DataAlign::try_from(request.align.as_str())
+            .map_err(Status::invalid_argument)?;
+        let result = self.resource_db.list_data_set_aligned(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            Duration::microseconds(request.interval),
+            align,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataSetAlignedListResponse { results }))
+    }
+
+    async fn list_data_set_by_number_before(&self, request: Request<DataSetNumber>)
+        -> Result<Response<DataSetListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_data_set_by_number_before(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            request.number as usize,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataSetListResponse { results }))
+    }
+
+    async fn list_data_set_by_number_after(&self, request: Request<DataSetNumber>)
+        -> Result<Response<DataSetListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_data_set_by_number_after(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            request.number as usize,
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataSetListResponse { results }))
+    }
+
     async fn create_data(&self, request: Request<DataSchema>)
         -> Result<Response<DataChangeResponse>, Status>
     {
         self.validate(request.extensions(), CREATE_DATA)?;
-        let request = request.into_inner();
+        let schema: bbthings_database::DataSchema = request.into_inner().into();
         let result = self.resource_db.create_data(
-            Uuid::from_slice(&request.device_id).unwrap_or_default(),
-            Uuid::from_slice(&request.model_id).unwrap_or_default(),
-            Utc.timestamp_nanos(request.timestamp * 1000),
-            &ArrayDataValue::from_bytes(
-                &request.data_bytes,
-                &request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>()
-            ).to_vec(),
-            Some(request.tag as i16)
+            schema.device_id,
+            schema.model_id,
+            schema.timestamp,
+            &schema.data,
+            Some(schema.tag)
         ).await;
-        match result {
-            Ok(_) => (),
+        let violated = match result {
+            Ok(value) => {
+                self.latest.update(&schema);
+                value as u32
+            },
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(DataChangeResponse { }))
+        Ok(Response::new(DataChangeResponse { violated }))
     }
 
     async fn create_data_multiple(&self, request: Request<DataMultipleSchema>)
-        -> Result<Response<DataChangeResponse>, Status>
+        -> Result<Response<DataCreateMultipleResponse>, Status>
     {
         self.validate(request.extensions(), CREATE_DATA)?;
         let request = request.into_inner();
+        let conflict = match request.conflict {
+            Some(value) => DataConflict::try_from(value.as_str()).map_err(Status::invalid_argument)?,
+            None => DataConflict::Error
+        };
         let (device_ids, model_ids, timestamps, data_vec, tags): (Vec<Uuid>, Vec<Uuid>, Vec<DateTime<Utc>>, Vec<Vec<DataValue>>, Vec<i16>) 
             = request.schemas.into_iter().map(|r| {(
                 Uuid::from_slice(&r.device_id).unwrap_or_default(),
@@ -412,18 +791,253 @@ impl DataService for DataServer {
                 r.tag as i16
             )}).collect();
         let data_multiple: Vec<&[DataValue]> = data_vec.iter().map(|d| d.as_slice()).collect();
-        let result = self.resource_db.create_data_multiple(
+        let result = self.resource_db.create_data_multiple_conflict(
             &device_ids,
             &model_ids,
             &timestamps,
             &data_multiple,
-            Some(&tags)
+            Some(&tags),
+            conflict
+        ).await;
+        let count = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        // rows skipped by the conflict policy are not known so the pairs are read back from database next time
+        if count.skipped > 0 {
+            self.latest.invalidate(&device_ids, &model_ids);
+        } else {
+            for (i, data) in data_vec.into_iter().enumerate() {
+                self.latest.update(&bbthings_database::DataSchema {
+                    device_id: device_ids[i],
+                    model_id: model_ids[i],
+                    timestamp: timestamps[i],
+                    data,
+                    tag: tags[i]
+                });
+            }
+        }
+        Ok(Response::new(DataCreateMultipleResponse {
+            inserted: count.inserted as u32,
+            skipped: count.skipped as u32,
+            overwritten: count.overwritten as u32,
+            violated: count.violated as u32
+        }))
+    }
+
+    async fn create_data_set(&self, request: Request<DataSetSchema>)
+        -> Result<Response<DataChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.create_data_set(
+            Uuid::from_slice(&request.set_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            &ArrayDataValue::from_bytes(
+                &request.data_bytes,
+                &request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>()
+            ).to_vec(),
+            Some(request.tag as i16)
+        ).await;
+        let violated = match result {
+            // set members are not known here so drop all cached data
+            Ok(value) => {
+                self.latest.clear();
+                value as u32
+            },
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataChangeResponse { violated }))
+    }
+
+    async fn ingest_data(&self, request: Request<Streaming<DataSchema>>)
+        -> Result<Response<DataIngestResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let mut stream = request.into_inner();
+        let mut response = DataIngestResponse::default();
+        let mut schemas: Vec<bbthings_database::DataSchema> = Vec::new();
+        let mut offset = 0;
+        loop {
+            // a failure stops the ingestion and returns the acknowledgement of stored batches with the error
+            let message = match stream.message().await {
+                Ok(value) => value,
+                Err(e) => {
+                    response.error = Some(e.message().to_owned());
+                    break;
+                }
+            };
+            let end = message.is_none();
+            if let Some(schema) = message {
+                schemas.push(schema.into());
+            }
+            // insert every batch size messages and the remaining messages when the stream is ended
+            if schemas.len() >= STREAM_BATCH_SIZE || (end && !schemas.is_empty()) {
+                let result = self.resource_db.ingest_data(&schemas).await;
+                let (violated, rejects) = match result {
+                    Ok(value) => value,
+                    Err(e) => {
+                        response.error = Some(handle_error(e).message().to_owned());
+                        break;
+                    }
+                };
+                for (i, schema) in schemas.iter().enumerate() {
+                    if !rejects.iter().any(|(index, _)| *index == i) {
+                        self.latest.update(schema);
+                    }
+                }
+                let count = schemas.len() - rejects.len();
+                response.batches.push(DataIngestBatch {
+                    batch: response.batches.len() as u32,
+                    count: count as u32,
+                    rejected: rejects.len() as u32
+                });
+                response.count += count as u64;
+                response.violated += violated as u64;
+                response.rejects.extend(rejects.into_iter().map(|(index, reason)| DataIngestReject {
+                    index: (offset + index) as u64,
+                    reason
+                }));
+                offset += schemas.len();
+                schemas.clear();
+            }
+            if end {
+                break;
+            }
+        }
+        Ok(Response::new(response))
+    }
+
+    async fn import_data(&self, request: Request<Streaming<DataImport>>)
+        -> Result<Response<DataImportResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_DATA)?;
+        let mut stream = request.into_inner();
+        let mut response = DataImportResponse::default();
+        let mut offset = 0;
+        // every message is a batch of rows with its source line numbers
+        loop {
+            // a failure stops the import and returns the counts of stored batches with the error
+            let message = match stream.message().await {
+                Ok(Some(value)) => value,
+                Ok(None) => break,
+                Err(e) => {
+                    response.error = Some(e.message().to_owned());
+                    break;
+                }
+            };
+            let conflict = match DataConflict::try_from(message.conflict.as_str()) {
+                Ok(value) => value,
+                Err(e) => {
+                    response.error = Some(e);
+                    break;
+                }
+            };
+            let schemas: Vec<bbthings_database::DataSchema> = message.data.into_iter().map(|d| d.into()).collect();
+            if schemas.is_empty() {
+                continue;
+            }
+            let result = self.resource_db.import_data(&schemas, conflict).await;
+            let (count, rejects) = match result {
+                Ok(value) => value,
+                Err(e) => {
+                    response.error = Some(handle_error(e).message().to_owned());
+                    break;
+                }
+            };
+            // overwritten rows may replace cached latest data so the pairs are read back from database next time
+            let device_ids: Vec<Uuid> = schemas.iter().map(|d| d.device_id).collect();
+            let model_ids: Vec<Uuid> = schemas.iter().map(|d| d.model_id).collect();
+            self.latest.invalidate(&device_ids, &model_ids);
+            response.inserted += count.inserted as u64;
+            response.skipped += count.skipped as u64;
+            response.overwritten += count.overwritten as u64;
+            response.violated += count.violated as u64;
+            response.rejects.extend(rejects.into_iter().map(|(index, reason)| DataImportReject {
+                line: message.lines.get(index).copied().unwrap_or((offset + index) as u64),
+                reason
+            }));
+            offset += schemas.len();
+        }
+        Ok(Response::new(response))
+    }
+
+    async fn update_data(&self, request: Request<DataUpdate>)
+        -> Result<Response<DataChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_DATA)?;
+        let changed_by = self.token_user(request.extensions());
+        let request = request.into_inner();
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
+        let result = self.resource_db.update_data_changed_by(
+            device_id,
+            model_id,
+            Utc.timestamp_nanos(request.timestamp * 1000),
+            request.tag.map(|t| t as i16),
+            request.data_bytes.map(|s| {
+                ArrayDataValue::from_bytes(
+                    &s,
+                    request.data_type.into_iter().map(|e| DataType::from(e)).collect::<Vec<DataType>>().as_slice()
+                ).to_vec()
+            }).as_deref(),
+            request.new_tag.map(|t| t as i16),
+            &changed_by
         ).await;
         match result {
-            Ok(_) => (),
+            Ok(_) => self.latest.invalidate(&[device_id], &[model_id]),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataChangeResponse::default()))
+    }
+
+    async fn update_data_by_range(&self, request: Request<DataUpdateRange>)
+        -> Result<Response<DataCountResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_DATA)?;
+        let changed_by = self.token_user(request.extensions());
+        let request = request.into_inner();
+        let types: Vec<DataType> = request.data_type.into_iter().map(|e| DataType::from(e)).collect();
+        let value = ArrayDataValue::from_bytes(&request.data_bytes, &types).to_vec()
+            .into_iter().next().unwrap_or(DataValue::Null);
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
+        let update = DataRangeUpdate {
+            device_id,
+            model_id,
+            begin: Utc.timestamp_nanos(request.begin * 1000),
+            end: Utc.timestamp_nanos(request.end * 1000),
+            tag: request.tag.map(|t| t as i16),
+            index: request.index as usize,
+            value
+        };
+        let result = self.resource_db.update_data_by_range_changed_by(update, &changed_by).await;
+        let count = match result {
+            Ok(value) => {
+                self.latest.invalidate(&[device_id], &[model_id]);
+                value as u32
+            },
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(DataChangeResponse { }))
+        Ok(Response::new(DataCountResponse { count }))
+    }
+
+    async fn list_data_audit_by_range(&self, request: Request<DataRange>)
+        -> Result<Response<DataAuditListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DATA)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_data_audit_by_range(
+            Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataAuditListResponse { results }))
     }
 
     async fn delete_data(&self, request: Request<DataTime>)
@@ -431,17 +1045,71 @@ impl DataService for DataServer {
     {
         self.validate(request.extensions(), DELETE_DATA)?;
         let request = request.into_inner();
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
         let result = self.resource_db.delete_data(
-            Uuid::from_slice(&request.device_id).unwrap_or_default(),
-            Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            device_id,
+            model_id,
             Utc.timestamp_nanos(request.timestamp * 1000),
             request.tag.map(|t| t as i16)
         ).await;
         match result {
-            Ok(_) => (),
+            Ok(_) => self.latest.invalidate(&[device_id], &[model_id]),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataChangeResponse::default()))
+    }
+
+    async fn delete_data_by_range(&self, request: Request<DataRangeDelete>)
+        -> Result<Response<DataCountResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_DATA } else { DELETE_DATA };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let device_id = Uuid::from_slice(&request.device_id).unwrap_or_default();
+        let model_id = Uuid::from_slice(&request.model_id).unwrap_or_default();
+        let result = self.resource_db.delete_data_by_range(
+            device_id,
+            model_id,
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            request.dry_run
+        ).await;
+        let count = match result {
+            Ok(value) => {
+                self.latest.invalidate(&[device_id], &[model_id]);
+                value as u32
+            },
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DataCountResponse { count }))
+    }
+
+    async fn delete_data_group_by_range(&self, request: Request<DataGroupRangeDelete>)
+        -> Result<Response<DataCountResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_DATA } else { DELETE_DATA };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let device_ids: Vec<Uuid> = request.device_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let model_ids: Vec<Uuid> = request.model_ids.into_iter().map(|id| Uuid::from_slice(&id).unwrap_or_default()).collect();
+        let result = self.resource_db.delete_data_group_by_range(
+            &device_ids,
+            &model_ids,
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.tag.map(|t| t as i16),
+            request.dry_run
+        ).await;
+        let count = match result {
+            Ok(value) => {
+                self.latest.invalidate(&device_ids, &model_ids);
+                value as u32
+            },
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(DataChangeResponse { }))
+        Ok(Response::new(DataCountResponse { count }))
     }
 
     async fn read_data_timestamp(&self, request: Request<DataTime>)
@@ -1,20 +1,22 @@
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
 use bbthings_database::{Resource, DataType, DataValue};
+use bbthings_database::resource::integrity::IntegrityTable;
 use crate::proto::resource::device::device_service_server::DeviceService;
 use crate::proto::resource::device::{
-    DeviceSchema, DeviceId, DeviceIds, SerialNumber, DeviceName, DeviceOption, DeviceUpdate,
+    DeviceSchema, DeviceId, DeviceIds, SerialNumber, DeviceName, DeviceOption, DeviceOptionPage, DeviceUpdate,
     GatewaySchema, GatewayId, GatewayIds, GatewayName, GatewayOption, GatewayUpdate,
     TypeSchema, TypeId, TypeIds, TypeName, TypeOption, TypeUpdate, TypeModel,
     ConfigSchema, ConfigId, ConfigUpdate, TypeConfigSchema, TypeConfigId, TypeConfigUpdate,
-    DeviceReadResponse, DeviceListResponse, DeviceCreateResponse, DeviceChangeResponse,
+    DeviceReadResponse, DeviceListResponse, DevicePageResponse, DeviceCreateResponse, DeviceChangeResponse,
     GatewayReadResponse, GatewayListResponse, GatewayCreateResponse, GatewayChangeResponse,
     ConfigReadResponse, ConfigListResponse, ConfigCreateResponse, ConfigChangeResponse,
     TypeReadResponse, TypeListResponse, TypeCreateResponse, TypeChangeResponse,
-    TypeConfigReadResponse, TypeConfigListResponse
+    TypeConfigReadResponse, TypeConfigListResponse,
+    IntegrityOption, IntegrityViolationListResponse
 };
 use crate::common::validator::{AccessValidator, AccessSchema};
-use crate::common::utility::handle_error;
+use crate::common::utility::{handle_error, decode_page};
 
 const READ_DEVICE: &str = "read_device";
 const CREATE_DEVICE: &str = "create_device";
@@ -33,6 +35,7 @@ const READ_TYPE_CONFIG: &str = "read_type_config";
 const CREATE_TYPE_CONFIG: &str = "create_type_config";
 const UPDATE_TYPE_CONFIG: &str = "update_type_config";
 const DELETE_TYPE_CONFIG: &str = "delete_type_config";
+const READ_INTEGRITY: &str = "read_integrity";
 
 #[derive(Debug)]
 pub struct DeviceServer {
@@ -53,7 +56,8 @@ impl DeviceServer {
         const PROCEDURES: &[&str] = &[
             READ_DEVICE, CREATE_DEVICE, UPDATE_DEVICE, DELETE_DEVICE,
             READ_DEVICE_CONFIG, CREATE_DEVICE_CONFIG, UPDATE_DEVICE_CONFIG, DELETE_DEVICE_CONFIG,
-            READ_TYPE, CREATE_TYPE, UPDATE_TYPE, DELETE_TYPE, CHANGE_TYPE_MODEL
+            READ_TYPE, CREATE_TYPE, UPDATE_TYPE, DELETE_TYPE, CHANGE_TYPE_MODEL,
+            READ_INTEGRITY
         ];
         Self {
             resource_db,
@@ -162,6 +166,25 @@ impl DeviceService for DeviceServer {
         Ok(Response::new(DeviceListResponse { results }))
     }
 
+    async fn list_device_option_page(&self, request: Request<DeviceOptionPage>)
+        -> Result<Response<DevicePageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_DEVICE)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_device_option_page(
+            request.gateway_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.type_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.name.as_deref(),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(DevicePageResponse { results, cursor }))
+    }
+
     async fn create_device(&self, request: Request<DeviceSchema>)
         -> Result<Response<DeviceCreateResponse>, Status>
     {
@@ -733,6 +756,23 @@ impl DeviceService for DeviceServer {
         Ok(Response::new(ConfigChangeResponse { }))
     }
 
+    async fn list_integrity_violation(&self, request: Request<IntegrityOption>)
+        -> Result<Response<IntegrityViolationListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_INTEGRITY)?;
+        let request = request.into_inner();
+        let table = match request.table {
+            Some(table) => Some(IntegrityTable::try_from(table.as_str()).map_err(Status::invalid_argument)?),
+            None => None
+        };
+        let result = self.resource_db.list_integrity_violation(table).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(IntegrityViolationListResponse { results }))
+    }
+
 }
 
 impl AccessValidator for DeviceServer {
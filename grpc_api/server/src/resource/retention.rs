@@ -0,0 +1,167 @@
+use tonic::{Request, Response, Status};
+use chrono::Duration;
+use uuid::Uuid;
+use bbthings_database::Resource;
+use crate::proto::resource::retention::retention_service_server::RetentionService;
+use crate::proto::resource::retention::{
+    RetentionSchema, RetentionId, RetentionOption, RetentionUpdate, RetentionApply, RetentionCount,
+    RetentionReadResponse, RetentionListResponse, RetentionCreateResponse, RetentionChangeResponse, RetentionApplyResponse
+};
+use crate::common::validator::{AccessValidator, AccessSchema};
+use crate::common::cache::DataLatestCache;
+use crate::common::utility::handle_error;
+
+const READ_RETENTION: &str = "read_retention";
+const CREATE_RETENTION: &str = "create_retention";
+const UPDATE_RETENTION: &str = "update_retention";
+const DELETE_RETENTION: &str = "delete_retention";
+const DELETE_DATA: &str = "delete_data";
+
+#[derive(Debug)]
+pub struct RetentionServer {
+    resource_db: Resource,
+    latest: DataLatestCache,
+    token_key: Vec<u8>,
+    accesses: Vec<AccessSchema>
+}
+
+impl RetentionServer {
+    pub fn new(resource_db: Resource) -> Self {
+        Self {
+            resource_db,
+            latest: DataLatestCache::new(),
+            token_key: Vec::new(),
+            accesses: Vec::new()
+        }
+    }
+    pub fn new_with_validator(resource_db: Resource, token_key: &[u8], accesses: &[AccessSchema]) -> Self {
+        const PROCEDURES: &[&str] = &[
+            READ_RETENTION, CREATE_RETENTION, UPDATE_RETENTION, DELETE_RETENTION, DELETE_DATA
+        ];
+        Self {
+            resource_db,
+            latest: DataLatestCache::new(),
+            token_key: token_key.to_vec(),
+            accesses: Self::construct_accesses(accesses, PROCEDURES)
+        }
+    }
+    pub fn with_latest_cache(mut self, latest: DataLatestCache) -> Self {
+        // deleted data may be the latest data so the cache of data server is shared
+        self.latest = latest;
+        self
+    }
+}
+
+#[tonic::async_trait]
+impl RetentionService for RetentionServer {
+
+    async fn read_retention(&self, request: Request<RetentionId>)
+        -> Result<Response<RetentionReadResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_RETENTION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.read_retention(request.id).await;
+        let result = match result {
+            Ok(value) => Some(value.into()),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionReadResponse { result }))
+    }
+
+    async fn list_retention_option(&self, request: Request<RetentionOption>)
+        -> Result<Response<RetentionListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_RETENTION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_retention_option(
+            request.model_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.type_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.tag.map(|t| t as i16)
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionListResponse { results }))
+    }
+
+    async fn create_retention(&self, request: Request<RetentionSchema>)
+        -> Result<Response<RetentionCreateResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_RETENTION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.create_retention(
+            request.model_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.type_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.tag.map(|t| t as i16),
+            Duration::microseconds(request.retention)
+        ).await;
+        let id = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionCreateResponse { id }))
+    }
+
+    async fn update_retention(&self, request: Request<RetentionUpdate>)
+        -> Result<Response<RetentionChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_RETENTION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.update_retention(
+            request.id,
+            Duration::microseconds(request.retention)
+        ).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionChangeResponse { }))
+    }
+
+    async fn delete_retention(&self, request: Request<RetentionId>)
+        -> Result<Response<RetentionChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), DELETE_RETENTION)?;
+        let request = request.into_inner();
+        let result = self.resource_db.delete_retention(request.id).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionChangeResponse { }))
+    }
+
+    async fn apply_retention(&self, request: Request<RetentionApply>)
+        -> Result<Response<RetentionApplyResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_RETENTION } else { DELETE_DATA };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let result = self.resource_db.apply_retention(request.dry_run).await;
+        let results = match result {
+            Ok(value) => {
+                // retention rules select data by model, type, and tag so all cached data is dropped
+                if !request.dry_run && value.iter().any(|(_, count)| *count > 0) {
+                    self.latest.clear();
+                }
+                value.into_iter().map(|(id, count)| RetentionCount { id, count: count as u64 }).collect()
+            },
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(RetentionApplyResponse { results }))
+    }
+
+}
+
+impl AccessValidator for RetentionServer {
+
+    fn token_key(&self) -> Vec<u8> {
+        self.token_key.clone()
+    }
+
+    fn accesses(&self) -> Vec<AccessSchema> {
+        self.accesses.clone()
+    }
+
+}
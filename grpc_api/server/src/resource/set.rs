@@ -150,7 +150,7 @@ impl SetService for SetServer {
             Ok(_) => (),
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(SetChangeResponse { }))
+        Ok(Response::new(SetChangeResponse::default()))
     }
 
     async fn delete_set(&self, request: Request<SetId>)
@@ -163,7 +163,7 @@ impl SetService for SetServer {
             Ok(_) => (),
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(SetChangeResponse { }))
+        Ok(Response::new(SetChangeResponse::default()))
     }
 
     async fn add_set_member(&self, request: Request<SetMemberRequest>)
@@ -177,11 +177,11 @@ impl SetService for SetServer {
             Uuid::from_slice(&request.model_id).unwrap_or_default(),
             &request.data_index
         ).await;
-        match result {
-            Ok(_) => (),
+        let violated = match result {
+            Ok(value) => value as u32,
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(SetChangeResponse { }))
+        Ok(Response::new(SetChangeResponse { violated }))
     }
 
     async fn remove_set_member(&self, request: Request<SetMemberRequest>)
@@ -198,7 +198,7 @@ impl SetService for SetServer {
             Ok(_) => (),
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(SetChangeResponse { }))
+        Ok(Response::new(SetChangeResponse::default()))
     }
 
     async fn swap_set_member(&self, request: Request<SetMemberSwap>)
@@ -217,7 +217,7 @@ impl SetService for SetServer {
             Ok(_) => (),
             Err(e) => return Err(handle_error(e))
         };
-        Ok(Response::new(SetChangeResponse { }))
+        Ok(Response::new(SetChangeResponse::default()))
     }
 
     async fn read_set_template(&self, request: Request<SetTemplateId>)
@@ -1,17 +1,20 @@
 use tonic::{Request, Response, Status};
-use chrono::{Utc, TimeZone};
+use chrono::{Utc, TimeZone, Duration};
 use uuid::Uuid;
 use bbthings_database::Resource;
+use bbthings_database::resource::slice::{SliceCondition, SliceRuleCreate};
 use crate::proto::resource::slice::slice_service_server::SliceService;
 use crate::proto::resource::slice::{
-    SliceSchema, SliceId, SliceIds, SliceTime, SliceRange, SliceNameTime, SliceNameRange, SliceUpdate, SliceOption,
+    SliceSchema, SliceId, SliceIds, SliceTime, SliceRange, SliceNameTime, SliceNameRange, SliceUpdate, SliceOption, SliceOptionPage,
     SliceGroupTime, SliceGroupRange, SliceGroupOption,
     SliceSetSchema, SliceSetTime, SliceSetRange, SliceSetOption,
-    SliceReadResponse, SliceListResponse, SliceCreateResponse, SliceChangeResponse,
-    SliceSetReadResponse, SliceSetListResponse
+    SliceReadResponse, SliceListResponse, SlicePageResponse, SliceCreateResponse, SliceChangeResponse,
+    SliceSetReadResponse, SliceSetListResponse,
+    SliceRuleSchema, SliceRuleOption, SliceRuleUpdate, SliceRuleRange, SliceRuleApply, SliceRuleCount,
+    SliceRuleReadResponse, SliceRuleListResponse, SliceRuleApplyResponse
 };
 use crate::common::validator::{AccessValidator, AccessSchema};
-use crate::common::utility::handle_error;
+use crate::common::utility::{handle_error, decode_page};
 
 const READ_SLICE: &str = "read_slice";
 const CREATE_SLICE: &str = "create_slice";
@@ -161,6 +164,27 @@ impl SliceService for SliceServer {
         Ok(Response::new(SliceListResponse { results }))
     }
 
+    async fn list_slice_option_page(&self, request: Request<SliceOptionPage>)
+        -> Result<Response<SlicePageResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_SLICE)?;
+        let request = request.into_inner();
+        let page = decode_page(request.cursor, request.limit)?;
+        let result = self.resource_db.list_slice_option_page(
+            request.device_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.model_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.name.as_deref(),
+            request.begin.map(|t| Utc.timestamp_nanos(t * 1000)),
+            request.end.map(|t| Utc.timestamp_nanos(t * 1000)),
+            &page
+        ).await;
+        let (results, cursor) = match result {
+            Ok((value, cursor)) => (value.into_iter().map(|e| e.into()).collect(), cursor.map(|c| c.to_bytes())),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SlicePageResponse { results, cursor }))
+    }
+
     async fn list_slice_group_by_time(&self, request: Request<SliceGroupTime>)
         -> Result<Response<SliceListResponse>, Status>
     {
@@ -434,6 +458,128 @@ impl SliceService for SliceServer {
         Ok(Response::new(SliceChangeResponse { }))
     }
 
+    async fn read_slice_rule(&self, request: Request<SliceId>)
+        -> Result<Response<SliceRuleReadResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_SLICE)?;
+        let request = request.into_inner();
+        let result = self.resource_db.read_slice_rule(request.id).await;
+        let result = match result {
+            Ok(value) => Some(value.into()),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceRuleReadResponse { result }))
+    }
+
+    async fn list_slice_rule_option(&self, request: Request<SliceRuleOption>)
+        -> Result<Response<SliceRuleListResponse>, Status>
+    {
+        self.validate(request.extensions(), READ_SLICE)?;
+        let request = request.into_inner();
+        let result = self.resource_db.list_slice_rule_option(
+            request.device_id.map(|id| Uuid::from_slice(&id).unwrap_or_default()),
+            request.model_id.map(|id| Uuid::from_slice(&id).unwrap_or_default())
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceRuleListResponse { results }))
+    }
+
+    async fn create_slice_rule(&self, request: Request<SliceRuleSchema>)
+        -> Result<Response<SliceCreateResponse>, Status>
+    {
+        self.validate(request.extensions(), CREATE_SLICE)?;
+        let request = request.into_inner();
+        let condition = SliceCondition::try_from(request.condition.as_str()).map_err(Status::invalid_argument)?;
+        let result = self.resource_db.create_slice_rule(&SliceRuleCreate {
+            device_id: Uuid::from_slice(&request.device_id).unwrap_or_default(),
+            model_id: Uuid::from_slice(&request.model_id).unwrap_or_default(),
+            index: request.index as usize,
+            condition,
+            threshold: request.threshold,
+            min_duration: Duration::microseconds(request.min_duration),
+            max_gap: Duration::microseconds(request.max_gap),
+            name: request.name
+        }).await;
+        let id = match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceCreateResponse { id }))
+    }
+
+    async fn update_slice_rule(&self, request: Request<SliceRuleUpdate>)
+        -> Result<Response<SliceChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), UPDATE_SLICE)?;
+        let request = request.into_inner();
+        let condition = match request.condition {
+            Some(value) => Some(SliceCondition::try_from(value.as_str()).map_err(Status::invalid_argument)?),
+            None => None
+        };
+        let result = self.resource_db.update_slice_rule(
+            request.id,
+            condition,
+            request.threshold,
+            request.min_duration.map(Duration::microseconds),
+            request.max_gap.map(Duration::microseconds),
+            request.name.as_deref()
+        ).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceChangeResponse { }))
+    }
+
+    async fn delete_slice_rule(&self, request: Request<SliceId>)
+    -> Result<Response<SliceChangeResponse>, Status>
+    {
+        self.validate(request.extensions(), DELETE_SLICE)?;
+        let request = request.into_inner();
+        let result = self.resource_db.delete_slice_rule(request.id).await;
+        match result {
+            Ok(value) => value,
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceChangeResponse { }))
+    }
+
+    async fn generate_slice_by_range(&self, request: Request<SliceRuleRange>)
+        -> Result<Response<SliceListResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_SLICE } else { CREATE_SLICE };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let result = self.resource_db.generate_slice_by_range(
+            request.id,
+            Utc.timestamp_nanos(request.begin * 1000),
+            Utc.timestamp_nanos(request.end * 1000),
+            request.dry_run
+        ).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|e| e.into()).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceListResponse { results }))
+    }
+
+    async fn apply_slice_rule(&self, request: Request<SliceRuleApply>)
+        -> Result<Response<SliceRuleApplyResponse>, Status>
+    {
+        let procedure = if request.get_ref().dry_run { READ_SLICE } else { CREATE_SLICE };
+        self.validate(request.extensions(), procedure)?;
+        let request = request.into_inner();
+        let result = self.resource_db.apply_slice_rule(request.dry_run).await;
+        let results = match result {
+            Ok(value) => value.into_iter().map(|(id, count)| SliceRuleCount { id, count: count as u64 }).collect(),
+            Err(e) => return Err(handle_error(e))
+        };
+        Ok(Response::new(SliceRuleApplyResponse { results }))
+    }
+
 }
 
 impl AccessValidator for SliceServer {
@@ -1,5 +1,8 @@
 use bbthings_database::Resource;
 use bbthings_database::utility::migrate_resource;
+use bbthings_database::resource::compression::CompressionTable;
+use bbthings_database::resource::integrity::IntegrityMode;
+use bbthings_database::resource::transition::TransitionMode;
 use bbthings_grpc_server::proto::resource::config::config_service_server::ConfigServiceServer;
 use bbthings_grpc_server::proto::resource::model::model_service_server::ModelServiceServer;
 use bbthings_grpc_server::proto::resource::device::device_service_server::DeviceServiceServer;
@@ -8,6 +11,9 @@ use bbthings_grpc_server::proto::resource::set::set_service_server::SetServiceSe
 use bbthings_grpc_server::proto::resource::data::data_service_server::DataServiceServer;
 use bbthings_grpc_server::proto::resource::buffer::buffer_service_server::BufferServiceServer;
 use bbthings_grpc_server::proto::resource::slice::slice_service_server::SliceServiceServer;
+use bbthings_grpc_server::proto::resource::aggregate::aggregate_service_server::AggregateServiceServer;
+use bbthings_grpc_server::proto::resource::retention::retention_service_server::RetentionServiceServer;
+use bbthings_grpc_server::proto::resource::compression::compression_service_server::CompressionServiceServer;
 use bbthings_grpc_server::proto::descriptor;
 use bbthings_grpc_server::auth::auth::api_login;
 use bbthings_grpc_server::resource::config::ConfigServer;
@@ -18,15 +24,22 @@ use bbthings_grpc_server::resource::set::SetServer;
 use bbthings_grpc_server::resource::data::DataServer;
 use bbthings_grpc_server::resource::buffer::BufferServer;
 use bbthings_grpc_server::resource::slice::SliceServer;
+use bbthings_grpc_server::resource::aggregate::AggregateServer;
+use bbthings_grpc_server::resource::retention::RetentionServer;
+use bbthings_grpc_server::resource::compression::CompressionServer;
 use bbthings_grpc_server::common::config::{API_ID, ACCESS_MAP, ROOT_DATA, RootData};
 use bbthings_grpc_server::common::validator::AccessSchema;
+use bbthings_grpc_server::common::cache::DataLatestCache;
 use bbthings_grpc_server::common::interceptor::interceptor;
 use bbthings_grpc_server::common::utility;
 use tonic::transport::Server;
+use tokio::task::JoinSet;
+use sqlx::Error;
 use tonic_web::GrpcWebLayer;
 use http::{header::HeaderName, Method};
 use tower_http::cors::{CorsLayer, Any};
 use uuid::Uuid;
+use chrono::Duration;
 use clap::Parser;
 
 #[derive(Parser, Debug)]
@@ -43,7 +56,17 @@ struct Args {
     #[arg(long)]
     api_id: Option<String>,
     #[arg(long)]
-    password: Option<String>
+    password: Option<String>,
+    #[arg(long)]
+    retention_period: Option<u64>,
+    #[arg(long)]
+    slice_rule_period: Option<u64>,
+    #[arg(long)]
+    compress_after: Option<u64>,
+    #[arg(long)]
+    integrity: Option<String>,
+    #[arg(long)]
+    transition: Option<String>
 }
 
 #[tokio::main]
@@ -79,6 +102,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(_) => false
     };
     let secured = args.secured || secured_env;
+    let retention_period = match args.retention_period {
+        Some(value) => value,
+        None => match std::env::var("RETENTION_PERIOD") {
+            Ok(value) => value.parse()?,
+            Err(_) => RETENTION_PERIOD_DEFAULT
+        }
+    };
+    let slice_rule_period = match args.slice_rule_period {
+        Some(value) => value,
+        None => match std::env::var("SLICE_RULE_PERIOD") {
+            Ok(value) => value.parse()?,
+            Err(_) => SLICE_RULE_PERIOD_DEFAULT
+        }
+    };
+    let compress_after = match args.compress_after {
+        Some(value) => value,
+        None => match std::env::var("COMPRESS_AFTER") {
+            Ok(value) => value.parse()?,
+            Err(_) => 0
+        }
+    };
+    let integrity = match args.integrity {
+        Some(value) => IntegrityMode::try_from(value.as_str())?,
+        None => match std::env::var("DATA_INTEGRITY") {
+            Ok(value) => IntegrityMode::try_from(value.as_str())?,
+            Err(_) => IntegrityMode::default()
+        }
+    };
+    let transition = match args.transition {
+        Some(value) => TransitionMode::try_from(value.as_str())?,
+        None => match std::env::var("BUFFER_TRANSITION") {
+            Ok(value) => TransitionMode::try_from(value.as_str())?,
+            Err(_) => TransitionMode::default()
+        }
+    };
 
     let api_id = Uuid::try_parse(&api_id).unwrap();
     API_ID.set(api_id).unwrap();
@@ -96,28 +154,117 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         )).unwrap();
     }
 
+    let config = ResourceConfig {
+        retention_period,
+        slice_rule_period,
+        compress_after,
+        integrity,
+        transition
+    };
+
     if secured {
-        resource_server_secured(db_url, address, auth_address, api_id, password).await
+        resource_server_secured(db_url, address, auth_address, api_id, password, config).await
     } else {
-        resource_server(db_url, address).await
+        resource_server(db_url, address, config).await
     }
 }
 
-async fn resource_server(db_url: String, address: String) -> Result<(), Box<dyn std::error::Error>>
+#[derive(Debug, Clone, Copy)]
+struct ResourceConfig {
+    retention_period: u64,
+    slice_rule_period: u64,
+    compress_after: u64,
+    integrity: IntegrityMode,
+    transition: TransitionMode
+}
+
+async fn resource_setup(db_url: &str, config: ResourceConfig) -> Result<(Resource, DataLatestCache, JoinSet<Result<(), Error>>), Error>
+{
+    let mut resource_db = Resource::new_with_url(db_url).await;
+    resource_db.integrity = config.integrity;
+    resource_db.transition = config.transition;
+    migrate_resource(&resource_db.pool).await.unwrap();
+    // latest data cache is shared by every server and task which changes data
+    let latest = DataLatestCache::new();
+    // background tasks are supervised by the server, a failed task stops the server with its error
+    let mut tasks = JoinSet::new();
+    retention_task(&mut tasks, resource_db.clone(), latest.clone(), config.retention_period);
+    slice_rule_task(&mut tasks, resource_db.clone(), config.slice_rule_period);
+    compression_setup(&resource_db, config.compress_after).await?;
+    Ok((resource_db, latest, tasks))
+}
+
+const RETENTION_PERIOD_DEFAULT: u64 = 3600;
+
+fn retention_task(tasks: &mut JoinSet<Result<(), Error>>, resource_db: Resource, latest: DataLatestCache, period: u64)
+{
+    // zero period disables the retention enforcement
+    if period == 0 {
+        return;
+    }
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(period));
+        loop {
+            interval.tick().await;
+            let counts = resource_db.apply_retention(false).await?;
+            if counts.iter().any(|(_, count)| *count > 0) {
+                latest.clear();
+            }
+        }
+    });
+}
+
+const SLICE_RULE_PERIOD_DEFAULT: u64 = 60;
+
+fn slice_rule_task(tasks: &mut JoinSet<Result<(), Error>>, resource_db: Resource, period: u64)
+{
+    // zero period disables the continuous slice generation
+    if period == 0 {
+        return;
+    }
+    tasks.spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(period));
+        loop {
+            interval.tick().await;
+            resource_db.apply_slice_rule(false).await?;
+        }
+    });
+}
+
+async fn compression_setup(resource_db: &Resource, compress_after: u64) -> Result<(), Error>
+{
+    // zero compress after keeps the current compression setting of hypertables
+    if compress_after == 0 {
+        return Ok(());
+    }
+    for table in [CompressionTable::Data, CompressionTable::Buffer] {
+        resource_db.enable_compression(table).await?;
+        resource_db.set_compression_policy(table, Duration::seconds(compress_after as i64)).await?;
+    }
+    Ok(())
+}
+
+async fn resource_server(db_url: String, address: String, config: ResourceConfig) -> Result<(), Box<dyn std::error::Error>>
 {
     let addr = address.parse()?;
 
-    let resource_db = Resource::new_with_url(&db_url).await;
-    migrate_resource(&resource_db.pool).await.unwrap();
+    let (resource_db, latest, mut tasks) = resource_setup(&db_url, config).await?;
 
     let config_server = ConfigServer::new();
     let model_server = ModelServer::new(resource_db.clone());
     let device_server = DeviceServer::new(resource_db.clone());
     let group_server = GroupServer::new(resource_db.clone());
     let set_server = SetServer::new(resource_db.clone());
-    let data_server = DataServer::new(resource_db.clone());
-    let buffer_server = BufferServer::new(resource_db.clone());
+    let data_server = DataServer::new(resource_db.clone())
+        .with_latest_cache(latest.clone());
+    let buffer_server = BufferServer::new(resource_db.clone())
+        .with_latest_cache(latest.clone());
     let slice_server = SliceServer::new(resource_db.clone());
+    let aggregate_server = AggregateServer::new(resource_db.clone())
+        .with_latest_cache(latest.clone());
+    let retention_server = RetentionServer::new(resource_db.clone())
+        .with_latest_cache(latest);
+    let compression_server = CompressionServer::new(resource_db.clone());
 
     let config_service = ConfigServiceServer::new(config_server);
     let model_service = ModelServiceServer::new(model_server);
@@ -127,6 +274,9 @@ async fn resource_server(db_url: String, address: String) -> Result<(), Box<dyn
     let data_service = DataServiceServer::new(data_server);
     let buffer_service = BufferServiceServer::new(buffer_server);
     let slice_service = SliceServiceServer::new(slice_server);
+    let aggregate_service = AggregateServiceServer::new(aggregate_server);
+    let retention_service = RetentionServiceServer::new(retention_server);
+    let compression_service = CompressionServiceServer::new(compression_server);
 
     let reflection_service = tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(descriptor::config::DESCRIPTOR_SET)
@@ -137,9 +287,12 @@ async fn resource_server(db_url: String, address: String) -> Result<(), Box<dyn
         .register_encoded_file_descriptor_set(descriptor::data::DESCRIPTOR_SET)
         .register_encoded_file_descriptor_set(descriptor::buffer::DESCRIPTOR_SET)
         .register_encoded_file_descriptor_set(descriptor::slice::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::aggregate::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::retention::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::compression::DESCRIPTOR_SET)
         .build_v1alpha();
 
-    Server::builder()
+    let server = Server::builder()
         .accept_http1(true)
         .layer(CorsLayer::new()
             .allow_origin(Any)
@@ -156,14 +309,20 @@ async fn resource_server(db_url: String, address: String) -> Result<(), Box<dyn
         .add_service(data_service)
         .add_service(buffer_service)
         .add_service(slice_service)
+        .add_service(aggregate_service)
+        .add_service(retention_service)
+        .add_service(compression_service)
         .add_service(reflection_service?)
-        .serve(addr)
-        .await?;
+        .serve(addr);
 
+    tokio::select! {
+        result = server => result?,
+        Some(result) = tasks.join_next() => result??
+    }
     Ok(())
 }
 
-async fn resource_server_secured(db_url: String, address: String, auth_address: String, api_id: Uuid, password: String) -> Result<(), Box<dyn std::error::Error>> 
+async fn resource_server_secured(db_url: String, address: String, auth_address: String, api_id: Uuid, password: String, config: ResourceConfig) -> Result<(), Box<dyn std::error::Error>> 
 {
     let addr = address.parse()?;
 
@@ -176,17 +335,23 @@ async fn resource_server_secured(db_url: String, address: String, auth_address:
         .collect();
     ACCESS_MAP.set(accesses.clone()).unwrap();
 
-    let resource_db = Resource::new_with_url(&db_url).await;
-    migrate_resource(&resource_db.pool).await.unwrap();
+    let (resource_db, latest, mut tasks) = resource_setup(&db_url, config).await?;
 
     let config_server = ConfigServer::new_with_validator(&token_key, &accesses);
     let model_server = ModelServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
     let device_server = DeviceServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
     let group_server = GroupServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
     let set_server = SetServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
-    let data_server = DataServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
-    let buffer_server = BufferServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
+    let data_server = DataServer::new_with_validator(resource_db.clone(), &token_key, &accesses)
+        .with_latest_cache(latest.clone());
+    let buffer_server = BufferServer::new_with_validator(resource_db.clone(), &token_key, &accesses)
+        .with_latest_cache(latest.clone());
     let slice_server = SliceServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
+    let aggregate_server = AggregateServer::new_with_validator(resource_db.clone(), &token_key, &accesses)
+        .with_latest_cache(latest.clone());
+    let retention_server = RetentionServer::new_with_validator(resource_db.clone(), &token_key, &accesses)
+        .with_latest_cache(latest);
+    let compression_server = CompressionServer::new_with_validator(resource_db.clone(), &token_key, &accesses);
 
     let config_service = ConfigServiceServer::with_interceptor(config_server, interceptor);
     let model_service = ModelServiceServer::with_interceptor(model_server, interceptor);
@@ -196,6 +361,9 @@ async fn resource_server_secured(db_url: String, address: String, auth_address:
     let data_service = DataServiceServer::with_interceptor(data_server, interceptor);
     let buffer_service = BufferServiceServer::with_interceptor(buffer_server, interceptor);
     let slice_service = SliceServiceServer::with_interceptor(slice_server, interceptor);
+    let aggregate_service = AggregateServiceServer::with_interceptor(aggregate_server, interceptor);
+    let retention_service = RetentionServiceServer::with_interceptor(retention_server, interceptor);
+    let compression_service = CompressionServiceServer::with_interceptor(compression_server, interceptor);
 
     let reflection_service = tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(descriptor::config::DESCRIPTOR_SET)
@@ -206,9 +374,12 @@ async fn resource_server_secured(db_url: String, address: String, auth_address:
         .register_encoded_file_descriptor_set(descriptor::data::DESCRIPTOR_SET)
         .register_encoded_file_descriptor_set(descriptor::buffer::DESCRIPTOR_SET)
         .register_encoded_file_descriptor_set(descriptor::slice::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::aggregate::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::retention::DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(descriptor::compression::DESCRIPTOR_SET)
         .build_v1();
 
-    Server::builder()
+    let server = Server::builder()
         .accept_http1(true)
         .layer(CorsLayer::new()
             .allow_origin(Any)
@@ -225,9 +396,15 @@ async fn resource_server_secured(db_url: String, address: String, auth_address:
         .add_service(data_service)
         .add_service(buffer_service)
         .add_service(slice_service)
+        .add_service(aggregate_service)
+        .add_service(retention_service)
+        .add_service(compression_service)
         .add_service(reflection_service?)
-        .serve(addr)
-        .await?;
+        .serve(addr);
 
+    tokio::select! {
+        result = server => result?,
+        Some(result) = tasks.join_next() => result??
+    }
     Ok(())
 }